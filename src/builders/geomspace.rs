@@ -0,0 +1,229 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Doc reference:
+// https://docs.scipy.org/doc/numpy/reference/generated/numpy.geomspace.html
+
+use num::{Float, FromPrimitive};
+use std::ops::AddAssign;
+
+/// Geometrically spaced vector parameters
+pub struct GeomspaceVectorParams<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    /// The starting value of the sequence. Unlike [`Linspace`], there is
+    /// no default: both endpoints of a geometric progression must be
+    /// specified and must be positive.
+    ///
+    /// [`Linspace`]: ../linspace/trait.Linspace.html
+    pub start: Option<T>,
+    /// The end value of the sequence.
+    pub stop: Option<T>,
+    /// The size of the vectors or number
+    /// of points to generate.
+    /// The default `size` is 10.
+    pub size: usize,
+}
+
+impl<T> GeomspaceVectorParams<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    /// Set the starting value of the sequence.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let geo: Vec<f64> = Vec::geomspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(1000.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// // The first value should be 1.0
+    /// assert_eq!(geo[0], 1.0);
+    /// ````
+    pub fn start_at(self, value: T) -> GeomspaceVectorParams<T> {
+        GeomspaceVectorParams {
+            start: Some(value),
+            stop: self.stop,
+            size: self.size,
+        }
+    }
+
+    /// Set the end value of the sequence.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let geo: Vec<f64> = Vec::geomspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(1000.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// // The end value should be 1000.0
+    /// assert_eq!(*geo.last().unwrap(), 1000.0);
+    /// ````
+    pub fn stop_at(self, value: T) -> GeomspaceVectorParams<T> {
+        GeomspaceVectorParams {
+            start: self.start,
+            stop: Some(value),
+            size: self.size,
+        }
+    }
+
+    /// Set the size of the generated vectors
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let geo: Vec<f64> = Vec::geomspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(1000.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// // The size of generated vectors should be 4
+    /// assert_eq!(geo.size(), 4);
+    /// ````
+    pub fn with_size(self, value: usize) -> GeomspaceVectorParams<T> {
+        GeomspaceVectorParams {
+            start: self.start,
+            stop: self.stop,
+            size: value,
+        }
+    }
+
+    /// Generate the geometrically spaced vector based on the
+    /// configured parameters. Each value is a constant multiple of the
+    /// value before it, i.e. the sequence is evenly spaced on a log scale.
+    ///
+    /// # Panics
+    /// Panics if `start` or `stop` is not specified, or if either of them
+    /// is not positive. `start` may be greater than `stop`, in which case
+    /// a descending sequence is generated.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let geo: Vec<f32> = Vec::geomspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(1000.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// assert_eq!(geo, [1.0, 10.0, 100.00001, 1000.0]);
+    /// ````
+    pub fn generate(self) -> Vec<T> {
+        // Panics if `start` or `stop` is not specified
+        if self.start.is_none() {
+            panic!("Geomspace: start value should be specified")
+        }
+        if self.stop.is_none() {
+            panic!("Geomspace: stop value should be specified")
+        }
+        let start = self.start.unwrap();
+        let stop = self.stop.unwrap();
+        let zero = T::from_i32(0).unwrap();
+        // Panics if either endpoint is not positive
+        if start <= zero || stop <= zero {
+            panic!("Geomspace: both start and stop should be positive")
+        }
+
+        // With a single point there's no step to take; NumPy returns
+        // just `start` in this case.
+        if self.size == 1 {
+            return vec![start];
+        }
+
+        // Work in log space, where the progression becomes linear, then
+        // exponentiate back. Each exponent is computed directly as
+        // `log_start + i * step`, so no per-iteration error can
+        // accumulate, and the last value is pinned exactly to `stop`.
+        let log_start = start.ln();
+        let log_stop = stop.ln();
+        let size = T::from_usize(self.size).unwrap();
+        let step = (log_stop - log_start) / (size - T::from_f32(1.0).unwrap());
+        let mut output = Vec::with_capacity(self.size);
+        for i in 0..self.size {
+            output.push((log_start + T::from_usize(i).unwrap() * step).exp());
+        }
+        output[self.size - 1] = stop;
+
+        output
+    }
+}
+
+/// Geometrically spaced vector builder
+pub trait Geomspace<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    /// A geometrically spaced vectors builder. It returns
+    /// [`GeomspaceVectorParams`] with the following default value:
+    ///
+    /// ```ignore
+    /// GeomspaceVectorParams{
+    ///     start: None,
+    ///     stop: None,
+    ///     size: 10,
+    /// }
+    /// ```
+    ///
+    /// The parameters can be configured using the following methods:
+    /// [`start_at()`], [`stop_at()`] and [`with_size()`]. Both
+    /// [`start_at()`] and [`stop_at()`] are required, and both values must
+    /// be positive.
+    ///
+    /// After the parameters are configured, use [`generate()`] to generate
+    /// the geometrically spaced vector: a sequence of `size` values
+    /// evenly spaced between `start` and `stop` on a log scale.
+    ///
+    /// [`GeomspaceVectorParams`]: struct.GeomspaceVectorParams.html
+    /// [`start_at()`]: struct.GeomspaceVectorParams.html#method.start_at
+    /// [`stop_at()`]: struct.GeomspaceVectorParams.html#method.stop_at
+    /// [`with_size()`]: struct.GeomspaceVectorParams.html#method.with_size
+    /// [`generate()`]: struct.GeomspaceVectorParams.html#method.generate
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// // Generate geometrically spaced vector within interval [1.0, 1000.0]
+    /// let geo: Vec<f32> = Vec::geomspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(1000.0)
+    ///     .with_size(4)
+    ///     .generate()
+    ///     ;
+    ///
+    /// assert_eq!(geo, [1.0, 10.0, 100.00001, 1000.0]);
+    /// ```
+    ///
+    fn geomspace() -> GeomspaceVectorParams<T>;
+}
+
+impl<T> Geomspace<T> for Vec<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    fn geomspace() -> GeomspaceVectorParams<T> {
+        GeomspaceVectorParams {
+            start: None,
+            stop: None,
+            size: 10,
+        }
+    }
+}
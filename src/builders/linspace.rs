@@ -33,6 +33,9 @@ where
     /// of points to generate.
     /// The default `size` is 10.
     pub size: usize,
+    /// Whether `stop` should be included in the generated sequence.
+    /// The default is `true`, matching NumPy's `linspace`.
+    pub endpoint: bool,
 }
 
 impl<T> LinspaceVectorParams<T>
@@ -58,6 +61,7 @@ where
             start: Some(value),
             stop: self.stop,
             size: self.size,
+            endpoint: self.endpoint,
         }
     }
 
@@ -80,6 +84,7 @@ where
             start: self.start,
             stop: Some(value),
             size: self.size,
+            endpoint: self.endpoint,
         }
     }
 
@@ -102,57 +107,117 @@ where
             start: self.start,
             stop: self.stop,
             size: value,
+            endpoint: self.endpoint,
         }
     }
 
-    /// Generate the linearly spaced vector based on the
-    /// configured parameters.
-    ///
-    /// It returns `None`, if `stop` value is not specified
-    /// or the `start >= stop`.
+    /// Set whether `stop` should be included as the last generated value.
+    /// When `false`, the spacing is `(stop-start)/size` instead of
+    /// `(stop-start)/(size-1)` and `stop` itself is excluded, matching
+    /// NumPy's `endpoint=False`.
     ///
-    /// # Panics
-    /// Panics if the `stop` value is not specified or
     /// # Examples
     /// ````
     /// # use gulali::prelude::*;
-    /// let lin: Vec<f32> = Vec::linspace()
-    ///     .start_at(1.0)
-    ///     .stop_at(3.0)
+    /// let lin: Vec<f64> = Vec::linspace()
+    ///     .start_at(0.0)
+    ///     .stop_at(4.0)
     ///     .with_size(4)
+    ///     .endpoint(false)
     ///     .generate();
     ///
-    /// assert_eq!(lin, [1.0, 1.6666667, 2.3333335, 3.0]);
+    /// assert_eq!(lin, [0.0, 1.0, 2.0, 3.0]);
     /// ````
-    pub fn generate(self) -> Vec<T> {
+    pub fn endpoint(self, value: bool) -> LinspaceVectorParams<T> {
+        LinspaceVectorParams {
+            start: self.start,
+            stop: self.stop,
+            size: self.size,
+            endpoint: value,
+        }
+    }
+
+    // Compute the generated sequence together with the step that was
+    // used, so `generate()` and `generate_retstep()` share one
+    // implementation. Each point is computed directly as
+    // `start + i * step`, so no per-iteration error can accumulate.
+    fn compute(&self) -> (Vec<T>, T) {
         // Panics if the `stop` value is not specified
         if self.stop.is_none() {
             panic!("Linspace: stop value should be specified")
         }
         let start = self.start.unwrap_or(T::from_i32(0).unwrap());
         let stop = self.stop.unwrap();
-        // Panics if start >= stop, it should be start < stop
-        if start >= stop {
-            panic!("Linspace: start >= stop, it should be start < stop")
+
+        // With a single point there's no step to take; NumPy returns
+        // just the start of the interval in this case.
+        if self.size == 1 {
+            return (vec![start], T::zero());
         }
-        // Convert size to float type
+
         let size = T::from_usize(self.size).unwrap();
+        let divisor = if self.endpoint {
+            size - T::from_f32(1.0).unwrap()
+        } else {
+            size
+        };
+        let step = (stop - start) / divisor;
+
         let mut output = Vec::with_capacity(self.size);
-        let mut current_step = start;
-        let step = (stop - start) / (size - T::from_f32(1.0).unwrap());
-        while current_step < stop {
-            output.push(current_step);
-            current_step += step;
+        for i in 0..self.size {
+            output.push(start + T::from_usize(i).unwrap() * step);
         }
 
-        // Include the `stop` value in the sequences
-        if output.len() == self.size {
+        // Pin the last element exactly to `stop` to avoid float drift.
+        if self.endpoint {
             output[self.size - 1] = stop;
-        } else {
-            output.push(stop);
         }
 
-        output
+        (output, step)
+    }
+
+    /// Generate the linearly spaced vector based on the
+    /// configured parameters.
+    ///
+    /// # Panics
+    /// Panics if the `stop` value is not specified.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let lin: Vec<f32> = Vec::linspace()
+    ///     .start_at(2.0)
+    ///     .stop_at(10.0)
+    ///     .with_size(5)
+    ///     .generate();
+    ///
+    /// assert_eq!(lin, [2.0, 4.0, 6.0, 8.0, 10.0]);
+    /// ````
+    pub fn generate(self) -> Vec<T> {
+        self.compute().0
+    }
+
+    /// Generate the linearly spaced vector, also returning the step
+    /// between consecutive values, matching NumPy's `linspace(...,
+    /// retstep=True)`.
+    ///
+    /// # Panics
+    /// Panics if the `stop` value is not specified.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let (lin, step): (Vec<f32>, f32) = Vec::linspace()
+    ///     .start_at(0.0)
+    ///     .stop_at(10.0)
+    ///     .with_size(5)
+    ///     .generate_retstep();
+    ///
+    /// assert_eq!(lin, [0.0, 2.5, 5.0, 7.5, 10.0]);
+    /// assert_eq!(step, 2.5);
+    /// ````
+    pub fn generate_retstep(self) -> (Vec<T>, T) {
+        self.compute()
     }
 }
 
@@ -168,46 +233,47 @@ where
     /// LinspaceVectorParams{
     ///     start: 0,
     ///     stop: None,
-    ///     size: 100
+    ///     size: 10,
+    ///     endpoint: true,
     /// }
     /// ```
     ///
     /// The parameters can be configured using
-    /// the following methods: [`start_at()`], [`stop_at()`]
-    /// and [`with_size()`]. The only required method
+    /// the following methods: [`start_at()`], [`stop_at()`],
+    /// [`with_size()`] and [`endpoint()`]. The only required method
     /// is [`stop_at()`].
     ///
     /// After the parameters are configured,
-    /// use [`generate()`] to generate the linearly spaced vector.
+    /// use [`generate()`] to generate the linearly spaced vector (or
+    /// [`generate_retstep()`] to also get the computed step back).
     /// The values of the linearly spaced vector are generated
     /// within the interval `[start, stop]` (in other words, the interval
     /// including `start` and `stop`). The spacing between the values
-    /// is `(stop-start)/(size-1)`.
+    /// is `(stop-start)/(size-1)`. With `endpoint(false)`, `stop` is
+    /// excluded and the spacing is `(stop-start)/size` instead. `start` may
+    /// be greater than `stop`, in which case a descending sequence is
+    /// generated.
     ///
     /// [`LinspaceVectorParams`]: struct.LinspaceVectorParams.html
     /// [`start_at()`]: struct.LinspaceVectorParams.html#method.start_at
     /// [`stop_at()`]: struct.LinspaceVectorParams.html#method.stop_at
     /// [`with_size()`]: struct.LinspaceVectorParams.html#method.with_size
+    /// [`endpoint()`]: struct.LinspaceVectorParams.html#method.endpoint
     /// [`generate()`]: struct.LinspaceVectorParams.html#method.generate
+    /// [`generate_retstep()`]: struct.LinspaceVectorParams.html#method.generate_retstep
     ///
     /// # Examples
     /// ```
     /// # use gulali::prelude::*;
-    /// // Generate linearly spaced vector within interval [2.0, 5.0]
+    /// // Generate linearly spaced vector within interval [2.0, 10.0]
     /// let lin: Vec<f32> = Vec::linspace()
     ///     .start_at(2.0)
-    ///     .stop_at(5.0)
-    ///     .with_size(10)
+    ///     .stop_at(10.0)
+    ///     .with_size(5)
     ///     .generate()
     ///     ;
     ///
-    /// assert_eq!(
-    ///     lin,
-    ///     [
-    ///         2.0, 2.33333333, 2.6666665, 2.9999998, 3.333333,
-    ///         3.6666663, 3.9999995, 4.333333, 4.6666665, 5.0
-    ///     ]
-    /// );
+    /// assert_eq!(lin, [2.0, 4.0, 6.0, 8.0, 10.0]);
     /// ```
     ///
     fn linspace() -> LinspaceVectorParams<T>;
@@ -222,6 +288,7 @@ where
             start: None,
             stop: None,
             size: 10,
+            endpoint: true,
         }
     }
 }
@@ -0,0 +1,246 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// Doc reference:
+// https://docs.scipy.org/doc/numpy/reference/generated/numpy.logspace.html
+
+use num::{Float, FromPrimitive};
+use std::ops::AddAssign;
+
+/// Logarithmically spaced vector parameters
+pub struct LogspaceVectorParams<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    /// The starting value of the sequence.
+    /// The default `start` value is 0.
+    pub start: Option<T>,
+    /// The end value of the sequence.
+    pub stop: Option<T>,
+    /// The size of the vectors or number
+    /// of points to generate.
+    /// The default `size` is 10.
+    pub size: usize,
+    /// The base of the log space.
+    /// The default `base` is 10.
+    pub base: T,
+}
+
+impl<T> LogspaceVectorParams<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    /// Set the starting value of the sequence.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let log: Vec<f64> = Vec::logspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(3.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// // The first value should be 10.0
+    /// assert_eq!(log[0], 10.0);
+    /// ````
+    pub fn start_at(self, value: T) -> LogspaceVectorParams<T> {
+        LogspaceVectorParams {
+            start: Some(value),
+            stop: self.stop,
+            size: self.size,
+            base: self.base,
+        }
+    }
+
+    /// Set the end value of the sequence.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let log: Vec<f64> = Vec::logspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(3.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// // The end value should be 1000.0
+    /// assert_eq!(*log.last().unwrap(), 1000.0);
+    /// ````
+    pub fn stop_at(self, value: T) -> LogspaceVectorParams<T> {
+        LogspaceVectorParams {
+            start: self.start,
+            stop: Some(value),
+            size: self.size,
+            base: self.base,
+        }
+    }
+
+    /// Set the size of the generated vectors
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let log: Vec<f64> = Vec::logspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(3.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// // The size of generated vectors should be 4
+    /// assert_eq!(log.size(), 4);
+    /// ````
+    pub fn with_size(self, value: usize) -> LogspaceVectorParams<T> {
+        LogspaceVectorParams {
+            start: self.start,
+            stop: self.stop,
+            size: value,
+            base: self.base,
+        }
+    }
+
+    /// Set the base of the log space. The default base is 10.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let log: Vec<f64> = Vec::logspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(3.0)
+    ///     .with_size(4)
+    ///     .base(2.0)
+    ///     .generate();
+    ///
+    /// // The first value should be 2.0
+    /// assert_eq!(log[0], 2.0);
+    /// ````
+    pub fn base(self, value: T) -> LogspaceVectorParams<T> {
+        LogspaceVectorParams {
+            start: self.start,
+            stop: self.stop,
+            size: self.size,
+            base: value,
+        }
+    }
+
+    /// Generate the logarithmically spaced vector based on the
+    /// configured parameters. It is equivalent to
+    /// `base.powf(linspace(start, stop, size))`.
+    ///
+    /// # Panics
+    /// Panics if the `stop` value is not specified.
+    ///
+    /// # Examples
+    /// ````
+    /// # use gulali::prelude::*;
+    /// let log: Vec<f32> = Vec::logspace()
+    ///     .start_at(1.0)
+    ///     .stop_at(3.0)
+    ///     .with_size(4)
+    ///     .generate();
+    ///
+    /// assert_eq!(log, [10.0, 46.415897, 215.44354, 1000.0]);
+    /// ````
+    pub fn generate(self) -> Vec<T> {
+        // Panics if the `stop` value is not specified
+        if self.stop.is_none() {
+            panic!("Logspace: stop value should be specified")
+        }
+        let start = self.start.unwrap_or(T::from_i32(0).unwrap());
+        let stop = self.stop.unwrap();
+
+        // With a single point there's no step to take; NumPy returns
+        // just `base**start` in this case.
+        if self.size == 1 {
+            return vec![self.base.powf(start)];
+        }
+
+        // Each exponent is computed directly as `start + i * step`, so
+        // no per-iteration error can accumulate, and the last value is
+        // pinned exactly to `stop`.
+        let size = T::from_usize(self.size).unwrap();
+        let step = (stop - start) / (size - T::from_f32(1.0).unwrap());
+        let mut output = Vec::with_capacity(self.size);
+        for i in 0..self.size {
+            output.push(self.base.powf(start + T::from_usize(i).unwrap() * step));
+        }
+        output[self.size - 1] = self.base.powf(stop);
+
+        output
+    }
+}
+
+/// Logarithmically spaced vector builder
+pub trait Logspace<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    /// A logarithmically spaced vectors builder. It returns
+    /// [`LogspaceVectorParams`] with the following default value:
+    ///
+    /// ```ignore
+    /// LogspaceVectorParams{
+    ///     start: 0,
+    ///     stop: None,
+    ///     size: 10,
+    ///     base: 10,
+    /// }
+    /// ```
+    ///
+    /// The parameters can be configured using the following methods:
+    /// [`start_at()`], [`stop_at()`], [`with_size()`] and [`base()`]. The
+    /// only required method is [`stop_at()`].
+    ///
+    /// After the parameters are configured, use [`generate()`] to generate
+    /// the logarithmically spaced vector. The values are `base` raised to
+    /// the power of a [linearly spaced] sequence within `[start, stop]`.
+    ///
+    /// [`LogspaceVectorParams`]: struct.LogspaceVectorParams.html
+    /// [`start_at()`]: struct.LogspaceVectorParams.html#method.start_at
+    /// [`stop_at()`]: struct.LogspaceVectorParams.html#method.stop_at
+    /// [`with_size()`]: struct.LogspaceVectorParams.html#method.with_size
+    /// [`base()`]: struct.LogspaceVectorParams.html#method.base
+    /// [`generate()`]: struct.LogspaceVectorParams.html#method.generate
+    /// [linearly spaced]: ../linspace/trait.Linspace.html#tymethod.linspace
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// // Generate logarithmically spaced vector within interval [2.0, 3.0]
+    /// let log: Vec<f32> = Vec::logspace()
+    ///     .start_at(2.0)
+    ///     .stop_at(3.0)
+    ///     .with_size(4)
+    ///     .generate()
+    ///     ;
+    ///
+    /// assert_eq!(log, [100.0, 215.44344, 464.15897, 1000.0]);
+    /// ```
+    ///
+    fn logspace() -> LogspaceVectorParams<T>;
+}
+
+impl<T> Logspace<T> for Vec<T>
+where
+    T: Float + FromPrimitive + AddAssign,
+{
+    fn logspace() -> LogspaceVectorParams<T> {
+        LogspaceVectorParams {
+            start: None,
+            stop: None,
+            size: 10,
+            base: T::from_i32(10).unwrap(),
+        }
+    }
+}
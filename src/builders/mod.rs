@@ -21,8 +21,9 @@
 //! 1. Conversion from other Rust primitive types: [`array`] and [`slice`].
 //! 2. Using Gulali's vector builder routines (e.g., [`ones()`],
 //!    [`zeros()`], etc.)
-//! 3. Reading vectors from disk, either from standard or
-//!    custom formats *(Not available yet)*
+//! 3. Reading vectors from disk, either from delimited text
+//!    ([`Vector::from_csv`]) or a binary `.npy` file
+//!    ([`Vector::load_npy`]).
 //! 4. Creating vectors from raw bytes through the use of
 //!    strings or buffers *(Not available yet)*
 //!
@@ -32,6 +33,8 @@
 //!
 //! [`array`]: https://doc.rust-lang.org/std/primitive.array.html
 //! [`slice`]: https://doc.rust-lang.org/std/slice/index.html
+//! [`Vector::from_csv`]: ../struct.Vector.html#method.from_csv
+//! [`Vector::load_npy`]: ../struct.Vector.html#method.load_npy
 //!
 //! # Converting Array and Slice to Vector
 //! In general, numerical data arranged in an array-like structure
@@ -96,6 +99,17 @@ pub mod dimensional;
 /// Fill vectors with specified value
 pub mod full;
 
+/// Geometrically spaced vector builder
+pub mod geomspace;
+
+/// Logarithmically spaced vector builder
+pub mod logspace;
+
+/// Arbitrary-rank array builder, replacing the old rank-specific
+/// `OneDimensionalVectorParams`/`TwoDimensionalVectorParams`/
+/// `ThreeDimensionalVectorParams`/`FourDimensionalVectorParams` ladder.
+pub mod nd;
+
 /// Fill vectors with 1 value
 pub mod ones;
 
@@ -0,0 +1,364 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use num::{FromPrimitive, Num};
+
+/// An arbitrary-rank array: a flat, row-major buffer of elements plus the
+/// shape it is interpreted with.
+///
+/// This replaces the old `OneDimensional`/`TwoDimensional`/
+/// `ThreeDimensional`/`FourDimensional` ladder of rank-specific builders
+/// (one struct per rank, each with its own quadruple-nested-`Vec` style
+/// `generate()`). A single flat buffer scales to any rank with one
+/// allocation, instead of a combinatorial explosion of nested pushes.
+pub struct NdArray<T>
+where
+    T: Num + FromPrimitive + Copy,
+{
+    data: Vec<T>,
+    shape: Vec<usize>,
+}
+
+impl<T> NdArray<T>
+where
+    T: Num + FromPrimitive + Copy,
+{
+    /// The shape of the array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[2, 3]).zeros().generate();
+    /// assert_eq!(a.shape(), &[2, 3]);
+    /// ```
+    pub fn shape(&self) -> &[usize] {
+        &self.shape
+    }
+
+    /// Borrows the underlying elements as a flat, row-major slice.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[2, 2]).full_of(7).generate();
+    /// assert_eq!(a.as_slice(), &[7, 7, 7, 7]);
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Reinterprets the array with a new shape, without moving any
+    /// elements.
+    ///
+    /// # Panics
+    /// Panics if `new_shape`'s total element count doesn't match the
+    /// array's current total element count.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[2, 3]).zeros().generate();
+    /// let b = a.reshape(&[3, 2]);
+    /// assert_eq!(b.shape(), &[3, 2]);
+    /// ```
+    pub fn reshape(&self, new_shape: &[usize]) -> NdArray<T> {
+        let total: usize = new_shape.iter().product();
+        if total != self.data.len() {
+            panic!(
+                "cannot reshape array of {} elements into shape {:?}",
+                self.data.len(),
+                new_shape
+            );
+        }
+        NdArray {
+            data: self.data.clone(),
+            shape: new_shape.to_vec(),
+        }
+    }
+
+    /// Views a rank-2 array as nested rows, the same shape that
+    /// [`TwoDimensional`] used to produce directly.
+    ///
+    /// # Panics
+    /// Panics if the array's rank is not exactly 2.
+    ///
+    /// [`TwoDimensional`]: ../dimensional/trait.TwoDimensional.html
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[2, 2]).full_of(3).generate();
+    /// assert_eq!(a.to_matrix(), [[3, 3], [3, 3]]);
+    /// ```
+    pub fn to_matrix(&self) -> Vec<Vec<T>> {
+        if self.shape.len() != 2 {
+            panic!(
+                "cannot view a rank-{} array as a matrix, shape {:?} is not rank 2",
+                self.shape.len(),
+                self.shape
+            );
+        }
+        let ncols = self.shape[1];
+        self.data.chunks(ncols).map(|row| row.to_vec()).collect()
+    }
+}
+
+/// Arbitrary-rank array parameters.
+pub struct NdBuilder<T>
+where
+    T: Num + FromPrimitive + Copy,
+{
+    /// The shape of the array.
+    ///
+    /// The shape of the array can be specified using [`with_shape()`].
+    ///
+    /// [`with_shape()`]: #method.with_shape
+    pub shape: Option<Vec<usize>>,
+
+    /// Default value for each element of the array.
+    ///
+    /// The default value for each element of the array can be specified
+    /// using [`full_of()`], [`zeros()`] and [`ones()`].
+    ///
+    /// [`full_of()`]: #method.full_of
+    /// [`zeros()`]: #method.zeros
+    /// [`ones()`]: #method.ones
+    pub default_value: Option<T>,
+}
+
+impl<T> NdBuilder<T>
+where
+    T: Num + FromPrimitive + Copy,
+{
+    /// Set the shape of the array. `shape` can be of any length, unlike
+    /// the fixed `[usize; N]` shapes the old per-rank builders required.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[1, 1, 1, 2]).zeros().generate();
+    /// assert_eq!(a.shape(), &[1, 1, 1, 2]);
+    /// ```
+    pub fn with_shape(&self, shape: &[usize]) -> NdBuilder<T> {
+        NdBuilder {
+            shape: Some(shape.to_vec()),
+            default_value: None,
+        }
+    }
+
+    /// Set the value of all elements of the array with `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[1, 2]).full_of(5).generate();
+    /// assert_eq!(a.as_slice(), &[5, 5]);
+    /// ```
+    pub fn full_of(&self, value: T) -> NdBuilder<T> {
+        NdBuilder {
+            shape: self.shape.clone(),
+            default_value: Some(value),
+        }
+    }
+
+    /// Set the value of all elements of the array with zeros.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[1, 2]).zeros().generate();
+    /// assert_eq!(a.as_slice(), &[0, 0]);
+    /// ```
+    pub fn zeros(&self) -> NdBuilder<T> {
+        self.full_of(T::from_i32(0).unwrap())
+    }
+
+    /// Set the value of all elements of the array with ones.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[1, 2]).ones().generate();
+    /// assert_eq!(a.as_slice(), &[1, 1]);
+    /// ```
+    pub fn ones(&self) -> NdBuilder<T> {
+        self.full_of(T::from_i32(1).unwrap())
+    }
+
+    /// Generate the array based on the configured parameters.
+    ///
+    /// # Panics
+    /// Panics if the `shape` is not specified.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[1, 2]).ones().generate();
+    /// assert_eq!(a.as_slice(), &[1, 1]);
+    /// ```
+    pub fn generate(&self) -> NdArray<T> {
+        let default_value = self.default_value.unwrap_or_else(T::zero);
+        self.from_fn(|_indices| default_value)
+    }
+
+    /// Generate the array by calling `f` with the row-major indices of
+    /// every element, in order.
+    ///
+    /// # Panics
+    /// Panics if the `shape` is not specified.
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// // Fill a 2x3 array with the sum of each element's indices.
+    /// let a: NdArray<i32> = Vec::nd()
+    ///     .with_shape(&[2, 3])
+    ///     .from_fn(|indices| (indices[0] + indices[1]) as i32);
+    /// assert_eq!(a.as_slice(), &[0, 1, 2, 1, 2, 3]);
+    /// ```
+    pub fn from_fn<F>(&self, mut f: F) -> NdArray<T>
+    where
+        F: FnMut(&[usize]) -> T,
+    {
+        let shape = match &self.shape {
+            Some(shape) => shape.clone(),
+            None => panic!("array's shape should be specified"),
+        };
+        let total: usize = shape.iter().product();
+        let mut data = Vec::with_capacity(total);
+        let mut indices = vec![0; shape.len()];
+        for _ in 0..total {
+            data.push(f(&indices));
+            // Odometer-style increment: bump the last axis, carrying into
+            // the axis before it whenever it wraps around its extent.
+            for axis in (0..shape.len()).rev() {
+                indices[axis] += 1;
+                if indices[axis] < shape[axis] {
+                    break;
+                }
+                indices[axis] = 0;
+            }
+        }
+        NdArray { data, shape }
+    }
+}
+
+/// Arbitrary-rank array builder.
+pub trait NdDimensional<T>
+where
+    T: Num + FromPrimitive + Copy,
+{
+    /// Arbitrary-rank array builder. It returns [`NdBuilder`] with default
+    /// value:
+    ///
+    /// ```ignore
+    /// NdBuilder {
+    ///     shape: None,
+    ///     default_value: None,
+    /// }
+    /// ```
+    ///
+    /// The parameter can be configured using the following methods:
+    /// [`with_shape()`], [`full_of()`], [`ones()`] and [`zeros()`]. After
+    /// the array parameters are configured, use [`generate()`] or
+    /// [`from_fn()`] to generate the array.
+    ///
+    /// [`NdBuilder`]: struct.NdBuilder.html
+    /// [`with_shape()`]: struct.NdBuilder.html#method.with_shape
+    /// [`full_of()`]: struct.NdBuilder.html#method.full_of
+    /// [`zeros()`]: struct.NdBuilder.html#method.zeros
+    /// [`ones()`]: struct.NdBuilder.html#method.ones
+    /// [`generate()`]: struct.NdBuilder.html#method.generate
+    /// [`from_fn()`]: struct.NdBuilder.html#method.from_fn
+    ///
+    /// # Examples
+    /// ```
+    /// # use gulali::prelude::*;
+    /// let a: NdArray<i32> = Vec::nd().with_shape(&[1, 1, 1, 2]).ones().generate();
+    /// assert_eq!(a.as_slice(), &[1, 1]);
+    /// ```
+    fn nd() -> NdBuilder<T>;
+}
+
+impl<T> NdDimensional<T> for Vec<T>
+where
+    T: Num + FromPrimitive + Copy,
+{
+    fn nd() -> NdBuilder<T> {
+        NdBuilder {
+            shape: None,
+            default_value: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_shape_and_zeros() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[2, 2]).zeros().generate();
+        assert_eq!(a.shape(), &[2, 2]);
+        assert_eq!(a.as_slice(), &[0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn test_ones() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[3]).ones().generate();
+        assert_eq!(a.as_slice(), &[1, 1, 1]);
+    }
+
+    #[test]
+    fn test_full_of() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[1, 1, 1, 2]).full_of(5).generate();
+        assert_eq!(a.as_slice(), &[5, 5]);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let a: NdArray<i32> = Vec::nd()
+            .with_shape(&[2, 3])
+            .from_fn(|indices| (indices[0] * 3 + indices[1]) as i32);
+        assert_eq!(a.as_slice(), &[0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_reshape() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[2, 3]).zeros().generate();
+        let b = a.reshape(&[3, 2]);
+        assert_eq!(b.shape(), &[3, 2]);
+        assert_eq!(b.as_slice(), a.as_slice());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_reshape_invalid() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[2, 3]).zeros().generate();
+        a.reshape(&[4, 4]);
+    }
+
+    #[test]
+    fn test_to_matrix() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[2, 2]).full_of(3).generate();
+        assert_eq!(a.to_matrix(), [[3, 3], [3, 3]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_to_matrix_wrong_rank() {
+        let a: NdArray<i32> = Vec::nd().with_shape(&[2, 2, 2]).zeros().generate();
+        a.to_matrix();
+    }
+}
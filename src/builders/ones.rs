@@ -0,0 +1,73 @@
+use crate::builders::full::*;
+
+/// A one-able vectors
+///
+/// Mirrors [`Zero`] exactly, but fills with each scalar type's
+/// [`num::One`] value instead of its [`num::Zero`] value. See [`Zero`]'s
+/// docs for why the scalar base case is registered one type at a time
+/// via [`impl_one_scalar!`] rather than as a single blanket impl.
+///
+/// [`Zero`]: ../zeros/trait.Zero.html
+/// [`num::One`]: https://docs.rs/num/0.2/num/trait.One.html
+/// [`num::Zero`]: https://docs.rs/num/0.2/num/trait.Zero.html
+/// [`impl_one_scalar!`]: macro.impl_one_scalar.html
+pub trait One {
+    /// Return a new vector of given data type and shape,
+    /// filled with ones.
+    fn ones(&mut self) -> Self;
+}
+
+macro_rules! impl_one_scalar {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl One for Vec<$t> {
+                fn ones(&mut self) -> Vec<$t> {
+                    self.full(<$t as num::One>::one())
+                }
+            }
+        )*
+    };
+}
+
+impl_one_scalar!(
+    u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64
+);
+
+impl<T> One for Vec<T>
+where
+    T: One,
+{
+    fn ones(&mut self) -> Vec<T> {
+        self.iter_mut().map(|x| x.ones()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_ones_one_dim() {
+        let arr1: Vec<i32> = Vec::one_dim(2).ones();
+        assert_eq!(arr1, [1, 1]);
+    }
+
+    #[test]
+    fn test_ones_two_dim() {
+        let arr2: Vec<Vec<f64>> = Vec::two_dim(1, 2).ones();
+        assert_eq!(arr2, [[1.0, 1.0]]);
+    }
+
+    #[test]
+    fn test_ones_three_dim() {
+        let arr3: Vec<Vec<Vec<f64>>> = Vec::three_dim(1, 1, 2).ones();
+        assert_eq!(arr3, [[[1.0, 1.0]]]);
+    }
+
+    #[test]
+    fn test_ones_four_dim() {
+        let arr4: Vec<Vec<Vec<Vec<f64>>>> = Vec::four_dim(1, 1, 1, 2).ones();
+        assert_eq!(arr4, [[[[1.0, 1.0]]]]);
+    }
+}
@@ -1,297 +1,55 @@
 use crate::builders::full::*;
 
 /// A zero-able vectors
+///
+/// Implemented directly for `Vec<T>` where `T` is one of this crate's
+/// supported scalar types (the base case, filling with `T`'s
+/// [`num::Zero`] value), and recursively for `Vec<T>` where `T` is
+/// itself zero-able (each wrapper layer maps [`zeros()`] over its
+/// existing, capacity-reserved elements). This covers any nesting depth
+/// built with the [dimensional builders], not just the one-, two-,
+/// three- and four-dimensional shapes this crate ships helpers for.
+///
+/// A blanket `impl<T: num::Zero> Zero for Vec<T>` would be the more
+/// obvious base case, but it's rejected by coherence: it would overlap
+/// with the recursive `impl<T: Zero> Zero for Vec<T>` below, since
+/// nothing stops `T` from itself being `Vec<U>`. Scalar types are
+/// therefore registered one at a time via [`impl_zero_scalar!`]; adding
+/// support for a new scalar type is a one-line macro invocation, not a
+/// impl per nesting depth.
+///
+/// [`zeros()`]: #tymethod.zeros
+/// [dimensional builders]: ../dimensional/index.html
+/// [`num::Zero`]: https://docs.rs/num/0.2/num/trait.Zero.html
+/// [`impl_zero_scalar!`]: macro.impl_zero_scalar.html
 pub trait Zero {
     /// Return a new vector of given data type and shape,
     /// filled with zeros.
     fn zeros(&mut self) -> Self;
 }
 
-impl Zero for Vec<u8> {
-    fn zeros(&mut self) -> Vec<u8> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<u8>> {
-    fn zeros(&mut self) -> Vec<Vec<u8>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<u8>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<u8>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<u8>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<u8>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<u16> {
-    fn zeros(&mut self) -> Vec<u16> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<u16>> {
-    fn zeros(&mut self) -> Vec<Vec<u16>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<u16>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<u16>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<u16>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<u16>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<u32> {
-    fn zeros(&mut self) -> Vec<u32> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<u32>> {
-    fn zeros(&mut self) -> Vec<Vec<u32>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<u32>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<u32>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<u32>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<u32>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<u64> {
-    fn zeros(&mut self) -> Vec<u64> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<u64>> {
-    fn zeros(&mut self) -> Vec<Vec<u64>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<u64>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<u64>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<u64>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<u64>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<u128> {
-    fn zeros(&mut self) -> Vec<u128> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<u128>> {
-    fn zeros(&mut self) -> Vec<Vec<u128>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<u128>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<u128>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<u128>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<u128>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<i8> {
-    fn zeros(&mut self) -> Vec<i8> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<i8>> {
-    fn zeros(&mut self) -> Vec<Vec<i8>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<i8>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<i8>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<i8>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<i8>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<i16> {
-    fn zeros(&mut self) -> Vec<i16> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<i16>> {
-    fn zeros(&mut self) -> Vec<Vec<i16>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<i16>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<i16>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<i16>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<i16>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<i32> {
-    fn zeros(&mut self) -> Vec<i32> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<i32>> {
-    fn zeros(&mut self) -> Vec<Vec<i32>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<i32>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<i32>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<i32>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<i32>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<i64> {
-    fn zeros(&mut self) -> Vec<i64> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<i64>> {
-    fn zeros(&mut self) -> Vec<Vec<i64>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<i64>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<i64>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<i64>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<i64>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<i128> {
-    fn zeros(&mut self) -> Vec<i128> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<i128>> {
-    fn zeros(&mut self) -> Vec<Vec<i128>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<i128>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<i128>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<i128>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<i128>>>> {
-        self.full(0)
-    }
-}
-
-impl Zero for Vec<f32> {
-    fn zeros(&mut self) -> Vec<f32> {
-        self.full(0.0)
-    }
-}
-
-impl Zero for Vec<Vec<f32>> {
-    fn zeros(&mut self) -> Vec<Vec<f32>> {
-        self.full(0.0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<f32>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<f32>>> {
-        self.full(0.0)
-    }
-}
-
-impl Zero for Vec<Vec<Vec<Vec<f32>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<f32>>>> {
-        self.full(0.0)
-    }
-}
-
-impl Zero for Vec<f64> {
-    fn zeros(&mut self) -> Vec<f64> {
-        self.full(0.0)
-    }
-}
-
-impl Zero for Vec<Vec<f64>> {
-    fn zeros(&mut self) -> Vec<Vec<f64>> {
-        self.full(0.0)
-    }
+macro_rules! impl_zero_scalar {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl Zero for Vec<$t> {
+                fn zeros(&mut self) -> Vec<$t> {
+                    self.full(<$t as num::Zero>::zero())
+                }
+            }
+        )*
+    };
 }
 
-impl Zero for Vec<Vec<Vec<f64>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<f64>>> {
-        self.full(0.0)
-    }
-}
+impl_zero_scalar!(
+    u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64
+);
 
-impl Zero for Vec<Vec<Vec<Vec<f64>>>> {
-    fn zeros(&mut self) -> Vec<Vec<Vec<Vec<f64>>>> {
-        self.full(0.0)
+impl<T> Zero for Vec<T>
+where
+    T: Zero,
+{
+    fn zeros(&mut self) -> Vec<T> {
+        self.iter_mut().map(|x| x.zeros()).collect()
     }
 }
 
@@ -301,290 +59,38 @@ mod tests {
     use crate::prelude::*;
 
     #[test]
-    fn test_zeros_u8_one_dim() {
-        let arr: Vec<u8> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_u8_two_dim() {
-        let arr: Vec<Vec<u8>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_u8_three_dim() {
-        let arr: Vec<Vec<Vec<u8>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_u8_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<u8>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_u16_one_dim() {
-        let arr: Vec<u16> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_u16_two_dim() {
-        let arr: Vec<Vec<u16>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_u16_three_dim() {
-        let arr: Vec<Vec<Vec<u16>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_u16_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<u16>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_u32_one_dim() {
-        let arr: Vec<u32> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_u32_two_dim() {
-        let arr: Vec<Vec<u32>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_u32_three_dim() {
-        let arr: Vec<Vec<Vec<u32>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
+    fn test_zeros_one_dim() {
+        let arr1: Vec<i32> = Vec::one_dim(2).zeros();
+        assert_eq!(arr1, [0, 0]);
     }
 
     #[test]
-    fn test_zeros_u32_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<u32>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_u64_one_dim() {
-        let arr: Vec<u64> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
+    fn test_zeros_two_dim() {
+        let arr2: Vec<Vec<f64>> = Vec::two_dim(1, 2).zeros();
+        assert_eq!(arr2, [[0.0, 0.0]]);
     }
 
     #[test]
-    fn test_zeros_u64_two_dim() {
-        let arr: Vec<Vec<u64>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
+    fn test_zeros_three_dim() {
+        let arr3: Vec<Vec<Vec<f64>>> = Vec::three_dim(1, 1, 2).zeros();
+        assert_eq!(arr3, [[[0.0, 0.0]]]);
     }
 
     #[test]
-    fn test_zeros_u64_three_dim() {
-        let arr: Vec<Vec<Vec<u64>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
+    fn test_zeros_four_dim() {
+        let arr4: Vec<Vec<Vec<Vec<f64>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
+        assert_eq!(arr4, [[[[0.0, 0.0]]]]);
     }
 
     #[test]
-    fn test_zeros_u64_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<u64>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_u128_one_dim() {
+    fn test_zeros_u128() {
         let arr: Vec<u128> = Vec::one_dim(2).zeros();
         assert_eq!(arr, [0, 0]);
     }
 
     #[test]
-    fn test_zeros_u128_two_dim() {
-        let arr: Vec<Vec<u128>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_u128_three_dim() {
-        let arr: Vec<Vec<Vec<u128>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_u128_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<u128>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_i8_one_dim() {
-        let arr: Vec<i8> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_i8_two_dim() {
-        let arr: Vec<Vec<i8>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_i8_three_dim() {
-        let arr: Vec<Vec<Vec<i8>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_i8_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<i8>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_i16_one_dim() {
-        let arr: Vec<i16> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_i16_two_dim() {
-        let arr: Vec<Vec<i16>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_i16_three_dim() {
-        let arr: Vec<Vec<Vec<i16>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_i16_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<i16>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_i32_one_dim() {
-        let arr: Vec<i32> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_i32_two_dim() {
-        let arr: Vec<Vec<i32>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_i32_three_dim() {
-        let arr: Vec<Vec<Vec<i32>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_i32_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<i32>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_i64_one_dim() {
-        let arr: Vec<i64> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0, 0]);
-    }
-
-    #[test]
-    fn test_zeros_i64_two_dim() {
-        let arr: Vec<Vec<i64>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_i64_three_dim() {
-        let arr: Vec<Vec<Vec<i64>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_i64_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<i64>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_i128_one_dim() {
+    fn test_zeros_i128() {
         let arr: Vec<i128> = Vec::one_dim(2).zeros();
         assert_eq!(arr, [0, 0]);
     }
-
-    #[test]
-    fn test_zeros_i128_two_dim() {
-        let arr: Vec<Vec<i128>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0, 0]]);
-    }
-
-    #[test]
-    fn test_zeros_i128_three_dim() {
-        let arr: Vec<Vec<Vec<i128>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0, 0]]]);
-    }
-
-    #[test]
-    fn test_zeros_i128_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<i128>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0, 0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_f32_one_dim() {
-        let arr: Vec<f32> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0.0, 0.0]);
-    }
-
-    #[test]
-    fn test_zeros_f32_two_dim() {
-        let arr: Vec<Vec<f32>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0.0, 0.0]]);
-    }
-
-    #[test]
-    fn test_zeros_f32_three_dim() {
-        let arr: Vec<Vec<Vec<f32>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0.0, 0.0]]]);
-    }
-
-    #[test]
-    fn test_zeros_f32_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<f32>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0.0, 0.0]]]]);
-    }
-
-    #[test]
-    fn test_zeros_f64_one_dim() {
-        let arr: Vec<f64> = Vec::one_dim(2).zeros();
-        assert_eq!(arr, [0.0, 0.0]);
-    }
-
-    #[test]
-    fn test_zeros_f64_two_dim() {
-        let arr: Vec<Vec<f64>> = Vec::two_dim(1, 2).zeros();
-        assert_eq!(arr, [[0.0, 0.0]]);
-    }
-
-    #[test]
-    fn test_zeros_f64_three_dim() {
-        let arr: Vec<Vec<Vec<f64>>> = Vec::three_dim(1, 1, 2).zeros();
-        assert_eq!(arr, [[[0.0, 0.0]]]);
-    }
-
-    #[test]
-    fn test_zeros_f64_four_dim() {
-        let arr: Vec<Vec<Vec<Vec<f64>>>> = Vec::four_dim(1, 1, 1, 2).zeros();
-        assert_eq!(arr, [[[[0.0, 0.0]]]]);
-    }
 }
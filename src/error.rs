@@ -12,102 +12,97 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
-use csv;
+//! Crate-wide error type.
+//!
+//! [`CrabsformerError`] unifies every fallible constructor and loader in the
+//! crate behind a single `Result<_, CrabsformerError>`, so downstream crates
+//! do not need to match on `VectorBuilderError`, `MatrixBuilderError` and
+//! `MatrixLoadError` separately.
+
+use crate::matrix::errors::{MatrixBuilderError, MatrixLoadError};
+use crate::vector::errors::VectorBuilderError;
 use std::convert;
+use std::error;
 use std::fmt;
-use std::io;
-
-/// Enum to store the various types of errors that can cause loading a numeric vector
-/// or matrix to fail.
-pub enum LoadErrorKind {
-    /// I/O Error
-    ///
-    /// Among other causes, this variant will be constructed when failed loading a file
-    /// due to I/O problem.
-    IOError,
-    /// CSV Error
-    ///
-    /// Among other causes, this variant will be constructed when failed loading a CSV file.
-    CSVError,
-    /// File being loaded is empty.
-    ///
-    /// Among other causes, this variant will be constructed when loading an empty file.
-    Empty,
-    /// Contains an invalid element.
-    ///
-    /// Among other causes, this variant will be constructed when parsing a string that
-    /// contains non-numeric letter.
-    InvalidElement,
-}
 
-/// An error which can be returned when loading numeric vector or matrix from a file.
+/// The crate-wide error type for Crabsformer.
+///
+/// This enum unifies [`VectorBuilderError`], [`MatrixBuilderError`] and
+/// [`MatrixLoadError`] so fallible constructors across the crate can expose
+/// a single error type.
 ///
-/// # Potential causes
-/// Among other causes, `LoadError` can be thrown because of loaded file is not exists.
-pub struct LoadError {
-    kind: LoadErrorKind,
-    message: String,
+/// [`VectorBuilderError`]: ../vector/errors/struct.VectorBuilderError.html
+/// [`MatrixBuilderError`]: ../matrix/errors/struct.MatrixBuilderError.html
+/// [`MatrixLoadError`]: ../matrix/errors/struct.MatrixLoadError.html
+#[derive(Debug)]
+pub enum CrabsformerError {
+    /// An error that occurred while building a numeric vector.
+    VectorBuilder(VectorBuilderError),
+    /// An error that occurred while building a matrix.
+    MatrixBuilder(MatrixBuilderError),
+    /// An error that occurred while loading a matrix from a file.
+    MatrixLoad(MatrixLoadError),
+    /// The shapes of two operands of an arithmetic operation (e.g. a
+    /// vector/matrix product, element-wise addition) don't agree.
+    ShapeMismatch {
+        /// The shape of the left-hand operand, e.g. `[rows, cols]` for a
+        /// matrix or `[len]` for a vector.
+        lhs: Vec<usize>,
+        /// The shape of the right-hand operand.
+        rhs: Vec<usize>,
+    },
+    /// A square matrix has no inverse, e.g. because Gaussian elimination
+    /// ran out of nonzero pivots.
+    NotInvertible,
 }
 
-impl LoadError {
-    /// Creates a new `LoadError` from a known kind of error as well as an error message.
-    pub fn new(kind: LoadErrorKind, message: String) -> LoadError {
-        LoadError { kind, message }
-    }
-
-    /// Outputs the detailed cause of loading file failing.
-    pub fn kind(&self) -> &LoadErrorKind {
-        &self.kind
-    }
-
-    fn description(&self) -> String {
-        match self.kind {
-            LoadErrorKind::IOError => format!(
-                "Cannot load Matrix from file due to: {}",
-                self.message
+impl fmt::Display for CrabsformerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrabsformerError::VectorBuilder(error) => write!(f, "{}", error),
+            CrabsformerError::MatrixBuilder(error) => write!(f, "{}", error),
+            CrabsformerError::MatrixLoad(error) => write!(f, "{}", error),
+            CrabsformerError::ShapeMismatch { lhs, rhs } => write!(
+                f,
+                "shape mismatch: {:?} is not compatible with {:?}",
+                lhs, rhs
             ),
-            LoadErrorKind::CSVError => {
-                format!("Cannot load Matrix, {}", self.message)
+            CrabsformerError::NotInvertible => {
+                write!(f, "matrix is not invertible")
             }
-            LoadErrorKind::Empty => {
-                format!("Cannot load Matrix from empty file")
-            }
-            LoadErrorKind::InvalidElement => format!(
-                "Cannot load Matrix, invalid element: {}",
-                self.message
-            ),
         }
     }
 }
 
-/// Convert `io::Error` to `matrix::LoadError`
-impl convert::From<io::Error> for LoadError {
-    fn from(error: io::Error) -> Self {
-        LoadError {
-            kind: LoadErrorKind::IOError,
-            message: format!("{}", error),
+impl error::Error for CrabsformerError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CrabsformerError::VectorBuilder(error) => Some(error),
+            CrabsformerError::MatrixBuilder(error) => Some(error),
+            CrabsformerError::MatrixLoad(error) => Some(error),
+            CrabsformerError::ShapeMismatch { .. } => None,
+            CrabsformerError::NotInvertible => None,
         }
     }
 }
 
-/// Convert `csv::Error` to `matrix::LoadError`
-impl convert::From<csv::Error> for LoadError {
-    fn from(error: csv::Error) -> Self {
-        LoadError {
-            kind: LoadErrorKind::CSVError,
-            message: format!("{}", error),
-        }
+/// Convert `VectorBuilderError` to `CrabsformerError`
+impl convert::From<VectorBuilderError> for CrabsformerError {
+    fn from(error: VectorBuilderError) -> Self {
+        CrabsformerError::VectorBuilder(error)
     }
 }
 
-impl fmt::Debug for LoadError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+/// Convert `MatrixBuilderError` to `CrabsformerError`
+impl convert::From<MatrixBuilderError> for CrabsformerError {
+    fn from(error: MatrixBuilderError) -> Self {
+        CrabsformerError::MatrixBuilder(error)
     }
 }
 
-impl fmt::Display for LoadError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.description())
+/// Convert `MatrixLoadError` to `CrabsformerError`
+impl convert::From<MatrixLoadError> for CrabsformerError {
+    fn from(error: MatrixLoadError) -> Self {
+        CrabsformerError::MatrixLoad(error)
     }
 }
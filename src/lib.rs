@@ -467,7 +467,11 @@
 //! [Apache-2.0]: https://github.com/pyk/crabsformer/blob/master/LICENSE
 //!
 
+pub mod error;
 pub mod matrix;
 pub mod prelude;
+pub mod smatrix;
+pub mod stackvec;
+pub mod svector;
 pub mod utils;
 pub mod vector;
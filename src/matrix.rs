@@ -19,16 +19,27 @@
 
 use crate::vector::*;
 use num::Num;
+#[cfg(feature = "serde")]
+use serde::de::{self, Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeStruct, Serializer};
 use std::fmt;
 
 // Import all sub modules
+#[cfg(feature = "blas")]
+pub(crate) mod blas;
 pub mod builders;
 pub mod errors;
+pub mod galois;
 pub mod indexing;
 pub mod iterators;
 pub mod loaders;
 pub mod operations;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 pub mod slicing;
+pub mod sparse;
+pub mod stacking;
 
 /// Matrix.
 ///
@@ -126,6 +137,78 @@ where
     }
 }
 
+impl<T> Clone for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn clone(&self) -> Matrix<T> {
+        Matrix {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            vec: self.vec.clone(),
+        }
+    }
+}
+
+// Matrix is serialized as `{ "nrows": n, "ncols": m, "elements": [...] }`,
+// where `elements` is the row-major flattening of the matrix.
+#[cfg(feature = "serde")]
+impl<T> Serialize for Matrix<T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let elements: Vec<T> = self
+            .rows()
+            .flat_map(|row| row.elements().collect::<Vec<T>>())
+            .collect();
+        let mut state = serializer.serialize_struct("Matrix", 3)?;
+        state.serialize_field("nrows", &self.nrows)?;
+        state.serialize_field("ncols", &self.ncols)?;
+        state.serialize_field("elements", &elements)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+#[serde(rename = "Matrix")]
+struct MatrixData<T> {
+    nrows: usize,
+    ncols: usize,
+    elements: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for Matrix<T>
+where
+    T: Num + Copy + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = MatrixData::deserialize(deserializer)?;
+        if data.elements.len() != data.nrows * data.ncols {
+            return Err(de::Error::custom(format!(
+                "invalid matrix: expected {} elements for shape [{}, {}], found {}",
+                data.nrows * data.ncols,
+                data.nrows,
+                data.ncols,
+                data.elements.len()
+            )));
+        }
+        Ok(Matrix {
+            nrows: data.nrows,
+            ncols: data.ncols,
+            vec: Vector::from(data.elements),
+        })
+    }
+}
+
 /// Row matrix is reference to a row of a matrix.
 ///
 /// It is a `1xm` matrix where `m` is a number of columns.
@@ -193,6 +276,22 @@ where
     }
 }
 
+// A row matrix is serialized as a plain sequence of its visible elements,
+// the same way a `Vector` is; the `source` matrix and position/offset it
+// was borrowed from are not part of the wire representation.
+#[cfg(feature = "serde")]
+impl<'a, T> Serialize for RowMatrix<'a, T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.elements().collect::<Vec<T>>().serialize(serializer)
+    }
+}
+
 /// Column matrix is reference to a column of a matrix.
 ///
 /// It is a `nx1` matrix where `n` is a number of rows.
@@ -260,6 +359,59 @@ where
     }
 }
 
+// A column matrix is serialized as a plain sequence of its visible
+// elements, the same way a `RowMatrix` is.
+#[cfg(feature = "serde")]
+impl<'a, T> Serialize for ColumnMatrix<'a, T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.elements().collect::<Vec<T>>().serialize(serializer)
+    }
+}
+
+/// `RowMatrixMut` is a mutable reference to a row of the matrix. It is the
+/// mutable counterpart of [`RowMatrix`].
+///
+/// Unlike [`RowMatrix`], which borrows the whole matrix, `RowMatrixMut`
+/// holds a disjoint `&mut` slice of the row's own elements. This is what
+/// lets [`rows_mut`] hand out one of these per row of the same matrix at
+/// once without aliasing `&mut self`.
+///
+/// [`RowMatrix`]: struct.RowMatrix.html
+/// [`rows_mut`]: struct.Matrix.html#method.rows_mut
+pub struct RowMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // The row's own elements, disjoint from every other row's.
+    data: &'a mut [T],
+}
+
+/// `ColumnMatrixMut` is a mutable reference to a column of the matrix. It
+/// is the mutable counterpart of [`ColumnMatrix`].
+///
+/// Since a column's elements are interleaved with every other column's in
+/// the matrix's row-major backing store, `ColumnMatrixMut` can't borrow a
+/// single contiguous slice the way [`RowMatrixMut`] does. Instead it holds
+/// one `&mut` reference per element, built by re-slicing each row once;
+/// this is also what lets [`cols_mut`] hand out every column at once
+/// without aliasing `&mut self`.
+///
+/// [`ColumnMatrix`]: struct.ColumnMatrix.html
+/// [`RowMatrixMut`]: struct.RowMatrixMut.html
+/// [`cols_mut`]: struct.Matrix.html#method.cols_mut
+pub struct ColumnMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    elements: Vec<&'a mut T>,
+}
+
 /// Submatrix is a reference to a block of the elements in the matrix.
 ///
 /// TODO(pyk): Add visualization here based on this:
@@ -275,6 +427,10 @@ where
     // we use these to access the data from original matrix
     row_offset: usize,
     col_offset: usize,
+    // Step between sampled rows & columns in the original matrix; `1` for
+    // a contiguous (non-strided) submatrix produced by `slice`.
+    row_stride: usize,
+    col_stride: usize,
     // Original matrix; where to get the elements from
     source: &'a Matrix<T>,
 }
@@ -292,6 +448,11 @@ where
     pub fn offsets(&self) -> [usize; 2] {
         [self.row_offset, self.col_offset]
     }
+
+    // Strides of the submatrix `[row_stride, col_stride]`
+    pub fn strides(&self) -> [usize; 2] {
+        [self.row_stride, self.col_stride]
+    }
 }
 
 impl<'a, T> fmt::Debug for Submatrix<'a, T>
@@ -337,6 +498,7 @@ where
     fn eq(&self, other: &Submatrix<'a, T>) -> bool {
         if self.shape() == other.shape()
             && self.offsets() == other.offsets()
+            && self.strides() == other.strides()
             && self.source == other.source
         {
             true
@@ -348,6 +510,161 @@ where
     fn ne(&self, other: &Submatrix<'a, T>) -> bool {
         if self.shape() != other.shape()
             || self.offsets() != other.offsets()
+            || self.strides() != other.strides()
+            || self.source != other.source
+        {
+            true
+        } else {
+            false
+        }
+    }
+}
+
+// A submatrix is serialized the same way a `Matrix` is -- as
+// `{ "nrows": n, "ncols": m, "elements": [...] }`, where `elements` is the
+// row-major flattening of the visible elements. The `source` matrix and the
+// offsets/strides it was borrowed from are not part of the wire
+// representation.
+#[cfg(feature = "serde")]
+impl<'a, T> Serialize for Submatrix<'a, T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let elements: Vec<T> = (0..self.nrows)
+            .flat_map(|i| (0..self.ncols).map(move |j| *self.at(i, j)))
+            .collect();
+        let mut state = serializer.serialize_struct("Submatrix", 3)?;
+        state.serialize_field("nrows", &self.nrows)?;
+        state.serialize_field("ncols", &self.ncols)?;
+        state.serialize_field("elements", &elements)?;
+        state.end()
+    }
+}
+
+/// `SubmatrixMut` is a mutable reference to a block of the elements in the
+/// matrix. It is the mutable counterpart of [`Submatrix`].
+///
+/// [`Submatrix`]: struct.Submatrix.html
+pub struct SubmatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // The number of rows and columns of the SubmatrixMut
+    nrows: usize,
+    ncols: usize,
+    // Offset row & column from original matrix;
+    // we use these to access the data from original matrix
+    row_offset: usize,
+    col_offset: usize,
+    // Original matrix; where to get and write the elements to
+    source: &'a mut Matrix<T>,
+}
+
+impl<'a, T: 'a> SubmatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // Shape of the submatrix `[nrows, ncols]`
+    pub fn shape(&self) -> [usize; 2] {
+        [self.nrows, self.ncols]
+    }
+
+    // Offsets of the submatrix `[row_offset, col_offset]`
+    pub fn offsets(&self) -> [usize; 2] {
+        [self.row_offset, self.col_offset]
+    }
+}
+
+/// Diagonal is a view over the elements of a matrix (or submatrix) where
+/// `col - row == offset`: `0` selects the main diagonal, a positive offset
+/// a super-diagonal, a negative offset a sub-diagonal.
+pub struct Diagonal<'a, T>
+where
+    T: Num + Copy,
+{
+    // Offset from the main diagonal
+    offset: isize,
+    // Offset row & column of the view this diagonal was taken from; `0` for
+    // a plain matrix, the submatrix's own offsets for a submatrix diagonal
+    row_offset: usize,
+    col_offset: usize,
+    // Step between successive diagonal elements in the source matrix; `1`
+    // for a plain matrix, the submatrix's own strides for a submatrix
+    // diagonal
+    row_stride: usize,
+    col_stride: usize,
+    // Number of elements on the diagonal
+    len: usize,
+    // Original matrix; where to get the elements from
+    source: &'a Matrix<T>,
+}
+
+impl<'a, T: 'a> Diagonal<'a, T>
+where
+    T: Num + Copy,
+{
+    // Number of elements on the diagonal
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Whether the diagonal has no elements; always `false` since
+    // `Matrix::diagonal`/`Submatrix::diagonal` panic on an offset that would
+    // produce an empty diagonal.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<'a, T> fmt::Debug for Diagonal<'a, T>
+where
+    T: Num + Copy + fmt::Debug + ToString,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut output = String::from("[");
+        for (i, value) in self.elements().enumerate() {
+            output += &value.to_string();
+            if i < self.len - 1 {
+                output += ", ";
+            }
+        }
+        output += "]";
+        write!(f, "{}", output)
+    }
+}
+
+// Diagonal comparison
+// Diagonal is equal if the offset, origin and content are the same.
+impl<'a, T> PartialEq for Diagonal<'a, T>
+where
+    T: Num + Copy,
+{
+    fn eq(&self, other: &Diagonal<'a, T>) -> bool {
+        if self.offset == other.offset
+            && self.row_offset == other.row_offset
+            && self.col_offset == other.col_offset
+            && self.row_stride == other.row_stride
+            && self.col_stride == other.col_stride
+            && self.len == other.len
+            && self.source == other.source
+        {
+            true
+        } else {
+            false
+        }
+    }
+
+    fn ne(&self, other: &Diagonal<'a, T>) -> bool {
+        if self.offset != other.offset
+            || self.row_offset != other.row_offset
+            || self.col_offset != other.col_offset
+            || self.row_stride != other.row_stride
+            || self.col_stride != other.col_stride
+            || self.len != other.len
             || self.source != other.source
         {
             true
@@ -357,6 +674,40 @@ where
     }
 }
 
+/// `DiagonalMut` is a mutable reference to the elements on a diagonal band
+/// of a matrix. It is the mutable counterpart of [`Diagonal`].
+///
+/// [`Diagonal`]: struct.Diagonal.html
+pub struct DiagonalMut<'a, T>
+where
+    T: Num + Copy,
+{
+    offset: isize,
+    row_offset: usize,
+    col_offset: usize,
+    row_stride: usize,
+    col_stride: usize,
+    len: usize,
+    source: &'a mut Matrix<T>,
+}
+
+impl<'a, T: 'a> DiagonalMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // Number of elements on the diagonal
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Whether the diagonal has no elements; always `false` since
+    // `Matrix::diagonal_mut`/`SubmatrixMut::diagonal_mut` panic on an offset
+    // that would produce an empty diagonal.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -384,6 +735,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         println!("{:?}", s);
@@ -0,0 +1,127 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Optional fast path for `Matrix::try_dot`, gated behind the `blas`
+//! Cargo feature.
+//!
+//! `T` is generic over `Num + Copy` everywhere else in the crate, so
+//! there's no (stable) way to specialize `try_dot` for `f32`/`f64` at the
+//! trait level. Instead, [`try_dot_fast`] checks `TypeId` at runtime and,
+//! for those two types only, reinterprets the row-major buffers as
+//! `f32`/`f64` slices and hands them to [`matrixmultiply`]'s tuned GEMM
+//! kernels. Every other scalar type returns `None`, so `Matrix::try_dot`
+//! falls back to its generic triple-loop implementation.
+//!
+//! [`matrixmultiply`]: https://docs.rs/matrixmultiply
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num::Num;
+use std::any::TypeId;
+
+/// Attempts a BLAS-backed product of `a` and `b`, returning `None` if `T`
+/// isn't `f32` or `f64`.
+///
+/// Callers must have already checked that the shapes agree
+/// (`a.ncols == b.nrows`); this function assumes it.
+pub(crate) fn try_dot_fast<T>(a: &Matrix<T>, b: &Matrix<T>) -> Option<Matrix<T>>
+where
+    T: Num + Copy + 'static,
+{
+    let [m, k] = a.shape();
+    let [_, n] = b.shape();
+
+    if TypeId::of::<T>() == TypeId::of::<f32>() {
+        let a_data = cast_slice::<T, f32>(a.vec.as_slice());
+        let b_data = cast_slice::<T, f32>(b.vec.as_slice());
+        let mut data = vec![0.0f32; m * n];
+        unsafe {
+            matrixmultiply::sgemm(
+                m,
+                k,
+                n,
+                1.0,
+                a_data.as_ptr(),
+                k as isize,
+                1,
+                b_data.as_ptr(),
+                n as isize,
+                1,
+                0.0,
+                data.as_mut_ptr(),
+                n as isize,
+                1,
+            );
+        }
+        return Some(build_matrix(m, n, cast_vec::<f32, T>(data)));
+    }
+
+    if TypeId::of::<T>() == TypeId::of::<f64>() {
+        let a_data = cast_slice::<T, f64>(a.vec.as_slice());
+        let b_data = cast_slice::<T, f64>(b.vec.as_slice());
+        let mut data = vec![0.0f64; m * n];
+        unsafe {
+            matrixmultiply::dgemm(
+                m,
+                k,
+                n,
+                1.0,
+                a_data.as_ptr(),
+                k as isize,
+                1,
+                b_data.as_ptr(),
+                n as isize,
+                1,
+                0.0,
+                data.as_mut_ptr(),
+                n as isize,
+                1,
+            );
+        }
+        return Some(build_matrix(m, n, cast_vec::<f64, T>(data)));
+    }
+
+    None
+}
+
+fn build_matrix<T>(nrows: usize, ncols: usize, data: Vec<T>) -> Matrix<T>
+where
+    T: Num + Copy,
+{
+    Matrix {
+        nrows,
+        ncols,
+        vec: Vector::from(data),
+    }
+}
+
+// Reinterprets `s` as a slice of `U`. Only called once the caller has
+// confirmed via `TypeId` that `T` and `U` are the same type, so the
+// layouts are guaranteed to match.
+fn cast_slice<T: 'static, U: 'static>(s: &[T]) -> &[U] {
+    debug_assert_eq!(TypeId::of::<T>(), TypeId::of::<U>());
+    unsafe { std::slice::from_raw_parts(s.as_ptr() as *const U, s.len()) }
+}
+
+// Reinterprets `v` as a `Vec<U>`. Only called once the caller has
+// confirmed via `TypeId` that `T` and `U` are the same type, so the
+// layouts (including allocator capacity/length) are guaranteed to match.
+fn cast_vec<T: 'static, U: 'static>(mut v: Vec<T>) -> Vec<U> {
+    debug_assert_eq!(TypeId::of::<T>(), TypeId::of::<U>());
+    let ptr = v.as_mut_ptr() as *mut U;
+    let len = v.len();
+    let cap = v.capacity();
+    std::mem::forget(v);
+    unsafe { Vec::from_raw_parts(ptr, len, cap) }
+}
@@ -18,11 +18,14 @@
 //!
 //!
 
+use crate::error::CrabsformerError;
 use crate::matrix::errors::MatrixBuilderError;
 use crate::matrix::Matrix;
 use crate::vector::builders::RandomVectorBuilder;
-use num::{FromPrimitive, Num};
+use crate::vector::Vector;
+use num::{Float, FromPrimitive, Num, ToPrimitive};
 use rand::distributions::uniform::SampleUniform;
+use rand::Rng;
 use std::fmt;
 
 /// Creates a [matrix] containing the arguments.
@@ -225,6 +228,91 @@ where
     {
         matrix![m.shape() => T::from_i32(1).unwrap()]
     }
+
+    /// Create a new square identity matrix of size `n`, i.e. with ones on
+    /// the main diagonal and zeros everywhere else.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w: Matrix<i32> = Matrix::eye(3);
+    /// assert_eq!(w, matrix![1, 0, 0; 0, 1, 0; 0, 0, 1]);
+    /// ```
+    pub fn eye(n: usize) -> Matrix<T>
+    where
+        T: FromPrimitive,
+    {
+        let zero = T::from_i32(0).unwrap();
+        let one = T::from_i32(1).unwrap();
+        let mut data = vec![zero; n * n];
+        for i in 0..n {
+            data[i * n + i] = one;
+        }
+        Matrix::from_vector(Vector::from(data), n).unwrap()
+    }
+
+    /// Builds a matrix with `cols` columns out of a numeric vector `v`,
+    /// filling it in row-major order. The number of rows is derived from
+    /// `v.len() / cols`.
+    ///
+    /// Returns a [`CrabsformerError::ShapeMismatch`] if `cols` doesn't
+    /// evenly divide `v.len()`.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let v = vector![1, 2, 3, 4, 5, 6];
+    /// let w = Matrix::from_vector(v, 3).unwrap();
+    /// assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+    /// ```
+    pub fn from_vector(v: Vector<T>, cols: usize) -> Result<Matrix<T>, CrabsformerError> {
+        let len = v.len();
+        if cols == 0 || len % cols != 0 {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![len],
+                rhs: vec![len / cols.max(1), cols],
+            });
+        }
+
+        Ok(Matrix {
+            nrows: len / cols,
+            ncols: cols,
+            vec: v,
+        })
+    }
+
+    /// Reinterprets this matrix as a new matrix of shape `[rows, cols]`,
+    /// keeping its elements in row-major order.
+    ///
+    /// Returns a [`CrabsformerError::ShapeMismatch`] if `rows * cols`
+    /// doesn't equal the total number of elements in the matrix.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1, 2, 3; 4, 5, 6];
+    /// let reshaped = w.reshape(3, 2).unwrap();
+    /// assert_eq!(reshaped, matrix![1, 2; 3, 4; 5, 6]);
+    /// ```
+    pub fn reshape(self, rows: usize, cols: usize) -> Result<Matrix<T>, CrabsformerError> {
+        if rows * cols != self.nrows * self.ncols {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.nrows, self.ncols],
+                rhs: vec![rows, cols],
+            });
+        }
+
+        Ok(Matrix {
+            nrows: rows,
+            ncols: cols,
+            vec: self.vec,
+        })
+    }
 }
 
 /// Random matrices builder.
@@ -288,6 +376,44 @@ impl RandomMatrixBuilder {
         })
     }
 
+    /// Create a new matrix of the given shape `shape`, populated with
+    /// random samples from a uniform distribution over the half-open
+    /// interval `[low, high)`, drawing from the given `rng` instead of a
+    /// seeded builder instance. This lets callers pass any `rand::Rng`
+    /// (e.g. a seeded ISAAC or Xorshift generator) to get identical
+    /// matrices across runs.
+    ///
+    /// **Note that**: If `low >= high` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(12);
+    /// let w = RandomMatrixBuilder::uniform_with_rng([5, 5], 0.0, 1.0, &mut rng).unwrap();
+    /// ```
+    pub fn uniform_with_rng<T, R>(
+        shape: [usize; 2],
+        low: T,
+        high: T,
+        rng: &mut R,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Num + Copy + SampleUniform + PartialOrd + fmt::Display,
+        R: Rng,
+    {
+        let total_elements = shape.iter().product();
+        let vec = RandomVectorBuilder::uniform_with_rng(total_elements, low, high, rng)?;
+
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
     /// Create a new matrix of the given shape `shape` and populate it with
     /// random samples from a normal distribution `N(mean, std_dev**2)`.
     ///
@@ -313,4 +439,210 @@ impl RandomMatrixBuilder {
             vec,
         })
     }
+
+    /// Create a new matrix of the given shape `shape`, populated with
+    /// random samples from a normal distribution `N(mean, std_dev**2)`,
+    /// drawing from the given `rng` instead of a seeded builder instance.
+    /// See [`uniform_with_rng`] for why one would want to do that.
+    ///
+    /// **Note that**: If `std_dev < 0` it will returns an error.
+    ///
+    /// [`uniform_with_rng`]: #method.uniform_with_rng
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(12);
+    /// let v = RandomMatrixBuilder::normal_with_rng([5, 5], 0.0, 1.0, &mut rng);
+    /// ```
+    pub fn normal_with_rng<R>(
+        shape: [usize; 2],
+        mean: f64,
+        std_dev: f64,
+        rng: &mut R,
+    ) -> Result<Matrix<f64>, MatrixBuilderError>
+    where
+        R: Rng,
+    {
+        let total_elements = shape.iter().product();
+        let vec = RandomVectorBuilder::normal_with_rng(total_elements, mean, std_dev, rng)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from the standard normal distribution `N(0, 1)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w: Matrix<f64> = rmb.standard_normal([5, 5]);
+    /// ```
+    pub fn standard_normal<T>(&mut self, shape: [usize; 2]) -> Matrix<T>
+    where
+        T: Float + FromPrimitive,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.standard_normal(total_elements);
+        Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        }
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a log-normal distribution, i.e. `exp(X)` where
+    /// `X ~ N(mean, std_dev**2)`.
+    ///
+    /// **Note that**: If `std_dev < 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.lognormal([5, 5], 0.0, 1.0).unwrap();
+    /// ```
+    pub fn lognormal<T>(
+        &mut self,
+        shape: [usize; 2],
+        mean: T,
+        std_dev: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.lognormal(total_elements, mean, std_dev)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from an exponential distribution with rate `lambda`.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w = rmb.exponential([5, 5], 1.0).unwrap();
+    /// ```
+    pub fn exponential<T>(
+        &mut self,
+        shape: [usize; 2],
+        lambda: T,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.exponential(total_elements, lambda)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a Poisson distribution with rate `lambda`.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w: Matrix<u32> = rmb.poisson([5, 5], 4.0).unwrap();
+    /// ```
+    pub fn poisson<T>(
+        &mut self,
+        shape: [usize; 2],
+        lambda: f64,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.poisson(total_elements, lambda)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a binomial distribution of `n` trials with
+    /// success probability `p`.
+    ///
+    /// **Note that**: If `p` is not within `[0, 1]` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w: Matrix<u32> = rmb.binomial([5, 5], 10, 0.5).unwrap();
+    /// ```
+    pub fn binomial<T>(
+        &mut self,
+        shape: [usize; 2],
+        n: u64,
+        p: f64,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.binomial(total_elements, n, p)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
+
+    /// Create a new matrix of the given shape `shape` and populate it with
+    /// random samples from a Bernoulli distribution, i.e. `1` with
+    /// probability `p` and `0` otherwise. Equivalent to `binomial(shape, 1,
+    /// p)`.
+    ///
+    /// **Note that**: If `p` is not within `[0, 1]` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rmb = RandomMatrixBuilder::new();
+    /// let w: Matrix<u32> = rmb.bernoulli([5, 5], 0.5).unwrap();
+    /// ```
+    pub fn bernoulli<T>(
+        &mut self,
+        shape: [usize; 2],
+        p: f64,
+    ) -> Result<Matrix<T>, MatrixBuilderError>
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        let total_elements = shape.iter().product();
+        let vec = self.builder.bernoulli(total_elements, p)?;
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec,
+        })
+    }
 }
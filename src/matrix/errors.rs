@@ -19,6 +19,8 @@
 
 use crate::vector::errors::{VectorBuilderError, VectorBuilderErrorKind};
 use csv;
+#[cfg(feature = "serde")]
+use serde_json;
 use std::convert;
 use std::fmt;
 use std::io;
@@ -79,6 +81,18 @@ impl convert::From<VectorBuilderError> for MatrixBuilderError {
                     error.message,
                 )
             }
+            VectorBuilderErrorKind::NegativeStandardDeviation => {
+                MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::Other,
+                    error.message,
+                )
+            }
+            VectorBuilderErrorKind::InvalidParameter => {
+                MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::Other,
+                    error.message,
+                )
+            }
         }
     }
 }
@@ -95,6 +109,8 @@ impl fmt::Display for MatrixBuilderError {
     }
 }
 
+impl std::error::Error for MatrixBuilderError {}
+
 /// Enum to store the various types of errors that can cause loading a matrix to fail.
 pub enum MatrixLoadErrorKind {
     /// I/O Error
@@ -115,6 +131,27 @@ pub enum MatrixLoadErrorKind {
     /// Among other causes, this variant will be constructed when parsing a string that
     /// contains non-numeric letter.
     InvalidElement,
+    /// Row has more columns than the rest of the file.
+    ///
+    /// Among other causes, this variant will be constructed when loading a
+    /// flexible CSV/TSV file whose rows disagree on the number of columns
+    /// and padding would be ambiguous.
+    InconsistentColumn,
+    /// JSON Error
+    ///
+    /// Among other causes, this variant will be constructed when failed
+    /// parsing or writing a JSON document, for example malformed JSON
+    /// syntax.
+    #[cfg(feature = "serde")]
+    JSONError,
+    /// Binary `.npy`-style file has a malformed or mismatched header.
+    ///
+    /// Among other causes, this variant will be constructed when the magic
+    /// bytes, version, element type tag or element count of a file loaded
+    /// with [`Matrix::load_npy`] don't match what was expected.
+    ///
+    /// [`Matrix::load_npy`]: ../struct.Matrix.html#method.load_npy
+    InvalidFormat,
 }
 
 /// An error which can be returned when loading matrix from a file.
@@ -124,6 +161,7 @@ pub enum MatrixLoadErrorKind {
 pub struct MatrixLoadError {
     pub(crate) kind: MatrixLoadErrorKind,
     pub(crate) message: String,
+    pub(crate) source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 }
 
 impl MatrixLoadError {
@@ -132,7 +170,11 @@ impl MatrixLoadError {
         kind: MatrixLoadErrorKind,
         message: String,
     ) -> MatrixLoadError {
-        MatrixLoadError { kind, message }
+        MatrixLoadError {
+            kind,
+            message,
+            source: None,
+        }
     }
 
     /// Outputs the detailed cause of loading file failing.
@@ -156,6 +198,18 @@ impl MatrixLoadError {
                 "Cannot load Matrix, invalid element: {}",
                 self.message
             ),
+            MatrixLoadErrorKind::InconsistentColumn => format!(
+                "Cannot load Matrix, inconsistent column count: {}",
+                self.message
+            ),
+            #[cfg(feature = "serde")]
+            MatrixLoadErrorKind::JSONError => {
+                format!("Cannot load Matrix, {}", self.message)
+            }
+            MatrixLoadErrorKind::InvalidFormat => format!(
+                "Cannot load Matrix, invalid .npy-style format: {}",
+                self.message
+            ),
         }
     }
 }
@@ -166,6 +220,7 @@ impl convert::From<io::Error> for MatrixLoadError {
         MatrixLoadError {
             kind: MatrixLoadErrorKind::IOError,
             message: format!("{}", error),
+            source: Some(Box::new(error)),
         }
     }
 }
@@ -176,6 +231,19 @@ impl convert::From<csv::Error> for MatrixLoadError {
         MatrixLoadError {
             kind: MatrixLoadErrorKind::CSVError,
             message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+/// Convert `serde_json::Error` to `matrix::LoadError`
+#[cfg(feature = "serde")]
+impl convert::From<serde_json::Error> for MatrixLoadError {
+    fn from(error: serde_json::Error) -> Self {
+        MatrixLoadError {
+            kind: MatrixLoadErrorKind::JSONError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
         }
     }
 }
@@ -191,3 +259,108 @@ impl fmt::Display for MatrixLoadError {
         write!(f, "{}", self.description())
     }
 }
+
+impl std::error::Error for MatrixLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|error| error.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Enum to store the various types of errors that can cause saving a matrix to fail.
+pub enum MatrixSaveErrorKind {
+    /// I/O Error
+    ///
+    /// Among other causes, this variant will be constructed when failed saving a file
+    /// due to I/O problem.
+    IOError,
+    /// CSV Error
+    ///
+    /// Among other causes, this variant will be constructed when failed writing a CSV file.
+    CSVError,
+}
+
+/// An error which can be returned when saving a matrix to a file.
+///
+/// # Potential causes
+/// Among other causes, `MatrixSaveError` can be thrown because the destination
+/// file or its parent directory is not writable.
+pub struct MatrixSaveError {
+    pub(crate) kind: MatrixSaveErrorKind,
+    pub(crate) message: String,
+    pub(crate) source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl MatrixSaveError {
+    /// Creates a new `MatrixSaveError` from a known kind of error as well as an error message.
+    pub fn new(
+        kind: MatrixSaveErrorKind,
+        message: String,
+    ) -> MatrixSaveError {
+        MatrixSaveError {
+            kind,
+            message,
+            source: None,
+        }
+    }
+
+    /// Outputs the detailed cause of saving file failing.
+    pub fn kind(&self) -> &MatrixSaveErrorKind {
+        &self.kind
+    }
+
+    fn description(&self) -> String {
+        match self.kind {
+            MatrixSaveErrorKind::IOError => format!(
+                "Cannot save Matrix to file due to: {}",
+                self.message
+            ),
+            MatrixSaveErrorKind::CSVError => {
+                format!("Cannot save Matrix, {}", self.message)
+            }
+        }
+    }
+}
+
+/// Convert `io::Error` to `matrix::MatrixSaveError`
+impl convert::From<io::Error> for MatrixSaveError {
+    fn from(error: io::Error) -> Self {
+        MatrixSaveError {
+            kind: MatrixSaveErrorKind::IOError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+/// Convert `csv::Error` to `matrix::MatrixSaveError`
+impl convert::From<csv::Error> for MatrixSaveError {
+    fn from(error: csv::Error) -> Self {
+        MatrixSaveError {
+            kind: MatrixSaveErrorKind::CSVError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+impl fmt::Debug for MatrixSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for MatrixSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl std::error::Error for MatrixSaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|error| error.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
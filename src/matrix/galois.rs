@@ -0,0 +1,447 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! GF(2^8) finite-field arithmetic and Cauchy-matrix erasure coding.
+//!
+//! This module exists so `Matrix<T>`/`Vector<T>` can be reused, unchanged,
+//! for Reed-Solomon-style information dispersal: split `k` data shares
+//! into `n >= k` shares such that any `k` of the `n` shares are enough to
+//! reconstruct the original data.
+//!
+//! [`Gf256`] is a scalar type implementing [`num::Num`] over the field
+//! GF(2^8) with the primitive polynomial `0x11d`
+//! (`x^8 + x^4 + x^3 + x^2 + 1`), so it slots straight into `Matrix<Gf256>`
+//! and `Vector<Gf256>`. [`vandermonde_encoding_matrix`] builds the `n x k`
+//! encoding matrix, and [`Matrix::invert`] (implemented here for
+//! `Matrix<Gf256>` specifically) inverts the `k x k` submatrix needed to
+//! recover the original data from any `k` received shares.
+//!
+//! # Examples
+//! ```
+//! # use crabsformer::prelude::*;
+//! use crabsformer::matrix::galois::{vandermonde_encoding_matrix, Gf256};
+//!
+//! // Disperse 2 data rows into 4 shares; any 2 of the 4 are enough to
+//! // recover the original data.
+//! let encoding = vandermonde_encoding_matrix(4, 2).unwrap();
+//! let data = matrix![
+//!     Gf256::new(3), Gf256::new(1), Gf256::new(4);
+//!     Gf256::new(1), Gf256::new(5), Gf256::new(9);
+//! ];
+//! let shares = encoding.dot(&data);
+//! assert_eq!(shares.shape(), [4, 3]);
+//!
+//! // Drop shares 0 and 2, keep shares 1 and 3, and recover the data.
+//! let kept_rows = matrix![
+//!     *encoding.at(1, 0), *encoding.at(1, 1);
+//!     *encoding.at(3, 0), *encoding.at(3, 1);
+//! ];
+//! let kept_shares = matrix![
+//!     *shares.at(1, 0), *shares.at(1, 1), *shares.at(1, 2);
+//!     *shares.at(3, 0), *shares.at(3, 1), *shares.at(3, 2);
+//! ];
+//! let recovered = kept_rows.invert().unwrap().dot(&kept_shares);
+//! assert_eq!(recovered, data);
+//! ```
+
+use crate::error::CrabsformerError;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num::{FromPrimitive, Num, One, Zero};
+use std::ops;
+
+thread_local! {
+    // `exp[i]` is the primitive element raised to the `i`-th power and
+    // `log[x]` is the power the primitive element must be raised to, to
+    // get `x` (undefined, and never consulted, for `x == 0`). Built once
+    // per thread the first time GF(2^8) multiplication/division runs.
+    static GF_TABLES: (Box<[u8; 256]>, Box<[u8; 256]>) = build_gf_tables();
+}
+
+// The field has 255 nonzero elements, so `exp`/`log` are only meaningful
+// modulo 255; `0x11d` is the primitive polynomial used by Reed-Solomon
+// codes such as QR codes and RAID-6 (`x^8 + x^4 + x^3 + x^2 + 1`).
+const GF_POLY: u16 = 0x11d;
+
+fn build_gf_tables() -> (Box<[u8; 256]>, Box<[u8; 256]>) {
+    let mut exp = Box::new([0u8; 256]);
+    let mut log = Box::new([0u8; 256]);
+    let mut x: u16 = 1;
+    for i in 0..255usize {
+        exp[i] = x as u8;
+        log[x as usize] = i as u8;
+        x <<= 1;
+        if x & 0x100 != 0 {
+            x ^= GF_POLY;
+        }
+    }
+    // exp is conventionally extended so callers never need to reduce the
+    // exponent modulo 255 themselves; exp[255] wraps back to exp[0].
+    exp[255] = exp[0];
+    (exp, log)
+}
+
+fn with_gf_tables<R>(f: impl FnOnce(&[u8; 256], &[u8; 256]) -> R) -> R {
+    GF_TABLES.with(|(exp, log)| f(exp, log))
+}
+
+fn gf_mul(a: u8, b: u8) -> u8 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+    with_gf_tables(|exp, log| {
+        let sum = log[a as usize] as usize + log[b as usize] as usize;
+        exp[sum % 255]
+    })
+}
+
+fn gf_div(a: u8, b: u8) -> u8 {
+    assert!(b != 0, "division by zero in GF(2^8)");
+    if a == 0 {
+        return 0;
+    }
+    with_gf_tables(|exp, log| {
+        let diff =
+            255 + log[a as usize] as isize - log[b as usize] as isize;
+        exp[(diff % 255) as usize]
+    })
+}
+
+/// An element of the finite field GF(2^8), represented as a single byte
+/// under the primitive polynomial `0x11d`.
+///
+/// Addition and subtraction are both XOR (the field has characteristic
+/// 2); multiplication and division go through log/antilog tables. This is
+/// the arithmetic Reed-Solomon erasure coding and secret-splitting schemes
+/// are built on top of; see the [module docs](index.html) for a worked
+/// example.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Gf256(u8);
+
+impl Gf256 {
+    /// Wraps a raw byte as a GF(2^8) field element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::matrix::galois::Gf256;
+    /// let x = Gf256::new(3);
+    /// assert_eq!(x.value(), 3);
+    /// ```
+    pub fn new(value: u8) -> Gf256 {
+        Gf256(value)
+    }
+
+    /// Returns the underlying byte representation.
+    pub fn value(self) -> u8 {
+        self.0
+    }
+}
+
+impl ops::Add for Gf256 {
+    type Output = Gf256;
+
+    fn add(self, other: Gf256) -> Gf256 {
+        Gf256(self.0 ^ other.0)
+    }
+}
+
+impl ops::Sub for Gf256 {
+    type Output = Gf256;
+
+    fn sub(self, other: Gf256) -> Gf256 {
+        // Addition and subtraction coincide in a characteristic-2 field.
+        Gf256(self.0 ^ other.0)
+    }
+}
+
+impl ops::Mul for Gf256 {
+    type Output = Gf256;
+
+    fn mul(self, other: Gf256) -> Gf256 {
+        Gf256(gf_mul(self.0, other.0))
+    }
+}
+
+impl ops::Div for Gf256 {
+    type Output = Gf256;
+
+    fn div(self, other: Gf256) -> Gf256 {
+        Gf256(gf_div(self.0, other.0))
+    }
+}
+
+impl ops::Rem for Gf256 {
+    type Output = Gf256;
+
+    // GF(2^8) division is exact: every nonzero element has a
+    // multiplicative inverse, so the remainder is always zero.
+    fn rem(self, other: Gf256) -> Gf256 {
+        assert!(other.0 != 0, "division by zero in GF(2^8)");
+        Gf256::zero()
+    }
+}
+
+impl Zero for Gf256 {
+    fn zero() -> Gf256 {
+        Gf256(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Gf256 {
+    fn one() -> Gf256 {
+        Gf256(1)
+    }
+}
+
+impl Num for Gf256 {
+    type FromStrRadixErr = std::num::ParseIntError;
+
+    fn from_str_radix(
+        str: &str,
+        radix: u32,
+    ) -> Result<Self, Self::FromStrRadixErr> {
+        u8::from_str_radix(str, radix).map(Gf256)
+    }
+}
+
+// So `Matrix<Gf256>`/`Vector<Gf256>` can reuse the crate's generic `dot`,
+// `sum`, etc., which need a way to build the additive identity from a
+// literal `0`.
+impl FromPrimitive for Gf256 {
+    fn from_i64(n: i64) -> Option<Gf256> {
+        Some(Gf256(n as u8))
+    }
+
+    fn from_u64(n: u64) -> Option<Gf256> {
+        Some(Gf256(n as u8))
+    }
+}
+
+/// Builds an `n x k` GF(2^8) encoding matrix for `k`-into-`n` information
+/// dispersal: the top `k` rows are the `k x k` identity (so the first `k`
+/// shares produced by `encoding.dot(&data)` are just the original data
+/// rows), and the remaining `n - k` rows are a Cauchy matrix chosen so
+/// that *any* `k` of the `n` rows form an invertible `k x k` matrix. That
+/// guarantee is what lets the original data be recovered from any `k` of
+/// the `n` generated shares, tolerating up to `n - k` losses.
+///
+/// # Errors
+/// Returns a [`CrabsformerError::ShapeMismatch`] if `k == 0`, `n < k`, or
+/// `n > 255` (the field only has 255 nonzero elements to draw distinct
+/// Cauchy parameters from).
+///
+/// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// use crabsformer::matrix::galois::vandermonde_encoding_matrix;
+/// let encoding = vandermonde_encoding_matrix(5, 3).unwrap();
+/// assert_eq!(encoding.shape(), [5, 3]);
+/// ```
+pub fn vandermonde_encoding_matrix(
+    n: usize,
+    k: usize,
+) -> Result<Matrix<Gf256>, CrabsformerError> {
+    if k == 0 || n < k || n > 255 {
+        return Err(CrabsformerError::ShapeMismatch {
+            lhs: vec![n],
+            rhs: vec![k],
+        });
+    }
+
+    let mut data = Vec::with_capacity(n * k);
+    for i in 0..k {
+        for j in 0..k {
+            data.push(if i == j { Gf256::one() } else { Gf256::zero() });
+        }
+    }
+
+    // `x_i` (Cauchy rows) are drawn from `k + 1..=n` and `y_j` (Cauchy
+    // columns) from `0..k`, so every `x_i` is distinct from every `y_j`
+    // as well as from every other `x_i`/`y_j`.
+    for i in 0..(n - k) {
+        let x = Gf256::new((k + i + 1) as u8);
+        for j in 0..k {
+            let y = Gf256::new(j as u8);
+            data.push(Gf256::one() / (x - y));
+        }
+    }
+
+    Ok(Matrix::from_vector(Vector::from(data), k).unwrap())
+}
+
+impl Matrix<Gf256> {
+    /// Inverts this square GF(2^8) matrix using Gauss-Jordan elimination.
+    ///
+    /// Field elements have no notion of magnitude, so pivoting simply
+    /// picks the first nonzero entry in each column rather than the
+    /// largest.
+    ///
+    /// # Errors
+    /// Returns a [`CrabsformerError::ShapeMismatch`] if the matrix isn't
+    /// square, or [`CrabsformerError::NotInvertible`] if it's singular.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    /// [`CrabsformerError::NotInvertible`]: ../../error/enum.CrabsformerError.html#variant.NotInvertible
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use crabsformer::matrix::galois::Gf256;
+    /// let m = matrix![
+    ///     Gf256::new(1), Gf256::new(2);
+    ///     Gf256::new(3), Gf256::new(4);
+    /// ];
+    /// let inverse = m.invert().unwrap();
+    /// let identity = matrix![
+    ///     Gf256::new(1), Gf256::new(0);
+    ///     Gf256::new(0), Gf256::new(1);
+    /// ];
+    /// assert_eq!(m.dot(&inverse), identity);
+    /// ```
+    pub fn invert(&self) -> Result<Matrix<Gf256>, CrabsformerError> {
+        let [rows, cols] = self.shape();
+        if rows != cols {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![rows, cols],
+                rhs: vec![rows, rows],
+            });
+        }
+        let n = rows;
+        let width = 2 * n;
+
+        // Row-reduce `[self | identity]`; once the left half becomes the
+        // identity, the right half is the inverse.
+        let mut aug = vec![Gf256::zero(); n * width];
+        for i in 0..n {
+            for j in 0..n {
+                aug[i * width + j] = *self.at(i, j);
+            }
+            aug[i * width + n + i] = Gf256::one();
+        }
+
+        for col in 0..n {
+            let pivot_row = (col..n).find(|&r| !aug[r * width + col].is_zero());
+            let pivot_row = pivot_row.ok_or(CrabsformerError::NotInvertible)?;
+            if pivot_row != col {
+                for j in 0..width {
+                    aug.swap(col * width + j, pivot_row * width + j);
+                }
+            }
+
+            let pivot_inv = Gf256::one() / aug[col * width + col];
+            for j in 0..width {
+                aug[col * width + j] = aug[col * width + j] * pivot_inv;
+            }
+
+            for r in 0..n {
+                if r == col {
+                    continue;
+                }
+                let factor = aug[r * width + col];
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in 0..width {
+                    aug[r * width + j] =
+                        aug[r * width + j] - factor * aug[col * width + j];
+                }
+            }
+        }
+
+        let mut data = Vec::with_capacity(n * n);
+        for i in 0..n {
+            for j in 0..n {
+                data.push(aug[i * width + n + j]);
+            }
+        }
+        Ok(Matrix::from_vector(Vector::from(data), n).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gf_add_is_xor() {
+        assert_eq!(Gf256::new(0x53) + Gf256::new(0xca), Gf256::new(0x53 ^ 0xca));
+    }
+
+    #[test]
+    fn test_gf_mul_matches_known_vector() {
+        // Under the 0x11d polynomial, 0x53 * 0xca == 0x8f (carry-less
+        // product reduced modulo 0x11d).
+        assert_eq!(Gf256::new(0x53) * Gf256::new(0xca), Gf256::new(0x8f));
+    }
+
+    #[test]
+    fn test_gf_div_is_mul_inverse() {
+        let a = Gf256::new(0x53);
+        let b = Gf256::new(0xca);
+        assert_eq!((a * b) / b, a);
+    }
+
+    #[test]
+    fn test_encoding_matrix_top_block_is_identity() {
+        let encoding = vandermonde_encoding_matrix(5, 3).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { Gf256::one() } else { Gf256::zero() };
+                assert_eq!(*encoding.at(i, j), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_any_k_rows_are_invertible() {
+        let encoding = vandermonde_encoding_matrix(5, 3).unwrap();
+        // Pick a subset that isn't the trivial identity block.
+        let rows = [1, 2, 4];
+        let mut data = Vec::with_capacity(9);
+        for &r in &rows {
+            for j in 0..3 {
+                data.push(*encoding.at(r, j));
+            }
+        }
+        let subset = Matrix::from_vector(Vector::from(data), 3).unwrap();
+        assert!(subset.invert().is_ok());
+    }
+
+    #[test]
+    fn test_invert_rejects_non_square() {
+        let m = matrix![Gf256::new(1), Gf256::new(2)];
+        assert!(match m.invert() {
+            Err(CrabsformerError::ShapeMismatch { .. }) => true,
+            _ => false,
+        });
+    }
+
+    #[test]
+    fn test_invert_rejects_singular_matrix() {
+        let m = matrix![
+            Gf256::new(1), Gf256::new(1);
+            Gf256::new(1), Gf256::new(1);
+        ];
+        assert!(match m.invert() {
+            Err(CrabsformerError::NotInvertible) => true,
+            _ => false,
+        });
+    }
+}
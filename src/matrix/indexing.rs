@@ -18,10 +18,72 @@
 //!
 //!
 
-use crate::matrix::{ColumnMatrix, Matrix, RowMatrix, Submatrix};
+use crate::matrix::{
+    ColumnMatrix, ColumnMatrixMut, Diagonal, DiagonalMut, Matrix, RowMatrix,
+    RowMatrixMut, Submatrix, SubmatrixMut,
+};
 use num::Num;
 use std::ops;
 
+/// Maps a logical index into the flat, row-major position it refers to,
+/// or `None` if it's out of bounds. This is the bounds math shared by
+/// `Matrix`'s `Index<(usize, usize)>` impl and its non-panicking `get`.
+pub trait Index2D {
+    /// Converts `self` into a flat index for a `nrows x ncols` matrix.
+    fn to_1d(self, nrows: usize, ncols: usize) -> Option<usize>;
+}
+
+impl Index2D for (usize, usize) {
+    fn to_1d(self, nrows: usize, ncols: usize) -> Option<usize> {
+        let (i, j) = self;
+        if i < nrows && j < ncols {
+            Some(ncols * i + j)
+        } else {
+            None
+        }
+    }
+}
+
+// Number of elements on the diagonal `col - row == offset` of a `nrows x
+// ncols` matrix (or submatrix), following rulinalg's `DiagOffset` rule:
+// `min(nrows, ncols - offset)` for `offset >= 0`, `min(nrows + offset,
+// ncols)` for `offset < 0`.
+pub(crate) fn diagonal_len(nrows: usize, ncols: usize, offset: isize) -> usize {
+    let len = if offset >= 0 {
+        std::cmp::min(nrows as isize, ncols as isize - offset)
+    } else {
+        std::cmp::min(nrows as isize + offset, ncols as isize)
+    };
+    if len <= 0 {
+        panic!(
+            "Diagonal offset {} out of range for matrix with shape [{}, {}]",
+            offset, nrows, ncols
+        )
+    }
+    len as usize
+}
+
+// Resolve the `i`-th element of a diagonal at `offset` (relative to
+// `row_offset`/`col_offset`, stepping by `row_stride`/`col_stride` through
+// the source) into `(row, col)` coordinates of the source matrix:
+// `(row_offset + (i - min(offset, 0)) * row_stride, col_offset + (i +
+// max(offset, 0)) * col_stride)`.
+pub(crate) fn diagonal_position(
+    row_offset: usize,
+    col_offset: usize,
+    row_stride: usize,
+    col_stride: usize,
+    offset: isize,
+    i: usize,
+) -> (usize, usize) {
+    let row_shift = if offset < 0 { (-offset) as usize } else { 0 };
+    let col_shift = if offset > 0 { offset as usize } else { 0 };
+    (
+        row_offset + (i + row_shift) * row_stride,
+        col_offset + (i + col_shift) * col_stride,
+    )
+}
+
 impl<T> Matrix<T>
 where
     T: Num + Copy,
@@ -69,6 +131,81 @@ where
         &self.vec[(self.ncols * i) + j]
     }
 
+    // Whether `(i, j)` is within the matrix bounds; the shared predicate
+    // behind both the panicking `at`/`check_bound` and the non-panicking
+    // `get`.
+    pub(crate) fn in_bounds(&self, i: usize, j: usize) -> bool {
+        i < self.nrows && j < self.ncols
+    }
+
+    /// Get element of the matrix at row `i` and column `j`, returning
+    /// `None` instead of panicking if either index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    ///
+    /// assert_eq!(w.get(0, 0), Some(&3));
+    /// assert_eq!(w.get(10, 0), None);
+    /// assert_eq!(w.get(0, 10), None);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if self.in_bounds(i, j) {
+            Some(&self.vec[(self.ncols * i) + j])
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the element of the matrix at row `i` and
+    /// column `j`, returning `None` instead of panicking if either index
+    /// is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    ///
+    /// *w.get_mut(0, 0).unwrap() = 10;
+    /// assert_eq!(w.at(0, 0), &10);
+    /// assert_eq!(w.get_mut(10, 0), None);
+    /// ```
+    pub fn get_mut(&mut self, i: usize, j: usize) -> Option<&mut T> {
+        if self.in_bounds(i, j) {
+            Some(&mut self.vec[(self.ncols * i) + j])
+        } else {
+            None
+        }
+    }
+
+    /// Get a mutable reference to the element of the matrix at row `i` and
+    /// column `j`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    /// *w.at_mut(0, 0) = 10;
+    /// assert_eq!(w.at(0, 0), &10);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `i >= nrows` and `j >= ncols`.
+    pub fn at_mut(&mut self, i: usize, j: usize) -> &mut T {
+        self.check_bound(Some(i), Some(j));
+        &mut self.vec[(self.ncols * i) + j]
+    }
+
     /// Get the row of the matrix. It will returns a reference to a row
     /// of the matrix. Row matrix is `1xm` matrix, where `m` is the number
     /// of columns.
@@ -124,6 +261,117 @@ where
             source: self,
         }
     }
+
+    /// Get a mutable view over row `i` of the matrix, e.g. for mutating
+    /// its elements in place via [`RowMatrixMut::elements_mut`].
+    ///
+    /// [`RowMatrixMut::elements_mut`]: struct.RowMatrixMut.html#method.elements_mut
+    ///
+    /// # Panics
+    /// Panics if `i >= n` where `n` is number of rows.
+    pub fn row_mut<'a>(&'a mut self, i: usize) -> RowMatrixMut<'a, T> {
+        self.check_bound(Some(i), None);
+        let ncols = self.ncols;
+        let start = ncols * i;
+        RowMatrixMut {
+            data: &mut self.vec.as_mut_slice()[start..start + ncols],
+        }
+    }
+
+    /// Get a mutable view over column `j` of the matrix, e.g. for mutating
+    /// its elements in place via [`ColumnMatrixMut::elements_mut`].
+    ///
+    /// [`ColumnMatrixMut::elements_mut`]: struct.ColumnMatrixMut.html#method.elements_mut
+    ///
+    /// # Panics
+    /// Panics if `j >= m` where `m` is number of columns.
+    pub fn col_mut<'a>(&'a mut self, j: usize) -> ColumnMatrixMut<'a, T> {
+        self.check_bound(None, Some(j));
+        let ncols = self.ncols;
+        let elements = self
+            .vec
+            .as_mut_slice()
+            .chunks_mut(ncols)
+            .map(|row| &mut row[j])
+            .collect();
+        ColumnMatrixMut { elements }
+    }
+
+    /// Get a view over the diagonal at `offset` from the main diagonal:
+    /// `0` is the main diagonal, a positive offset a super-diagonal, a
+    /// negative offset a sub-diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// let d = w.diagonal(0);
+    /// assert_eq!(d[0], 3);
+    /// assert_eq!(d[1], 5);
+    /// assert_eq!(d[2], 5);
+    ///
+    /// let d = w.diagonal(1);
+    /// assert_eq!(d[0], 1);
+    /// assert_eq!(d[1], 9);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `offset` leaves no elements on the diagonal.
+    pub fn diagonal<'a>(&'a self, offset: isize) -> Diagonal<'a, T> {
+        let len = diagonal_len(self.nrows, self.ncols, offset);
+        Diagonal {
+            offset,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            len,
+            source: self,
+        }
+    }
+
+    /// Get a mutable view over the diagonal at `offset` from the main
+    /// diagonal. See [`diagonal`] for the offset convention.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// w.diagonal_mut(0).fill(0);
+    /// assert_eq!(
+    ///     w,
+    ///     matrix![
+    ///         0, 1, 4;
+    ///         1, 0, 9;
+    ///         2, 6, 0;
+    ///     ]
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `offset` leaves no elements on the diagonal.
+    ///
+    /// [`diagonal`]: #method.diagonal
+    pub fn diagonal_mut<'a>(&'a mut self, offset: isize) -> DiagonalMut<'a, T> {
+        let len = diagonal_len(self.nrows, self.ncols, offset);
+        DiagonalMut {
+            offset,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            len,
+            source: self,
+        }
+    }
 }
 
 impl<'a, T> Submatrix<'a, T>
@@ -168,7 +416,37 @@ where
     /// Panics if `i >= nrows` and `j >= ncols`.
     pub fn at(&self, i: usize, j: usize) -> &T {
         self.bound_check(Some(i), Some(j));
-        &self.source.at(self.row_offset + i, self.col_offset + j)
+        &self.source.at(
+            self.row_offset + i * self.row_stride,
+            self.col_offset + j * self.col_stride,
+        )
+    }
+
+    /// Get element of the submatrix at row `i` and column `j`, returning
+    /// `None` instead of panicking if either index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    /// let sub = w.slice(0..1, 1..); // [[1, 4]]
+    ///
+    /// assert_eq!(sub.get(0, 0), Some(&1));
+    /// assert_eq!(sub.get(10, 0), None);
+    /// ```
+    pub fn get(&self, i: usize, j: usize) -> Option<&T> {
+        if i < self.nrows && j < self.ncols {
+            self.source.get(
+                self.row_offset + i * self.row_stride,
+                self.col_offset + j * self.col_stride,
+            )
+        } else {
+            None
+        }
     }
 
     /// Get the row of the sub matrix. Row matrix is `1xm` matrix, where `m`
@@ -233,6 +511,228 @@ where
             source: self.source,
         }
     }
+
+    /// Copy the elements of the submatrix into a new, owned `Matrix<T>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    /// let sub = w.slice(0..1, 1..); // [[1, 4]]
+    /// let owned = sub.to_matrix();
+    /// assert_eq!(owned, matrix![1, 4]);
+    /// ```
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut elements = Vec::with_capacity(self.nrows);
+        for i in 0..self.nrows {
+            let mut row = Vec::with_capacity(self.ncols);
+            for j in 0..self.ncols {
+                row.push(*self.at(i, j));
+            }
+            elements.push(row);
+        }
+        Matrix::from(elements)
+    }
+
+    /// Get a view over the diagonal at `offset` from the main diagonal of
+    /// the submatrix. See [`Matrix::diagonal`] for the offset convention.
+    ///
+    /// # Panics
+    /// Panics if `offset` leaves no elements on the diagonal.
+    ///
+    /// [`Matrix::diagonal`]: struct.Matrix.html#method.diagonal
+    pub fn diagonal(&self, offset: isize) -> Diagonal<'a, T> {
+        let len = diagonal_len(self.nrows, self.ncols, offset);
+        Diagonal {
+            offset,
+            row_offset: self.row_offset,
+            col_offset: self.col_offset,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            len,
+            source: self.source,
+        }
+    }
+}
+
+// Materialize a `Submatrix` into an owned `Matrix<T>`, e.g. so it can be
+// returned from a function or fed into operators that require an owned
+// matrix.
+impl<'a, T> From<Submatrix<'a, T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn from(source: Submatrix<'a, T>) -> Self {
+        source.to_matrix()
+    }
+}
+
+impl<'a, T> SubmatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // Bound check
+    pub(crate) fn bound_check(&self, i: Option<usize>, j: Option<usize>) {
+        if i.is_some() && i.unwrap() >= self.nrows {
+            panic!(
+                "Row index {} out of range for matrix with number of rows {}",
+                i.unwrap(),
+                self.nrows
+            )
+        }
+        if j.is_some() && j.unwrap() >= self.ncols {
+            panic!(
+                "Column index {} out of range for matrix with number of columns {}",
+                j.unwrap(),
+                self.ncols
+            )
+        }
+    }
+
+    /// Get a mutable reference to the element of the submatrix at row `i`
+    /// and column `j`.
+    ///
+    /// # Panics
+    /// Panics if `i >= nrows` and `j >= ncols`.
+    pub fn get_mut(&mut self, i: usize, j: usize) -> &mut T {
+        self.bound_check(Some(i), Some(j));
+        self.source.at_mut(self.row_offset + i, self.col_offset + j)
+    }
+
+    /// Overwrite every element of the submatrix with `value`, writing back
+    /// into the parent matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    /// w.slice_mut(0..2, 1..3).fill(0);
+    /// assert_eq!(
+    ///     w,
+    ///     matrix![
+    ///         3, 0, 0;
+    ///         1, 0, 0;
+    ///     ]
+    /// );
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                *self.get_mut(i, j) = value;
+            }
+        }
+    }
+
+    /// Get a mutable view over the diagonal at `offset` from the main
+    /// diagonal of the submatrix. See [`Matrix::diagonal`] for the offset
+    /// convention.
+    ///
+    /// # Panics
+    /// Panics if `offset` leaves no elements on the diagonal.
+    ///
+    /// [`Matrix::diagonal`]: struct.Matrix.html#method.diagonal
+    pub fn diagonal_mut(&mut self, offset: isize) -> DiagonalMut<T> {
+        let len = diagonal_len(self.nrows, self.ncols, offset);
+        DiagonalMut {
+            offset,
+            row_offset: self.row_offset,
+            col_offset: self.col_offset,
+            // `SubmatrixMut` is only ever produced by `slice`/`slice_mut`,
+            // which are always contiguous (stride 1).
+            row_stride: 1,
+            col_stride: 1,
+            len,
+            source: &mut *self.source,
+        }
+    }
+}
+
+// Implement matrix indexing
+// matrix[(i, j)]
+impl<T> ops::Index<(usize, usize)> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        self.check_bound(Some(i), Some(j));
+        &self.vec[(i, j).to_1d(self.nrows, self.ncols).unwrap()]
+    }
+}
+
+// Implement submatrix indexing
+// submatrix[(i, j)]
+impl<'a, T> ops::Index<(usize, usize)> for Submatrix<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        self.at(i, j)
+    }
+}
+
+// Implement submatrix indexing in mutable context
+// submatrix[(i, j)] = value
+impl<'a, T> ops::IndexMut<(usize, usize)> for SubmatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        self.get_mut(i, j)
+    }
+}
+
+// Implement submatrix indexing
+// submatrix[(i, j)]
+impl<'a, T> ops::Index<(usize, usize)> for SubmatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        self.bound_check(Some(i), Some(j));
+        self.source.at(self.row_offset + i, self.col_offset + j)
+    }
+}
+
+impl<'a, T> RowMatrix<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Get the `j`-th element of the row, returning `None` instead of
+    /// panicking if it's out of bounds.
+    pub fn get(&self, j: usize) -> Option<&T> {
+        if j < self.size {
+            self.source.get(self.pos, self.offset + j)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> ColumnMatrix<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Get the `i`-th element of the column, returning `None` instead of
+    /// panicking if it's out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        if i < self.size {
+            self.source.get(self.offset + i, self.pos)
+        } else {
+            None
+        }
+    }
 }
 
 // Implement row matrix indexing
@@ -275,6 +775,196 @@ where
     }
 }
 
+// Implement row matrix indexing (mutable)
+impl<'a, T> ops::Index<usize> for RowMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, j: usize) -> &T {
+        if j >= self.data.len() {
+            panic!(
+                "index {} out of range for row matrix with number of elements {}",
+                j,
+                self.data.len()
+            )
+        };
+        &self.data[j]
+    }
+}
+
+impl<'a, T> ops::IndexMut<usize> for RowMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, j: usize) -> &mut T {
+        if j >= self.data.len() {
+            panic!(
+                "index {} out of range for row matrix with number of elements {}",
+                j,
+                self.data.len()
+            )
+        };
+        &mut self.data[j]
+    }
+}
+
+// Implement column matrix indexing (mutable)
+impl<'a, T> ops::Index<usize> for ColumnMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        if i >= self.elements.len() {
+            panic!(
+                "index {} out of range for column matrix with number of elements {}",
+                i,
+                self.elements.len()
+            )
+        };
+        &self.elements[i]
+    }
+}
+
+impl<'a, T> ops::IndexMut<usize> for ColumnMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        if i >= self.elements.len() {
+            panic!(
+                "index {} out of range for column matrix with number of elements {}",
+                i,
+                self.elements.len()
+            )
+        };
+        &mut self.elements[i]
+    }
+}
+
+// Implement diagonal indexing
+// diagonal[i]
+impl<'a, T> ops::Index<usize> for Diagonal<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        if i >= self.len {
+            panic!(
+                "index {} out of range for diagonal with number of elements {}",
+                i, self.len
+            )
+        };
+        let (row, col) = diagonal_position(
+            self.row_offset,
+            self.col_offset,
+            self.row_stride,
+            self.col_stride,
+            self.offset,
+            i,
+        );
+        self.source.at(row, col)
+    }
+}
+
+impl<'a, T> DiagonalMut<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Get a mutable reference to the `i`-th element of the diagonal.
+    ///
+    /// # Panics
+    /// Panics if `i >= len` where `len` is the number of elements on the
+    /// diagonal.
+    pub fn get_mut(&mut self, i: usize) -> &mut T {
+        if i >= self.len {
+            panic!(
+                "index {} out of range for diagonal with number of elements {}",
+                i, self.len
+            )
+        };
+        let (row, col) = diagonal_position(
+            self.row_offset,
+            self.col_offset,
+            self.row_stride,
+            self.col_stride,
+            self.offset,
+            i,
+        );
+        self.source.at_mut(row, col)
+    }
+
+    /// Overwrite every element of the diagonal with `value`, writing back
+    /// into the parent matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// w.diagonal_mut(1).fill(0);
+    /// assert_eq!(
+    ///     w,
+    ///     matrix![
+    ///         3, 0, 4;
+    ///         1, 5, 0;
+    ///         2, 6, 5;
+    ///     ]
+    /// );
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        for i in 0..self.len {
+            *self.get_mut(i) = value;
+        }
+    }
+}
+
+// Implement diagonal indexing in mutable context
+// diagonal[i] = value
+impl<'a, T> ops::IndexMut<usize> for DiagonalMut<'a, T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i)
+    }
+}
+
+// Implement diagonal indexing
+// diagonal[i]
+impl<'a, T> ops::Index<usize> for DiagonalMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        if i >= self.len {
+            panic!(
+                "index {} out of range for diagonal with number of elements {}",
+                i, self.len
+            )
+        };
+        let (row, col) = diagonal_position(
+            self.row_offset,
+            self.col_offset,
+            self.row_stride,
+            self.col_stride,
+            self.offset,
+            i,
+        );
+        self.source.at(row, col)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -294,6 +984,17 @@ mod tests {
         assert_eq!(*w.at(1, 2), 9);
     }
 
+    #[test]
+    fn test_matrix_index_tuple() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        assert_eq!(w[(0, 0)], 3);
+        assert_eq!(w[(0, 2)], 4);
+        assert_eq!(w[(1, 1)], 5);
+    }
+
     #[test]
     #[should_panic]
     fn test_matrix_indexing_invalid_i() {
@@ -314,6 +1015,30 @@ mod tests {
         w.at(0, 10);
     }
 
+    #[test]
+    fn test_matrix_get() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        assert_eq!(w.get(0, 0), Some(&3));
+        assert_eq!(w.get(1, 2), Some(&9));
+        assert_eq!(w.get(10, 0), None);
+        assert_eq!(w.get(0, 10), None);
+    }
+
+    #[test]
+    fn test_matrix_get_mut() {
+        let mut w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        *w.get_mut(0, 0).unwrap() = 10;
+        assert_eq!(w.at(0, 0), &10);
+        assert_eq!(w.get_mut(10, 0), None);
+        assert_eq!(w.get_mut(0, 10), None);
+    }
+
     #[test]
     fn test_matrix_row() {
         let w = matrix![
@@ -381,6 +1106,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         assert_eq!(submatrix.at(0, 0), &5);
@@ -389,6 +1116,50 @@ mod tests {
         assert_eq!(submatrix.at(1, 1), &8);
     }
 
+    #[test]
+    fn test_submatrix_get() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 7, 8;
+        ];
+        // Sub matrix: [5, 9; 7, 8]
+        let submatrix = Submatrix {
+            nrows: 2,
+            ncols: 2,
+            row_offset: 1,
+            col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
+            source: &w,
+        };
+        assert_eq!(submatrix.get(0, 0), Some(&5));
+        assert_eq!(submatrix.get(1, 1), Some(&8));
+        assert_eq!(submatrix.get(10, 0), None);
+        assert_eq!(submatrix.get(0, 10), None);
+    }
+
+    #[test]
+    fn test_submatrix_index_tuple() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 7, 8;
+        ];
+        // Sub matrix: [5, 9; 7, 8]
+        let submatrix = Submatrix {
+            nrows: 2,
+            ncols: 2,
+            row_offset: 1,
+            col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
+            source: &w,
+        };
+        assert_eq!(submatrix[(0, 0)], 5);
+        assert_eq!(submatrix[(1, 1)], 8);
+    }
+
     #[test]
     #[should_panic]
     fn test_submatrix_indexing_invalid_j() {
@@ -403,6 +1174,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         submatrix.at(0, 10);
@@ -421,6 +1194,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         assert_eq!(
@@ -434,6 +1209,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_row_matrix_get() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let row = w.row(1);
+        assert_eq!(row.get(0), Some(&1));
+        assert_eq!(row.get(2), Some(&9));
+        assert_eq!(row.get(10), None);
+    }
+
     #[test]
     #[should_panic]
     fn test_submatrix_row_invalid() {
@@ -448,6 +1235,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         submatrix.row(100);
@@ -466,6 +1255,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         assert_eq!(
@@ -479,6 +1270,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_column_matrix_get() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let col = w.col(1);
+        assert_eq!(col.get(0), Some(&1));
+        assert_eq!(col.get(1), Some(&5));
+        assert_eq!(col.get(10), None);
+    }
+
     #[test]
     #[should_panic]
     fn test_submatrix_col_invalid() {
@@ -493,9 +1296,150 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &w,
         };
         submatrix.col(100);
     }
 
+    #[test]
+    fn test_submatrix_to_matrix() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 7, 8;
+        ];
+        // Sub matrix: [5, 9; 7, 8]
+        let submatrix = Submatrix {
+            nrows: 2,
+            ncols: 2,
+            row_offset: 1,
+            col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
+            source: &w,
+        };
+        assert_eq!(
+            submatrix.to_matrix(),
+            matrix![
+                5, 9;
+                7, 8;
+            ]
+        );
+    }
+
+    #[test]
+    fn test_submatrix_from() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let sub = w.slice(0..1, 1..);
+        let owned = Matrix::from(sub);
+        assert_eq!(owned, matrix![1, 4]);
+    }
+
+    #[test]
+    fn test_matrix_diagonal_main() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        let d = w.diagonal(0);
+        assert_eq!(d.len(), 3);
+        assert_eq!(d[0], 3);
+        assert_eq!(d[1], 5);
+        assert_eq!(d[2], 5);
+    }
+
+    #[test]
+    fn test_matrix_diagonal_super() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        let d = w.diagonal(1);
+        assert_eq!(d.len(), 2);
+        assert_eq!(d[0], 1);
+        assert_eq!(d[1], 9);
+    }
+
+    #[test]
+    fn test_matrix_diagonal_sub() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        let d = w.diagonal(-1);
+        assert_eq!(d.len(), 2);
+        assert_eq!(d[0], 1);
+        assert_eq!(d[1], 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_matrix_diagonal_invalid_offset() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        w.diagonal(3);
+    }
+
+    #[test]
+    fn test_matrix_diagonal_mut_fill() {
+        let mut w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        w.diagonal_mut(0).fill(0);
+        assert_eq!(
+            w,
+            matrix![
+                0, 1, 4;
+                1, 0, 9;
+                2, 6, 0;
+            ]
+        );
+    }
+
+    #[test]
+    fn test_matrix_diagonal_mut_index_mut() {
+        let mut w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        let mut d = w.diagonal_mut(0);
+        d[1] = 42;
+        assert_eq!(w.at(1, 1), &42);
+    }
+
+    #[test]
+    fn test_submatrix_diagonal() {
+        let w = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        // Sub matrix: [5, 9; 6, 5]
+        let submatrix = Submatrix {
+            nrows: 2,
+            ncols: 2,
+            row_offset: 1,
+            col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
+            source: &w,
+        };
+        let d = submatrix.diagonal(0);
+        assert_eq!(d.len(), 2);
+        assert_eq!(d[0], 5);
+        assert_eq!(d[1], 5);
+    }
 }
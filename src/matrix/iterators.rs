@@ -17,8 +17,13 @@
 //! TODO(pyk): Add docs here
 //!
 
-use crate::matrix::{ColumnMatrix, Matrix, RowMatrix, Submatrix};
+use crate::matrix::indexing::{diagonal_len, diagonal_position};
+use crate::matrix::{
+    ColumnMatrix, ColumnMatrixMut, Diagonal, Matrix, RowMatrix, RowMatrixMut,
+    Submatrix,
+};
 use num::Num;
+use std::slice;
 
 /// Matrix row iterator.
 pub struct MatrixRowIterator<'a, T: 'a>
@@ -120,6 +125,200 @@ where
             pos: 0,
         }
     }
+
+    /// Mutably iterates over rows of the matrix, e.g. for filling or
+    /// transforming rows in place without reconstructing the whole
+    /// matrix. Rows are disjoint in the matrix's row-major backing
+    /// store, so each step just splits off the next contiguous chunk.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![3, 1, 4; 1, 5, 9];
+    /// for mut row in w.rows_mut() {
+    ///     row[0] = 0;
+    /// }
+    /// assert_eq!(w, matrix![0, 1, 4; 0, 5, 9]);
+    /// ```
+    pub fn rows_mut<'a>(&'a mut self) -> MatrixRowIteratorMut<'a, T> {
+        let ncols = self.ncols;
+        MatrixRowIteratorMut {
+            remainder: Some(self.vec.as_mut_slice()),
+            ncols,
+        }
+    }
+
+    /// Mutably iterates over columns of the matrix, e.g. for filling or
+    /// transforming columns in place without reconstructing the whole
+    /// matrix. Unlike rows, columns are interleaved in the backing
+    /// store, so every column is built up front as a set of disjoint
+    /// `&mut T` references.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![3, 1, 4; 1, 5, 9];
+    /// for mut col in w.cols_mut() {
+    ///     col[0] = 0;
+    /// }
+    /// assert_eq!(w, matrix![0, 0, 0; 1, 5, 9]);
+    /// ```
+    pub fn cols_mut<'a>(&'a mut self) -> MatrixColumnIteratorMut<'a, T> {
+        let ncols = self.ncols;
+        let mut columns: Vec<Vec<&'a mut T>> =
+            (0..ncols).map(|_| Vec::new()).collect();
+        for row in self.vec.as_mut_slice().chunks_mut(ncols) {
+            for (j, cell) in row.iter_mut().enumerate() {
+                columns[j].push(cell);
+            }
+        }
+        MatrixColumnIteratorMut {
+            columns: columns.into_iter(),
+        }
+    }
+
+    /// Iterates over elements on the main diagonal: `(k, k)` for `k` in
+    /// `0..min(nrows, ncols)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// let diag: Vec<&i32> = w.diag().collect();
+    /// assert_eq!(diag, [&3, &5, &5]);
+    /// ```
+    pub fn diag<'a>(&'a self) -> MatrixDiagonalElementIterator<'a, T> {
+        self.diag_offset(0)
+    }
+
+    /// Iterates over elements on the diagonal at `offset` from the main
+    /// diagonal: a positive offset shifts into columns (super-diagonal),
+    /// a negative offset shifts into rows (sub-diagonal).
+    ///
+    /// # Panics
+    /// Panics if `offset` leaves no elements on the diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// let super_diag: Vec<&i32> = w.diag_offset(1).collect();
+    /// assert_eq!(super_diag, [&1, &9]);
+    /// ```
+    pub fn diag_offset<'a>(&'a self, offset: isize) -> MatrixDiagonalElementIterator<'a, T> {
+        let len = diagonal_len(self.nrows, self.ncols, offset);
+        MatrixDiagonalElementIterator {
+            source: self,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            offset,
+            pos: 0,
+            len,
+        }
+    }
+
+    /// Iterates over elements on the anti-diagonal: `(k, ncols - 1 - k)`
+    /// for `k` in `0..min(nrows, ncols)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// let anti_diag: Vec<&i32> = w.anti_diag().collect();
+    /// assert_eq!(anti_diag, [&4, &5, &2]);
+    /// ```
+    pub fn anti_diag<'a>(&'a self) -> MatrixAntiDiagonalElementIterator<'a, T> {
+        MatrixAntiDiagonalElementIterator {
+            source: self,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            ncols: self.ncols,
+            pos: 0,
+            len: std::cmp::min(self.nrows, self.ncols),
+        }
+    }
+
+    /// Iterates over every cell of the matrix in row-major order, yielding
+    /// `(i, j, value)` instead of just `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![3, 1; 4, 1];
+    /// let cells: Vec<(usize, usize, &i32)> = w.indexed_elements().collect();
+    /// assert_eq!(
+    ///     cells,
+    ///     [(0, 0, &3), (0, 1, &1), (1, 0, &4), (1, 1, &1)]
+    /// );
+    /// ```
+    pub fn indexed_elements<'a>(&'a self) -> MatrixIndexedElementIterator<'a, T> {
+        MatrixIndexedElementIterator {
+            matrix: self,
+            pos: 0,
+            len: self.nrows * self.ncols,
+        }
+    }
+}
+
+/// Mutable matrix row iterator, produced by [`Matrix::rows_mut`].
+///
+/// [`Matrix::rows_mut`]: struct.Matrix.html#method.rows_mut
+pub struct MatrixRowIteratorMut<'a, T: 'a> {
+    remainder: Option<&'a mut [T]>,
+    ncols: usize,
+}
+
+impl<'a, T> Iterator for MatrixRowIteratorMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = RowMatrixMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slice = self.remainder.take()?;
+        if slice.len() < self.ncols {
+            return None;
+        }
+        let (data, rest) = slice.split_at_mut(self.ncols);
+        self.remainder = Some(rest);
+        Some(RowMatrixMut { data })
+    }
+}
+
+/// Mutable matrix column iterator, produced by [`Matrix::cols_mut`].
+///
+/// [`Matrix::cols_mut`]: struct.Matrix.html#method.cols_mut
+pub struct MatrixColumnIteratorMut<'a, T: 'a> {
+    columns: std::vec::IntoIter<Vec<&'a mut T>>,
+}
+
+impl<'a, T> Iterator for MatrixColumnIteratorMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = ColumnMatrixMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.columns
+            .next()
+            .map(|elements| ColumnMatrixMut { elements })
+    }
 }
 
 /// Matrix row element iterator.
@@ -230,6 +429,64 @@ where
     }
 }
 
+impl<'a, T> RowMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Mutably iterates over the elements of the row, writing changes
+    /// back into the parent matrix. A row is contiguous in the matrix's
+    /// flat backing store, so this reuses a plain slice iterator.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![3, 1, 4; 1, 5, 9];
+    /// w.row_mut(0).elements_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(w, matrix![30, 10, 40; 1, 5, 9]);
+    /// ```
+    pub fn elements_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+}
+
+/// Mutable element iterator over a [`ColumnMatrixMut`]. Since the
+/// column's elements are already held as disjoint `&mut T` references,
+/// this just reborrows each of them in turn.
+///
+/// [`ColumnMatrixMut`]: struct.ColumnMatrixMut.html
+pub struct MatrixColumnElementMutIterator<'s, 'a, T: 'a> {
+    inner: slice::IterMut<'s, &'a mut T>,
+}
+
+impl<'s, 'a, T> Iterator for MatrixColumnElementMutIterator<'s, 'a, T> {
+    type Item = &'s mut T;
+
+    fn next(&mut self) -> Option<&'s mut T> {
+        self.inner.next().map(|cell| &mut **cell)
+    }
+}
+
+impl<'a, T> ColumnMatrixMut<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Mutably iterates over the elements of the column, writing changes
+    /// back into the parent matrix.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![3, 1, 4; 1, 5, 9];
+    /// w.col_mut(0).elements_mut().for_each(|x| *x *= 10);
+    /// assert_eq!(w, matrix![30, 1, 4; 10, 5, 9]);
+    /// ```
+    pub fn elements_mut(&mut self) -> MatrixColumnElementMutIterator<'_, 'a, T> {
+        MatrixColumnElementMutIterator {
+            inner: self.elements.iter_mut(),
+        }
+    }
+}
+
 /// Submatrix row iterator.
 pub struct SubmatrixRowIterator<'a, T: 'a>
 where
@@ -335,4 +592,262 @@ where
             pos: 0,
         }
     }
+
+    /// Iterates over elements on the main diagonal of the submatrix. See
+    /// [`Matrix::diag`] for the same layout.
+    ///
+    /// [`Matrix::diag`]: struct.Matrix.html#method.diag
+    pub fn diag(&'a self) -> MatrixDiagonalElementIterator<'a, T> {
+        self.diag_offset(0)
+    }
+
+    /// Iterates over elements on the diagonal at `offset` from the main
+    /// diagonal of the submatrix. See [`Matrix::diag_offset`] for the
+    /// offset convention.
+    ///
+    /// # Panics
+    /// Panics if `offset` leaves no elements on the diagonal.
+    ///
+    /// [`Matrix::diag_offset`]: struct.Matrix.html#method.diag_offset
+    pub fn diag_offset(&'a self, offset: isize) -> MatrixDiagonalElementIterator<'a, T> {
+        let len = diagonal_len(self.nrows, self.ncols, offset);
+        MatrixDiagonalElementIterator {
+            source: self.source,
+            row_offset: self.row_offset,
+            col_offset: self.col_offset,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            offset,
+            pos: 0,
+            len,
+        }
+    }
+
+    /// Iterates over elements on the anti-diagonal of the submatrix. See
+    /// [`Matrix::anti_diag`] for the same layout.
+    ///
+    /// [`Matrix::anti_diag`]: struct.Matrix.html#method.anti_diag
+    pub fn anti_diag(&'a self) -> MatrixAntiDiagonalElementIterator<'a, T> {
+        MatrixAntiDiagonalElementIterator {
+            source: self.source,
+            row_offset: self.row_offset,
+            col_offset: self.col_offset,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            ncols: self.ncols,
+            pos: 0,
+            len: std::cmp::min(self.nrows, self.ncols),
+        }
+    }
+
+    /// Iterates over every cell of the submatrix in row-major order,
+    /// yielding `(i, j, value)` instead of just `value`. See
+    /// [`Matrix::indexed_elements`] for the same layout.
+    ///
+    /// [`Matrix::indexed_elements`]: struct.Matrix.html#method.indexed_elements
+    pub fn indexed_elements(&'a self) -> SubmatrixIndexedElementIterator<'a, T> {
+        SubmatrixIndexedElementIterator {
+            submatrix: self,
+            pos: 0,
+            len: self.nrows * self.ncols,
+        }
+    }
+}
+
+/// Iterator over elements on a matrix's (possibly offset) diagonal,
+/// produced by [`Matrix::diag`]/[`Matrix::diag_offset`].
+///
+/// [`Matrix::diag`]: struct.Matrix.html#method.diag
+/// [`Matrix::diag_offset`]: struct.Matrix.html#method.diag_offset
+pub struct MatrixDiagonalElementIterator<'a, T: 'a>
+where
+    T: Num + Copy,
+{
+    source: &'a Matrix<T>,
+    row_offset: usize,
+    col_offset: usize,
+    row_stride: usize,
+    col_stride: usize,
+    offset: isize,
+    pos: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for MatrixDiagonalElementIterator<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.len {
+            let (i, j) = diagonal_position(
+                self.row_offset,
+                self.col_offset,
+                self.row_stride,
+                self.col_stride,
+                self.offset,
+                self.pos,
+            );
+            // Increment the position of the diagonal iterator.
+            self.pos += 1;
+            Some(self.source.at(i, j))
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over elements on a matrix's anti-diagonal, produced by
+/// [`Matrix::anti_diag`].
+///
+/// [`Matrix::anti_diag`]: struct.Matrix.html#method.anti_diag
+pub struct MatrixAntiDiagonalElementIterator<'a, T: 'a>
+where
+    T: Num + Copy,
+{
+    source: &'a Matrix<T>,
+    row_offset: usize,
+    col_offset: usize,
+    row_stride: usize,
+    col_stride: usize,
+    ncols: usize,
+    pos: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for MatrixAntiDiagonalElementIterator<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.len {
+            let i = self.row_offset + self.pos * self.row_stride;
+            let j = self.col_offset + (self.ncols - 1 - self.pos) * self.col_stride;
+            // Increment the position of the anti-diagonal iterator.
+            self.pos += 1;
+            Some(self.source.at(i, j))
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over every cell of a matrix in row-major order, yielding
+/// `(i, j, value)`, produced by [`Matrix::indexed_elements`].
+///
+/// [`Matrix::indexed_elements`]: struct.Matrix.html#method.indexed_elements
+pub struct MatrixIndexedElementIterator<'a, T: 'a>
+where
+    T: Num + Copy,
+{
+    matrix: &'a Matrix<T>,
+    pos: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for MatrixIndexedElementIterator<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.len {
+            let i = self.pos / self.matrix.ncols;
+            let j = self.pos % self.matrix.ncols;
+            // Increment the position of the indexed element iterator.
+            self.pos += 1;
+            Some((i, j, self.matrix.at(i, j)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Iterator over every cell of a submatrix in row-major order, yielding
+/// `(i, j, value)`, produced by [`Submatrix::indexed_elements`].
+///
+/// [`Submatrix::indexed_elements`]: struct.Submatrix.html#method.indexed_elements
+pub struct SubmatrixIndexedElementIterator<'a, T: 'a>
+where
+    T: Num + Copy,
+{
+    submatrix: &'a Submatrix<'a, T>,
+    pos: usize,
+    len: usize,
+}
+
+impl<'a, T> Iterator for SubmatrixIndexedElementIterator<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = (usize, usize, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.len {
+            let i = self.pos / self.submatrix.ncols;
+            let j = self.pos % self.submatrix.ncols;
+            // Increment the position of the indexed element iterator.
+            self.pos += 1;
+            Some((i, j, self.submatrix.at(i, j)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Diagonal element iterator.
+pub struct DiagonalElementIterator<'a, T: 'a>
+where
+    T: Num + Copy,
+{
+    diagonal: &'a Diagonal<'a, T>,
+    pos: usize,
+}
+
+impl<'a, T> Iterator for DiagonalElementIterator<'a, T>
+where
+    T: Num + Copy,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.pos < self.diagonal.len() {
+            // Increment the position of the diagonal iterator.
+            self.pos += 1;
+            // Return the element
+            Some(self.diagonal[self.pos - 1])
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T> Diagonal<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Iterates over elements of the diagonal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    ///     2, 6, 5;
+    /// ];
+    /// let mut elements = w.diagonal(0).elements();
+    ///
+    /// assert_eq!(elements.next(), Some(3));
+    /// assert_eq!(elements.next(), Some(5));
+    /// assert_eq!(elements.next(), Some(5));
+    /// assert_eq!(elements.next(), None);
+    /// ```
+    pub fn elements(&'a self) -> DiagonalElementIterator<'a, T> {
+        DiagonalElementIterator { diagonal: self, pos: 0 }
+    }
 }
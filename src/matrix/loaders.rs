@@ -14,14 +14,25 @@
 
 // TODO(pyk): Add docs about how to load matrix from external file here
 
-use crate::matrix::errors::{MatrixLoadError, MatrixLoadErrorKind};
+use crate::matrix::errors::{
+    MatrixLoadError, MatrixLoadErrorKind, MatrixSaveError,
+};
 use crate::matrix::Matrix;
 use crate::utils;
+use crate::utils::LittleEndianBytes;
 use csv;
-use num::{FromPrimitive, Num};
+use num::{FromPrimitive, Num, Zero};
 use std::fs::File;
+use std::io;
+use std::io::{Read, Write};
 use std::marker::PhantomData;
 use std::path::Path;
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use serde::ser::Serialize;
+#[cfg(feature = "serde")]
+use serde_json;
 
 /// Matrix loader for CSV formatted file.
 ///
@@ -35,6 +46,9 @@ where
 {
     file_path: P,
     has_headers: bool,
+    delimiter: u8,
+    flexible: bool,
+    trim: csv::Trim,
     // We use this to make compiler happy
     phantom: PhantomData<T>,
 }
@@ -58,12 +72,70 @@ where
     /// ```
     pub fn has_headers(self, yes: bool) -> MatrixLoaderForCSV<T, P> {
         MatrixLoaderForCSV {
-            file_path: self.file_path,
             has_headers: yes,
-            phantom: PhantomData,
+            ..self
         }
     }
 
+    /// Set the field delimiter used when parsing the CSV file. By default,
+    /// it is set to `b','`. Use `b'\t'` to load TSV files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/dataset.csv")
+    ///     .delimiter(b'\t')
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn delimiter(self, delimiter: u8) -> MatrixLoaderForCSV<T, P> {
+        MatrixLoaderForCSV { delimiter, ..self }
+    }
+
+    /// Set to true to allow rows with a different number of fields than the
+    /// rest of the file. Short rows are padded with zeroes; a row with more
+    /// fields than the first row is reported as
+    /// `MatrixLoadErrorKind::InconsistentColumn`. By default, it is set to
+    /// false, which rejects ragged CSV/TSV files outright.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/dataset.csv")
+    ///     .flexible(true)
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn flexible(self, yes: bool) -> MatrixLoaderForCSV<T, P> {
+        MatrixLoaderForCSV {
+            flexible: yes,
+            ..self
+        }
+    }
+
+    /// Set whether the CSV reader trims leading and trailing whitespace
+    /// from each field and header. By default, it is set to false. Numeric
+    /// fields are trimmed before parsing whatever this is set to.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/dataset.csv")
+    ///     .trim(true)
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn trim(self, yes: bool) -> MatrixLoaderForCSV<T, P> {
+        let trim = if yes { csv::Trim::All } else { csv::Trim::None };
+        MatrixLoaderForCSV { trim, ..self }
+    }
+
     /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
     ///
     /// # Examples
@@ -76,14 +148,132 @@ where
     where
         T: FromPrimitive + Num + Copy + utils::TypeName,
     {
-        // Open CSV file
+        // Open the file and delegate the parsing itself to the reader-based
+        // loader, so the two constructors share one code path.
         let file = File::open(self.file_path)?;
+        MatrixLoaderForCSVReader {
+            reader: file,
+            has_headers: self.has_headers,
+            delimiter: self.delimiter,
+            flexible: self.flexible,
+            trim: self.trim,
+            phantom: PhantomData,
+        }
+        .load()
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    ///
+    pub fn from_csv<P>(file_path: P) -> MatrixLoaderForCSV<T, P>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixLoaderForCSV {
+            file_path,
+            has_headers: false,
+            delimiter: b',',
+            flexible: false,
+            trim: csv::Trim::None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Matrix loader for CSV formatted data coming from any `Read` source.
+///
+/// See also: [`Matrix::from_csv_reader`].
+///
+/// [`Matrix::from_csv_reader`]: struct.Matrix.html#method.from_csv_reader
+#[derive(Debug)]
+pub struct MatrixLoaderForCSVReader<T, R>
+where
+    R: io::Read,
+{
+    reader: R,
+    has_headers: bool,
+    delimiter: u8,
+    flexible: bool,
+    trim: csv::Trim,
+    // We use this to make compiler happy
+    phantom: PhantomData<T>,
+}
+
+impl<T, R> MatrixLoaderForCSVReader<T, R>
+where
+    R: io::Read,
+{
+    /// Set to true to treat the first row as a special header row. By default, it is set
+    /// to false.
+    pub fn has_headers(self, yes: bool) -> MatrixLoaderForCSVReader<T, R> {
+        MatrixLoaderForCSVReader {
+            has_headers: yes,
+            ..self
+        }
+    }
+
+    /// Set the field delimiter used when parsing the CSV data. By default,
+    /// it is set to `b','`. Use `b'\t'` to load TSV data.
+    pub fn delimiter(self, delimiter: u8) -> MatrixLoaderForCSVReader<T, R> {
+        MatrixLoaderForCSVReader { delimiter, ..self }
+    }
+
+    /// Set to true to allow rows with a different number of fields than the
+    /// rest of the data. Short rows are padded with zeroes; a row with more
+    /// fields than the first row is reported as
+    /// `MatrixLoadErrorKind::InconsistentColumn`. By default, it is set to
+    /// false, which rejects ragged CSV/TSV data outright.
+    pub fn flexible(self, yes: bool) -> MatrixLoaderForCSVReader<T, R> {
+        MatrixLoaderForCSVReader {
+            flexible: yes,
+            ..self
+        }
+    }
+
+    /// Set whether the CSV reader trims leading and trailing whitespace
+    /// from each field and header. By default, it is set to false. Numeric
+    /// fields are trimmed before parsing whatever this is set to.
+    pub fn trim(self, yes: bool) -> MatrixLoaderForCSVReader<T, R> {
+        let trim = if yes { csv::Trim::All } else { csv::Trim::None };
+        MatrixLoaderForCSVReader { trim, ..self }
+    }
+
+    /// Load Matrix from a CSV-formatted `Read` source. You need to
+    /// explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let data = "3,1,4\n1,5,9\n".as_bytes();
+    /// let dataset: Matrix<f32> = Matrix::from_csv_reader(data).load().unwrap();
+    /// ```
+    pub fn load(self) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        T: FromPrimitive + Num + Copy + utils::TypeName,
+    {
         let mut rdr = csv::ReaderBuilder::new()
             .has_headers(self.has_headers)
-            .from_reader(file);
+            .delimiter(self.delimiter)
+            .flexible(self.flexible)
+            .trim(self.trim)
+            .from_reader(self.reader);
 
         // Collect each row
         let mut elements = Vec::new();
+        let mut ncols = None;
         for result in rdr.records() {
             // Convert each row in the CSV file to RowMatrix
             let record = result?;
@@ -106,6 +296,20 @@ where
                 };
                 rows.push(element);
             }
+            if self.flexible {
+                let width = *ncols.get_or_insert(rows.len());
+                if rows.len() > width {
+                    return Err(MatrixLoadError::new(
+                        MatrixLoadErrorKind::InconsistentColumn,
+                        format!(
+                            "row has {} columns, expected at most {}",
+                            rows.len(),
+                            width
+                        ),
+                    ));
+                }
+                rows.resize(width, T::zero());
+            }
             elements.push(rows);
         }
         if elements.len() == 0 {
@@ -122,24 +326,485 @@ impl<T> Matrix<T>
 where
     T: Num + Copy,
 {
-    /// Load Matrix from CSV file. You need to explicitly annotate the numeric type.
+    /// Load Matrix from any `Read` source formatted as CSV. You need to
+    /// explicitly annotate the numeric type.
+    ///
+    /// This is useful for loading a matrix from an in-memory buffer, a
+    /// network stream, or stdin, without going through the filesystem.
+    /// [`Matrix::from_csv`] delegates to this after opening the file.
+    ///
+    /// [`Matrix::from_csv`]: struct.Matrix.html#method.from_csv
     ///
     /// # Examples
     ///
     /// ```
     /// use crabsformer::prelude::*;
     ///
-    /// let dataset: Matrix<f32> = Matrix::from_csv("tests/data/weight.csv").load().unwrap();
+    /// let data = "3,1,4\n1,5,9\n".as_bytes();
+    /// let dataset: Matrix<f32> = Matrix::from_csv_reader(data).load().unwrap();
+    /// ```
+    pub fn from_csv_reader<R>(reader: R) -> MatrixLoaderForCSVReader<T, R>
+    where
+        R: io::Read,
+    {
+        MatrixLoaderForCSVReader {
+            reader,
+            has_headers: false,
+            delimiter: b',',
+            flexible: false,
+            trim: csv::Trim::None,
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Matrix loader for JSON formatted file.
+///
+/// See also: [`Matrix::from_json`].
+///
+/// [`Matrix::from_json`]: struct.Matrix.html#method.from_json
+#[cfg(feature = "serde")]
+#[derive(Debug)]
+pub struct MatrixLoaderForJSON<T, P>
+where
+    P: AsRef<Path>,
+{
+    file_path: P,
+    // We use this to make compiler happy
+    phantom: PhantomData<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T, P> MatrixLoaderForJSON<T, P>
+where
+    P: AsRef<Path>,
+{
+    /// Load Matrix from JSON file. You need to explicitly annotate the numeric type.
+    ///
+    /// # Examples
     /// ```
+    /// use crabsformer::prelude::*;
     ///
-    pub fn from_csv<P>(file_path: P) -> MatrixLoaderForCSV<T, P>
+    /// let dataset: Matrix<f32> = Matrix::from_json("tests/data/weight.json").load().unwrap();
+    /// ```
+    pub fn load(self) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        T: FromPrimitive + Num + Copy + DeserializeOwned,
+    {
+        let file = File::open(self.file_path)?;
+        if file.metadata()?.len() == 0 {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::Empty,
+                format!("file is empty"),
+            ));
+        }
+        serde_json::from_reader(file).map_err(|err| {
+            if err.is_data() {
+                MatrixLoadError::new(
+                    MatrixLoadErrorKind::InvalidElement,
+                    format!("{}", err),
+                )
+            } else {
+                MatrixLoadError::from(err)
+            }
+        })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Load Matrix from JSON file. You need to explicitly annotate the numeric type.
+    ///
+    /// The JSON document is expected to be shaped like
+    /// `{ "nrows": n, "ncols": m, "elements": [...] }`, mirroring
+    /// [`Matrix::to_json`].
+    ///
+    /// [`Matrix::to_json`]: struct.Matrix.html#method.to_json
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f32> = Matrix::from_json("tests/data/weight.json").load().unwrap();
+    /// ```
+    pub fn from_json<P>(file_path: P) -> MatrixLoaderForJSON<T, P>
     where
         P: AsRef<Path>,
     {
-        MatrixLoaderForCSV {
+        MatrixLoaderForJSON {
             file_path,
-            has_headers: false,
             phantom: PhantomData,
         }
     }
+
+    /// Save the matrix to a JSON file, shaped like
+    /// `{ "nrows": n, "ncols": m, "elements": [...] }`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![3.0, 1.0; 4.0, 1.0];
+    /// w.to_json("tests/data/weight.json").unwrap();
+    /// ```
+    pub fn to_json<P>(&self, file_path: P) -> Result<(), MatrixLoadError>
+    where
+        P: AsRef<Path>,
+        T: Serialize,
+    {
+        let file = File::create(file_path)?;
+        serde_json::to_writer(file, self).map_err(MatrixLoadError::from)
+    }
+}
+
+/// Matrix writer for CSV formatted file.
+///
+/// See also: [`Matrix::to_csv`].
+///
+/// [`Matrix::to_csv`]: struct.Matrix.html#method.to_csv
+#[derive(Debug)]
+pub struct MatrixWriterForCSV<'a, T, P>
+where
+    T: Num + Copy,
+    P: AsRef<Path>,
+{
+    matrix: &'a Matrix<T>,
+    file_path: P,
+    has_headers: bool,
+    delimiter: u8,
+}
+
+impl<'a, T, P> MatrixWriterForCSV<'a, T, P>
+where
+    T: Num + Copy,
+    P: AsRef<Path>,
+{
+    /// Set to true to write a special header row before the matrix rows. By
+    /// default, it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![3.0, 1.0; 4.0, 1.0];
+    /// w.to_csv("tests/data/weight.csv").has_headers(true).write().unwrap();
+    /// ```
+    pub fn has_headers(self, yes: bool) -> MatrixWriterForCSV<'a, T, P> {
+        MatrixWriterForCSV {
+            matrix: self.matrix,
+            file_path: self.file_path,
+            has_headers: yes,
+            delimiter: self.delimiter,
+        }
+    }
+
+    /// Set the field delimiter used when writing the CSV file. By default,
+    /// it is set to `b','`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![3.0, 1.0; 4.0, 1.0];
+    /// w.to_csv("tests/data/weight.csv").delimiter(b';').write().unwrap();
+    /// ```
+    pub fn delimiter(self, delimiter: u8) -> MatrixWriterForCSV<'a, T, P> {
+        MatrixWriterForCSV {
+            matrix: self.matrix,
+            file_path: self.file_path,
+            has_headers: self.has_headers,
+            delimiter,
+        }
+    }
+
+    /// Write the matrix to the CSV file, one matrix row per CSV record.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![3.0, 1.0; 4.0, 1.0];
+    /// w.to_csv("tests/data/weight.csv").write().unwrap();
+    /// ```
+    pub fn write(self) -> Result<(), MatrixSaveError>
+    where
+        T: ToString,
+    {
+        let file = File::create(self.file_path)?;
+        let mut wtr = csv::WriterBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .from_writer(file);
+
+        for row in self.matrix.rows() {
+            let record: Vec<String> =
+                row.elements().map(|value| value.to_string()).collect();
+            wtr.write_record(&record)?;
+        }
+        wtr.flush()?;
+        Ok(())
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Save the matrix to a CSV file, one matrix row per CSV record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![3.0, 1.0; 4.0, 1.0];
+    /// w.to_csv("tests/data/weight.csv").write().unwrap();
+    /// ```
+    pub fn to_csv<P>(&self, file_path: P) -> MatrixWriterForCSV<T, P>
+    where
+        P: AsRef<Path>,
+    {
+        MatrixWriterForCSV {
+            matrix: self,
+            file_path,
+            has_headers: false,
+            delimiter: b',',
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy + LittleEndianBytes + utils::NumpyDescr,
+{
+    /// Save the matrix to a NumPy `.npy` file: the standard `\x93NUMPY`
+    /// header (format version, then an ASCII dict giving `descr`,
+    /// `fortran_order` and `shape`) followed by the raw little-endian
+    /// element bytes in row-major order. Unlike [`Matrix::to_csv`], this
+    /// is lossless for floating point elements and avoids any string
+    /// round-trip, and the file can be loaded back with `numpy.load`.
+    ///
+    /// [`Matrix::to_csv`]: struct.Matrix.html#method.to_csv
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = matrix![3.0, 1.0; 4.0, 1.0];
+    /// w.save_npy("tests/data/weight.npy").unwrap();
+    /// ```
+    pub fn save_npy<P>(&self, file_path: P) -> Result<(), MatrixSaveError>
+    where
+        P: AsRef<Path>,
+    {
+        let [nrows, ncols] = self.shape();
+        let mut file = File::create(file_path)?;
+        utils::write_npy_header(&mut file, T::DESCR, &[nrows, ncols])?;
+        for row in self.rows() {
+            for value in row.elements() {
+                file.write_all(&value.to_le_bytes_vec())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Load a matrix from a NumPy `.npy` file previously saved with
+    /// [`Matrix::save_npy`] (or with `numpy.save`). You need to explicitly
+    /// annotate the numeric type, which must match the `descr` the file
+    /// was saved with. The shape is inferred from the header, and must
+    /// describe a 2-dimensional, C-order (`fortran_order: False`) array.
+    ///
+    /// [`Matrix::save_npy`]: struct.Matrix.html#method.save_npy
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Matrix<f64> = Matrix::load_npy("tests/data/weight.npy").unwrap();
+    /// ```
+    pub fn load_npy<P>(file_path: P) -> Result<Matrix<T>, MatrixLoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(file_path)?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+
+        let (shape, data) = utils::read_npy_header::<T>(&buf).map_err(|msg| {
+            MatrixLoadError::new(MatrixLoadErrorKind::InvalidFormat, msg)
+        })?;
+        if shape.len() != 2 {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidFormat,
+                format!("expected a 2-dimensional shape, found {:?}", shape),
+            ));
+        }
+        let (nrows, ncols) = (shape[0], shape[1]);
+        let expected_len = nrows * ncols * T::WIDTH;
+        if data.len() != expected_len {
+            return Err(MatrixLoadError::new(
+                MatrixLoadErrorKind::InvalidFormat,
+                format!(
+                    "expected {} bytes of element data, found {}",
+                    expected_len,
+                    data.len()
+                ),
+            ));
+        }
+
+        let mut offset = 0;
+        let mut elements = Vec::with_capacity(nrows);
+        for _ in 0..nrows {
+            let mut row = Vec::with_capacity(ncols);
+            for _ in 0..ncols {
+                row.push(T::from_le_bytes_slice(&data[offset..offset + T::WIDTH]));
+                offset += T::WIDTH;
+            }
+            elements.push(row);
+        }
+        Ok(Matrix::from(elements))
+    }
+}
+
+/// Iterator over row-chunks of a CSV file, yielded one `Matrix<T>` at a
+/// time instead of buffering the whole file.
+///
+/// See also: [`Matrix::from_csv_chunked`].
+///
+/// [`Matrix::from_csv_chunked`]: struct.Matrix.html#method.from_csv_chunked
+pub struct MatrixCsvChunks<T, R>
+where
+    R: io::Read,
+{
+    records: Option<csv::StringRecordsIntoIter<R>>,
+    open_error: Option<MatrixLoadError>,
+    rows_per_chunk: usize,
+    line: usize,
+    // We use this to make compiler happy
+    phantom: PhantomData<T>,
+}
+
+impl<T, R> Iterator for MatrixCsvChunks<T, R>
+where
+    T: FromPrimitive + Num + Copy + utils::TypeName,
+    R: io::Read,
+{
+    type Item = Result<Matrix<T>, MatrixLoadError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(err) = self.open_error.take() {
+            return Some(Err(err));
+        }
+        let records = self.records.as_mut()?;
+
+        let mut elements = Vec::with_capacity(self.rows_per_chunk);
+        let mut ncols = None;
+        for _ in 0..self.rows_per_chunk {
+            let record = match records.next() {
+                Some(Ok(record)) => record,
+                Some(Err(err)) => return Some(Err(MatrixLoadError::from(err))),
+                None => break,
+            };
+            self.line += 1;
+
+            let width = *ncols.get_or_insert(record.len());
+            if record.len() != width {
+                return Some(Err(MatrixLoadError::new(
+                    MatrixLoadErrorKind::InconsistentColumn,
+                    format!(
+                        "line {}: row has {} columns, expected {}",
+                        self.line,
+                        record.len(),
+                        width
+                    ),
+                )));
+            }
+
+            let mut row = Vec::with_capacity(record.len());
+            for value in record.iter() {
+                let element = match T::from_str_radix(value.trim(), 10) {
+                    Ok(value) => value,
+                    Err(_err) => {
+                        return Some(Err(MatrixLoadError::new(
+                            MatrixLoadErrorKind::InvalidElement,
+                            format!(
+                                "line {}: {:?} is not valid {}",
+                                self.line,
+                                value,
+                                T::type_name()
+                            ),
+                        )));
+                    }
+                };
+                row.push(element);
+            }
+            elements.push(row);
+        }
+
+        if elements.is_empty() {
+            return None;
+        }
+        Some(Ok(Matrix::from(elements)))
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Load a CSV file as a stream of row-chunks instead of one matrix, so
+    /// that a gigabyte-sized file can be processed without holding the
+    /// whole thing in memory at once. Each item of the returned iterator
+    /// is its own `Matrix<T>` of at most `rows_per_chunk` rows (the last
+    /// chunk may be smaller). You need to explicitly annotate the numeric
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// for chunk in Matrix::<f32>::from_csv_chunked("tests/data/weight.csv", 1000) {
+    ///     let chunk = chunk.unwrap();
+    ///     // process `chunk`
+    /// }
+    /// ```
+    pub fn from_csv_chunked<P>(
+        file_path: P,
+        rows_per_chunk: usize,
+    ) -> MatrixCsvChunks<T, File>
+    where
+        P: AsRef<Path>,
+    {
+        match File::open(file_path) {
+            Ok(file) => {
+                let rdr = csv::ReaderBuilder::new()
+                    .has_headers(false)
+                    .from_reader(file);
+                MatrixCsvChunks {
+                    records: Some(rdr.into_records()),
+                    open_error: None,
+                    rows_per_chunk,
+                    line: 0,
+                    phantom: PhantomData,
+                }
+            }
+            Err(err) => MatrixCsvChunks {
+                records: None,
+                open_error: Some(MatrixLoadError::from(err)),
+                rows_per_chunk,
+                line: 0,
+                phantom: PhantomData,
+            },
+        }
+    }
 }
@@ -14,10 +14,54 @@
 
 // TODO(pyk): Add docs here
 
-use crate::matrix::Matrix;
-use num::{FromPrimitive, Num};
+use crate::error::CrabsformerError;
+use crate::matrix::{ColumnMatrix, Matrix, RowMatrix};
+use crate::vector::Vector;
+use num::{Float, FromPrimitive, Num};
 use std::ops;
 
+// Tile size used by the cache-blocked fallback in `try_dot`. Chosen to
+// keep a `BLOCK_SIZE^2` tile of `f64`s comfortably inside a typical L1
+// cache; not tuned further since the `blas` feature is the fast path for
+// performance-sensitive callers.
+const BLOCK_SIZE: usize = 64;
+
+// Returns the shape the two matrix shapes broadcast to, NumPy-style: each
+// axis must either match or have one side equal to 1, in which case that
+// axis is virtually repeated to the other side's length. Returns a
+// `CrabsformerError::ShapeMismatch` if no axis-by-axis agreement exists.
+fn try_broadcast_shape(
+    a: [usize; 2],
+    b: [usize; 2],
+) -> Result<[usize; 2], CrabsformerError> {
+    let mut shape = [0usize; 2];
+    for axis in 0..2 {
+        shape[axis] = match (a[axis], b[axis]) {
+            (x, y) if x == y => x,
+            (1, y) => y,
+            (x, 1) => x,
+            _ => {
+                return Err(CrabsformerError::ShapeMismatch {
+                    lhs: a.to_vec(),
+                    rhs: b.to_vec(),
+                });
+            }
+        };
+    }
+    Ok(shape)
+}
+
+// Returns the shape the two matrix shapes broadcast to, NumPy-style.
+//
+// # Panics
+// Panics if the shapes can't be broadcast together.
+fn broadcast_shape(a: [usize; 2], b: [usize; 2]) -> [usize; 2] {
+    match try_broadcast_shape(a, b) {
+        Ok(shape) => shape,
+        Err(_) => panic!("cannot broadcast matrix shapes {:?} and {:?}", a, b),
+    }
+}
+
 // Unary operations
 impl<T> Matrix<T>
 where
@@ -69,6 +113,840 @@ where
     {
         self.vec.power_mut(exp);
     }
+
+    /// Returns the matrix product of `self` and `other`, i.e. the standard
+    /// linear-algebra `[m, k] × [k, n] -> [m, n]` product, not to be
+    /// confused with the element-wise `*` operator.
+    ///
+    /// # Panics
+    /// Panics if the number of columns of `self` doesn't match the number
+    /// of rows of `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 6; 7, 8];
+    /// assert_eq!(a.dot(&b), matrix![19, 22; 43, 50]);
+    /// ```
+    pub fn dot(&self, other: &Matrix<T>) -> Matrix<T>
+    where
+        T: FromPrimitive + 'static,
+    {
+        match self.try_dot(other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Matrix dot product with invalid shape: {:?} and {:?}, \
+                 the number of columns of the left matrix ({}) should \
+                 match the number of rows of the right matrix ({})",
+                self.shape(),
+                other.shape(),
+                self.shape()[1],
+                other.shape()[0]
+            ),
+        }
+    }
+
+    /// Returns the matrix multiplication (dot product) of two matrices, or
+    /// a [`CrabsformerError::ShapeMismatch`] if the number of columns of
+    /// `self` doesn't match the number of rows of `other`.
+    ///
+    /// This is the non-panicking counterpart of [`dot()`].
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    /// [`dot()`]: #method.dot
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 6; 7, 8];
+    /// assert_eq!(a.try_dot(&b).unwrap(), matrix![19, 22; 43, 50]);
+    ///
+    /// let c = matrix![1, 2; 3, 4; 5, 6];
+    /// assert!(a.try_dot(&c).is_err());
+    /// ```
+    pub fn try_dot(&self, other: &Matrix<T>) -> Result<Matrix<T>, CrabsformerError>
+    where
+        T: FromPrimitive + 'static,
+    {
+        let [m, k] = self.shape();
+        let [k2, n] = other.shape();
+        if k != k2 {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![m, k],
+                rhs: vec![k2, n],
+            });
+        }
+
+        #[cfg(feature = "blas")]
+        {
+            if let Some(result) = crate::matrix::blas::try_dot_fast(self, other) {
+                return Ok(result);
+            }
+        }
+
+        // Cache-blocked triple loop: tiling `i`/`j`/`k` into `BLOCK_SIZE`
+        // chunks keeps the working set of each inner pass resident in
+        // cache, which matters once `m`, `n`, `k` grow past a few hundred.
+        let zero = T::from_f32(0.0).unwrap();
+        let mut data = vec![zero; m * n];
+        for ii in (0..m).step_by(BLOCK_SIZE) {
+            let i_end = (ii + BLOCK_SIZE).min(m);
+            for jj in (0..n).step_by(BLOCK_SIZE) {
+                let j_end = (jj + BLOCK_SIZE).min(n);
+                for kk in (0..k).step_by(BLOCK_SIZE) {
+                    let k_end = (kk + BLOCK_SIZE).min(k);
+                    for i in ii..i_end {
+                        for j in jj..j_end {
+                            let mut sum = data[i * n + j];
+                            for p in kk..k_end {
+                                sum = sum + *self.at(i, p) * *other.at(p, j);
+                            }
+                            data[i * n + j] = sum;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(Matrix {
+            nrows: m,
+            ncols: n,
+            vec: Vector::from(data),
+        })
+    }
+
+    /// Alias for [`dot()`], the standard linear-algebra matrix product.
+    ///
+    /// [`dot()`]: #method.dot
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 6; 7, 8];
+    /// assert_eq!(a.matmul(&b), a.dot(&b));
+    /// ```
+    pub fn matmul(&self, other: &Matrix<T>) -> Matrix<T>
+    where
+        T: FromPrimitive + 'static,
+    {
+        self.dot(other)
+    }
+
+    /// Returns the `n`-th matrix power of `self`, i.e. `self` multiplied
+    /// by itself `n` times using the linear-algebra [`dot()`] product, not
+    /// to be confused with the element-wise [`power()`].
+    ///
+    /// Computed by binary exponentiation, so this is `O(log n)`
+    /// [`dot()`] calls rather than `n`. `pow(0)` returns the identity
+    /// matrix.
+    ///
+    /// # Panics
+    /// Panics if `self` isn't square.
+    ///
+    /// [`dot()`]: #method.dot
+    /// [`power()`]: #method.power
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 1; 0, 1];
+    /// assert_eq!(a.pow(3), matrix![1, 3; 0, 1]);
+    /// assert_eq!(a.pow(0), Matrix::eye(2));
+    /// ```
+    pub fn pow(&self, n: usize) -> Matrix<T>
+    where
+        T: FromPrimitive + 'static,
+    {
+        match self.try_pow(n) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Matrix power requires a square matrix, got shape {:?}",
+                self.shape()
+            ),
+        }
+    }
+
+    /// Returns the `n`-th matrix power of `self`, or a
+    /// [`CrabsformerError::ShapeMismatch`] if `self` isn't square.
+    ///
+    /// This is the non-panicking counterpart of [`pow()`].
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    /// [`pow()`]: #method.pow
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 1; 0, 1];
+    /// assert_eq!(a.try_pow(3).unwrap(), matrix![1, 3; 0, 1]);
+    ///
+    /// let b = matrix![1, 2, 3; 4, 5, 6];
+    /// assert!(b.try_pow(2).is_err());
+    /// ```
+    pub fn try_pow(&self, n: usize) -> Result<Matrix<T>, CrabsformerError>
+    where
+        T: FromPrimitive + 'static,
+    {
+        let [nrows, ncols] = self.shape();
+        if nrows != ncols {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![nrows, ncols],
+                rhs: vec![ncols, nrows],
+            });
+        }
+
+        let mut result = Matrix::eye(nrows);
+        let mut base = self.clone();
+        let mut n = n;
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result.dot(&base);
+            }
+            base = base.dot(&base);
+            n >>= 1;
+        }
+        Ok(result)
+    }
+
+    /// Alias for [`pow()`], the `n`-th matrix power of `self`.
+    ///
+    /// [`pow()`]: #method.pow
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 1; 0, 1];
+    /// assert_eq!(a.powi(3), a.pow(3));
+    /// ```
+    pub fn powi(&self, exp: usize) -> Matrix<T>
+    where
+        T: FromPrimitive + 'static,
+    {
+        self.pow(exp)
+    }
+
+    /// Applies `f` to every element of the matrix in place, avoiding the
+    /// allocation of a new matrix. Walks the flat backing store, so the
+    /// order `f` observes elements in is row-major.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![1, 2; 3, 4];
+    /// w.apply(|x| *x *= 10);
+    /// assert_eq!(w, matrix![10, 20; 30, 40]);
+    /// ```
+    pub fn apply<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for x in self.vec.as_mut_slice().iter_mut() {
+            f(x);
+        }
+    }
+
+    /// Folds `other`'s elements into `self` in place via `f(self_elem,
+    /// other_elem)`, avoiding the allocation of a new matrix.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` don't have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut a = matrix![1, 2; 3, 4];
+    /// let b = matrix![10, 20; 30, 40];
+    /// a.zip_apply(&b, |x, y| *x += y);
+    /// assert_eq!(a, matrix![11, 22; 33, 44]);
+    /// ```
+    pub fn zip_apply<F>(&mut self, other: &Matrix<T>, mut f: F)
+    where
+        F: FnMut(&mut T, T),
+    {
+        if self.shape() != other.shape() {
+            panic!(
+                "cannot zip_apply matrix shape {:?} with {:?}",
+                other.shape(),
+                self.shape()
+            );
+        }
+        for (x, y) in self
+            .vec
+            .as_mut_slice()
+            .iter_mut()
+            .zip(other.vec.as_slice().iter())
+        {
+            f(x, *y);
+        }
+    }
+
+    /// Maps every element of the matrix into a new matrix with `f`, whose
+    /// element type `U` can differ from `self`'s. Unlike [`apply`], this
+    /// allocates a new matrix rather than mutating `self` in place.
+    ///
+    /// [`apply`]: #method.apply
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1, 2; 3, 4];
+    /// let y = w.map(|x| x as f64 * 0.5);
+    /// assert_eq!(y, matrix![0.5, 1.0; 1.5, 2.0]);
+    /// ```
+    pub fn map<U, F>(&self, mut f: F) -> Matrix<U>
+    where
+        U: Num + Copy,
+        F: FnMut(T) -> U,
+    {
+        let [nrows, ncols] = self.shape();
+        let data: Vec<U> = self.vec.elements().map(|x| f(*x)).collect();
+        Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(data),
+        }
+    }
+
+    /// Pairs up the elements of `self` and `other` and maps each pair into
+    /// a new matrix with `f`, whose element type `U` can differ from both
+    /// of theirs. Unlike [`zip_apply`], this allocates a new matrix rather
+    /// than mutating `self` in place.
+    ///
+    /// [`zip_apply`]: #method.zip_apply
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` don't have the same shape.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![10, 20; 30, 40];
+    /// let c = a.zip_map(&b, |x, y| x + y);
+    /// assert_eq!(c, matrix![11, 22; 33, 44]);
+    /// ```
+    pub fn zip_map<U, F>(&self, other: &Matrix<T>, mut f: F) -> Matrix<U>
+    where
+        U: Num + Copy,
+        F: FnMut(T, T) -> U,
+    {
+        if self.shape() != other.shape() {
+            panic!(
+                "cannot zip_map matrix shape {:?} with {:?}",
+                other.shape(),
+                self.shape()
+            );
+        }
+        let [nrows, ncols] = self.shape();
+        let data: Vec<U> = self
+            .vec
+            .elements()
+            .zip(other.vec.elements())
+            .map(|(x, y)| f(*x, *y))
+            .collect();
+        Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(data),
+        }
+    }
+
+    /// Maps every cell of the matrix into a new matrix via `f(i, j,
+    /// value)`, giving the closure each cell's row/column alongside its
+    /// value. This is what lets position-dependent closures (e.g. an
+    /// identity mask) be expressed declaratively instead of built up with
+    /// a loop over [`indexed_elements`].
+    ///
+    /// [`indexed_elements`]: struct.Matrix.html#method.indexed_elements
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1, 2; 3, 4];
+    /// let identity_mask = w.map_indexed(|i, j, _| if i == j { 1 } else { 0 });
+    /// assert_eq!(identity_mask, matrix![1, 0; 0, 1]);
+    /// ```
+    pub fn map_indexed<F>(&self, f: F) -> Matrix<T>
+    where
+        F: Fn(usize, usize, &T) -> T,
+    {
+        let [nrows, ncols] = self.shape();
+        let data: Vec<T> = self
+            .indexed_elements()
+            .map(|(i, j, x)| f(i, j, x))
+            .collect();
+        Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(data),
+        }
+    }
+
+    /// Returns the transpose of `self`, a new `[ncols, nrows]` matrix
+    /// where `out[j][i] = self[i][j]`. If `self` is square and you want
+    /// to avoid the allocation, use [`transpose_mut`] instead.
+    ///
+    /// [`transpose_mut`]: #method.transpose_mut
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(w.transpose(), matrix![1, 4; 2, 5; 3, 6]);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let [nrows, ncols] = self.shape();
+        let mut data = Vec::with_capacity(nrows * ncols);
+        for j in 0..ncols {
+            for i in 0..nrows {
+                data.push(*self.at(i, j));
+            }
+        }
+        Matrix {
+            nrows: ncols,
+            ncols: nrows,
+            vec: Vector::from(data),
+        }
+    }
+
+    /// Transposes a square matrix in place by swapping elements across
+    /// the main diagonal. If `self` isn't square, use [`transpose`]
+    /// instead, which allocates a new matrix of the swapped shape.
+    ///
+    /// # Panics
+    /// Panics if `self` isn't square.
+    ///
+    /// [`transpose`]: #method.transpose
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut w = matrix![1, 2; 3, 4];
+    /// w.transpose_mut();
+    /// assert_eq!(w, matrix![1, 3; 2, 4]);
+    /// ```
+    pub fn transpose_mut(&mut self) {
+        let [nrows, ncols] = self.shape();
+        if nrows != ncols {
+            panic!(
+                "Matrix::transpose_mut requires a square matrix, got shape {:?}",
+                self.shape()
+            );
+        }
+        for i in 0..nrows {
+            for j in (i + 1)..ncols {
+                let upper = *self.at(i, j);
+                let lower = *self.at(j, i);
+                *self.at_mut(i, j) = lower;
+                *self.at_mut(j, i) = upper;
+            }
+        }
+    }
+
+    /// Returns the element-wise (optionally broadcast) sum of two matrices,
+    /// or a [`CrabsformerError::ShapeMismatch`] if their shapes can't be
+    /// broadcast together.
+    ///
+    /// This is the non-panicking counterpart of the `+` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 6; 7, 8];
+    /// assert_eq!(a.try_add(&b).unwrap(), matrix![6, 8; 10, 12]);
+    ///
+    /// let c = matrix![1, 2, 3; 4, 5, 6];
+    /// assert!(a.try_add(&c).is_err());
+    /// ```
+    pub fn try_add(&self, other: &Matrix<T>) -> Result<Matrix<T>, CrabsformerError> {
+        if self.shape() == other.shape() {
+            let vec = self.vec.clone() + other.vec.clone();
+            return Ok(Matrix {
+                nrows: self.nrows,
+                ncols: self.ncols,
+                vec,
+            });
+        }
+
+        let shape = try_broadcast_shape(self.shape(), other.shape())?;
+        let mut data = Vec::with_capacity(shape[0] * shape[1]);
+        for i in 0..shape[0] {
+            for j in 0..shape[1] {
+                let x = *self.at(i % self.nrows, j % self.ncols);
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                data.push(x + y);
+            }
+        }
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec: Vector::from(data),
+        })
+    }
+
+    /// Returns the element-wise (optionally broadcast) difference of two
+    /// matrices, or a [`CrabsformerError::ShapeMismatch`] if their shapes
+    /// can't be broadcast together.
+    ///
+    /// This is the non-panicking counterpart of the `-` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![5, 6; 7, 8];
+    /// let b = matrix![1, 2; 3, 4];
+    /// assert_eq!(a.try_sub(&b).unwrap(), matrix![4, 4; 4, 4]);
+    ///
+    /// let c = matrix![1, 2, 3; 4, 5, 6];
+    /// assert!(a.try_sub(&c).is_err());
+    /// ```
+    pub fn try_sub(&self, other: &Matrix<T>) -> Result<Matrix<T>, CrabsformerError> {
+        if self.shape() == other.shape() {
+            let vec = self.vec.clone() - other.vec.clone();
+            return Ok(Matrix {
+                nrows: self.nrows,
+                ncols: self.ncols,
+                vec,
+            });
+        }
+
+        let shape = try_broadcast_shape(self.shape(), other.shape())?;
+        let mut data = Vec::with_capacity(shape[0] * shape[1]);
+        for i in 0..shape[0] {
+            for j in 0..shape[1] {
+                let x = *self.at(i % self.nrows, j % self.ncols);
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                data.push(x - y);
+            }
+        }
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec: Vector::from(data),
+        })
+    }
+
+    /// Returns the element-wise (optionally broadcast) product of two
+    /// matrices, or a [`CrabsformerError::ShapeMismatch`] if their shapes
+    /// can't be broadcast together.
+    ///
+    /// This is the non-panicking counterpart of the `*` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 6; 7, 8];
+    /// assert_eq!(a.try_mul(&b).unwrap(), matrix![5, 12; 21, 32]);
+    ///
+    /// let c = matrix![1, 2, 3; 4, 5, 6];
+    /// assert!(a.try_mul(&c).is_err());
+    /// ```
+    pub fn try_mul(&self, other: &Matrix<T>) -> Result<Matrix<T>, CrabsformerError> {
+        if self.shape() == other.shape() {
+            let vec = self.vec.clone() * other.vec.clone();
+            return Ok(Matrix {
+                nrows: self.nrows,
+                ncols: self.ncols,
+                vec,
+            });
+        }
+
+        let shape = try_broadcast_shape(self.shape(), other.shape())?;
+        let mut data = Vec::with_capacity(shape[0] * shape[1]);
+        for i in 0..shape[0] {
+            for j in 0..shape[1] {
+                let x = *self.at(i % self.nrows, j % self.ncols);
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                data.push(x * y);
+            }
+        }
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec: Vector::from(data),
+        })
+    }
+
+    /// Returns the element-wise (optionally broadcast) quotient of two
+    /// matrices, or a [`CrabsformerError::ShapeMismatch`] if their shapes
+    /// can't be broadcast together.
+    ///
+    /// This is the non-panicking counterpart of the `/` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![5, 12; 21, 32];
+    /// let b = matrix![5, 6; 7, 8];
+    /// assert_eq!(a.try_div(&b).unwrap(), matrix![1, 2; 3, 4]);
+    ///
+    /// let c = matrix![1, 2, 3; 4, 5, 6];
+    /// assert!(a.try_div(&c).is_err());
+    /// ```
+    pub fn try_div(&self, other: &Matrix<T>) -> Result<Matrix<T>, CrabsformerError> {
+        if self.shape() == other.shape() {
+            let vec = self.vec.clone() / other.vec.clone();
+            return Ok(Matrix {
+                nrows: self.nrows,
+                ncols: self.ncols,
+                vec,
+            });
+        }
+
+        let shape = try_broadcast_shape(self.shape(), other.shape())?;
+        let mut data = Vec::with_capacity(shape[0] * shape[1]);
+        for i in 0..shape[0] {
+            for j in 0..shape[1] {
+                let x = *self.at(i % self.nrows, j % self.ncols);
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                data.push(x / y);
+            }
+        }
+        Ok(Matrix {
+            nrows: shape[0],
+            ncols: shape[1],
+            vec: Vector::from(data),
+        })
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Returns the lower-triangular Cholesky factor `L` such that `self
+    /// == L.dot(&L.transpose())`, or `None` if `self` isn't symmetric
+    /// positive-definite.
+    ///
+    /// # Panics
+    /// Panics if `self` isn't square.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![4.0, 2.0; 2.0, 5.0];
+    /// let l = a.cholesky().unwrap();
+    /// assert_eq!(l, matrix![2.0, 0.0; 1.0, 2.0]);
+    ///
+    /// // Not positive-definite: no real Cholesky factor exists.
+    /// let b = matrix![1.0, 2.0; 2.0, 1.0];
+    /// assert!(b.cholesky().is_none());
+    /// ```
+    pub fn cholesky(&self) -> Option<Matrix<T>> {
+        let [n, ncols] = self.shape();
+        if n != ncols {
+            panic!(
+                "Matrix::cholesky requires a square matrix, got shape {:?}",
+                self.shape()
+            );
+        }
+
+        // The algorithm below only ever reads the lower triangle of
+        // `self`, so a non-symmetric matrix would silently produce a
+        // bogus `L` instead of `None`. Reject it up front.
+        for i in 0..n {
+            for j in (i + 1)..n {
+                if *self.at(i, j) != *self.at(j, i) {
+                    return None;
+                }
+            }
+        }
+
+        let mut l = vec![T::zero(); n * n];
+        for j in 0..n {
+            let mut sum = T::zero();
+            for k in 0..j {
+                sum = sum + l[j * n + k] * l[j * n + k];
+            }
+            let radicand = *self.at(j, j) - sum;
+            if radicand <= T::zero() {
+                return None;
+            }
+            l[j * n + j] = radicand.sqrt();
+
+            for i in (j + 1)..n {
+                let mut sum = T::zero();
+                for k in 0..j {
+                    sum = sum + l[i * n + k] * l[j * n + k];
+                }
+                l[i * n + j] = (*self.at(i, j) - sum) / l[j * n + j];
+            }
+        }
+
+        Some(Matrix {
+            nrows: n,
+            ncols: n,
+            vec: Vector::from(l),
+        })
+    }
+
+    /// Solves the linear system `self * x == b` for `x`, using Gaussian
+    /// elimination with partial pivoting followed by back-substitution.
+    ///
+    /// # Panics
+    /// Panics if `self` isn't square, if its shape doesn't match `b`'s
+    /// length, or if `self` is singular (a pivot column is all zeros).
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![2.0, 1.0; 1.0, 3.0];
+    /// let b = vector![5.0, 10.0];
+    /// let x = a.solve(&b);
+    /// assert_eq!(x, vector![1.0, 3.0]);
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        let [n, ncols] = self.shape();
+        if n != ncols {
+            panic!(
+                "Matrix::solve requires a square matrix, got shape {:?}",
+                self.shape()
+            );
+        }
+        if b.len() != n {
+            panic!(
+                "Matrix::solve shape mismatch: matrix is {:?} but b has length {}",
+                self.shape(),
+                b.len()
+            );
+        }
+
+        // Working copies of `self` and `b`; eliminated and pivoted in place.
+        let mut a: Vec<T> =
+            (0..n * n).map(|idx| *self.at(idx / n, idx % n)).collect();
+        let mut x: Vec<T> = b.elements().copied().collect();
+
+        for col in 0..n {
+            let mut pivot_row = col;
+            let mut pivot_value = a[col * n + col].abs();
+            for row in (col + 1)..n {
+                let value = a[row * n + col].abs();
+                if value > pivot_value {
+                    pivot_value = value;
+                    pivot_row = row;
+                }
+            }
+            if pivot_value == T::zero() {
+                panic!("Matrix::solve: matrix is singular");
+            }
+            if pivot_row != col {
+                for k in 0..n {
+                    a.swap(col * n + k, pivot_row * n + k);
+                }
+                x.swap(col, pivot_row);
+            }
+            for row in (col + 1)..n {
+                let factor = a[row * n + col] / a[col * n + col];
+                for k in col..n {
+                    a[row * n + k] = a[row * n + k] - factor * a[col * n + k];
+                }
+                x[row] = x[row] - factor * x[col];
+            }
+        }
+
+        // Back-substitution over the now upper-triangular `a`.
+        let mut result = vec![T::zero(); n];
+        for row in (0..n).rev() {
+            let mut sum = x[row];
+            for col in (row + 1)..n {
+                sum = sum - a[row * n + col] * result[col];
+            }
+            result[row] = sum / a[row * n + row];
+        }
+
+        Vector::from(result)
+    }
+
+    /// Returns the determinant of `self`.
+    ///
+    /// If `self` is symmetric positive-definite, this runs [`cholesky`]
+    /// and returns the square of the product of `L`'s diagonal (cheaper
+    /// and more numerically stable than general LU). Otherwise it falls
+    /// back to LU decomposition with partial pivoting.
+    ///
+    /// # Panics
+    /// Panics if `self` isn't square.
+    ///
+    /// [`cholesky`]: #method.cholesky
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![4.0, 2.0; 2.0, 5.0];
+    /// assert_eq!(a.determinant(), 16.0);
+    ///
+    /// let b = matrix![1.0, 2.0; 3.0, 4.0];
+    /// assert_eq!(b.determinant(), -2.0);
+    /// ```
+    pub fn determinant(&self) -> T {
+        if let Some(l) = self.cholesky() {
+            let n = l.shape()[0];
+            let mut diagonal_product = T::one();
+            for i in 0..n {
+                diagonal_product = diagonal_product * *l.at(i, i);
+            }
+            return diagonal_product * diagonal_product;
+        }
+
+        let [n, ncols] = self.shape();
+        if n != ncols {
+            panic!(
+                "Matrix::determinant requires a square matrix, got shape {:?}",
+                self.shape()
+            );
+        }
+
+        // General LU decomposition with partial pivoting: `self` either
+        // isn't symmetric or isn't positive-definite, so `cholesky`
+        // above returned `None`.
+        let mut a: Vec<T> =
+            (0..n * n).map(|idx| *self.at(idx / n, idx % n)).collect();
+        let mut sign = T::one();
+        for col in 0..n {
+            let mut pivot_row = col;
+            let mut pivot_value = a[col * n + col].abs();
+            for row in (col + 1)..n {
+                let value = a[row * n + col].abs();
+                if value > pivot_value {
+                    pivot_value = value;
+                    pivot_row = row;
+                }
+            }
+            if pivot_value == T::zero() {
+                return T::zero();
+            }
+            if pivot_row != col {
+                for k in 0..n {
+                    a.swap(col * n + k, pivot_row * n + k);
+                }
+                sign = -sign;
+            }
+            for row in (col + 1)..n {
+                let factor = a[row * n + col] / a[col * n + col];
+                for k in col..n {
+                    a[row * n + k] = a[row * n + k] - factor * a[col * n + k];
+                }
+            }
+        }
+
+        let mut product = sign;
+        for i in 0..n {
+            product = product * a[i * n + i];
+        }
+        product
+    }
 }
 
 // This trait is implemented to support for matrix addition operator
@@ -79,20 +957,13 @@ where
     type Output = Matrix<T>;
 
     fn add(self, other: Matrix<T>) -> Matrix<T> {
-        if self.shape() != other.shape() {
-            panic!(
-                "Matrix addition with invalid shape: {:?} != {:?}",
+        match self.try_add(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "cannot broadcast matrix shapes {:?} and {:?}",
                 self.shape(),
                 other.shape()
-            );
-        }
-
-        // Add the element of the matrix
-        let vec = self.vec + other.vec;
-        Matrix {
-            nrows: self.nrows,
-            ncols: self.ncols,
-            vec,
+            ),
         }
     }
 }
@@ -163,14 +1034,27 @@ where
     T: Num + Copy + ops::AddAssign,
 {
     fn add_assign(&mut self, other: Matrix<T>) {
-        if self.shape() != other.shape() {
+        if self.shape() == other.shape() {
+            self.vec += other.vec;
+            return;
+        }
+
+        // Broadcasting in place can't grow `self`, so the broadcast shape
+        // must be exactly `self`'s own shape.
+        let shape = broadcast_shape(self.shape(), other.shape());
+        if shape != self.shape() {
             panic!(
-                "Matrix addition with invalid length: {:?} != {:?}",
-                self.shape(),
-                other.shape()
+                "cannot broadcast matrix shape {:?} into {:?} in place",
+                other.shape(),
+                self.shape()
             );
         }
-        self.vec += other.vec;
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                self.vec[(self.ncols * i) + j] += y;
+            }
+        }
     }
 }
 
@@ -199,20 +1083,13 @@ where
     type Output = Matrix<T>;
 
     fn sub(self, other: Matrix<T>) -> Matrix<T> {
-        if self.shape() != other.shape() {
-            panic!(
-                "Matrix substraction with invalid shape: {:?} != {:?}",
+        match self.try_sub(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "cannot broadcast matrix shapes {:?} and {:?}",
                 self.shape(),
                 other.shape()
-            );
-        }
-
-        // Substract the matrix
-        let vec = self.vec - other.vec;
-        Matrix {
-            nrows: self.nrows,
-            ncols: self.ncols,
-            vec,
+            ),
         }
     }
 }
@@ -285,14 +1162,27 @@ where
     T: Num + Copy + ops::SubAssign,
 {
     fn sub_assign(&mut self, other: Matrix<T>) {
-        if self.shape() != other.shape() {
+        if self.shape() == other.shape() {
+            self.vec -= other.vec;
+            return;
+        }
+
+        // Broadcasting in place can't grow `self`, so the broadcast shape
+        // must be exactly `self`'s own shape.
+        let shape = broadcast_shape(self.shape(), other.shape());
+        if shape != self.shape() {
             panic!(
-                "Matrix substraction with invalid length: {:?} != {:?}",
-                self.shape(),
-                other.shape()
+                "cannot broadcast matrix shape {:?} into {:?} in place",
+                other.shape(),
+                self.shape()
             );
         }
-        self.vec -= other.vec;
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                self.vec[(self.ncols * i) + j] -= y;
+            }
+        }
     }
 }
 
@@ -321,18 +1211,13 @@ where
     type Output = Matrix<T>;
 
     fn mul(self, other: Matrix<T>) -> Matrix<T> {
-        if self.shape() != other.shape() {
-            panic!(
-                "Matrix multiplication with invalid shape: {:?} != {:?}",
+        match self.try_mul(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "cannot broadcast matrix shapes {:?} and {:?}",
                 self.shape(),
                 other.shape()
-            );
-        }
-        let vec = self.vec * other.vec;
-        Matrix {
-            nrows: self.nrows,
-            ncols: self.ncols,
-            vec,
+            ),
         }
     }
 }
@@ -403,15 +1288,27 @@ where
     T: Num + Copy + ops::MulAssign,
 {
     fn mul_assign(&mut self, other: Matrix<T>) {
-        if self.shape() != other.shape() {
+        if self.shape() == other.shape() {
+            self.vec *= other.vec;
+            return;
+        }
+
+        // Broadcasting in place can't grow `self`, so the broadcast shape
+        // must be exactly `self`'s own shape.
+        let shape = broadcast_shape(self.shape(), other.shape());
+        if shape != self.shape() {
             panic!(
-                "Matrix multiplication with invalid length: {:?} != {:?}",
-                self.shape(),
-                other.shape()
+                "cannot broadcast matrix shape {:?} into {:?} in place",
+                other.shape(),
+                self.shape()
             );
         }
-
-        self.vec *= other.vec;
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                self.vec[(self.ncols * i) + j] *= y;
+            }
+        }
     }
 }
 
@@ -430,3 +1327,180 @@ where
         self.vec *= value;
     }
 }
+
+// This trait is implemented to support for matrix division operator
+impl<T> ops::Div<Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn div(self, other: Matrix<T>) -> Matrix<T> {
+        match self.try_div(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "cannot broadcast matrix shapes {:?} and {:?}",
+                self.shape(),
+                other.shape()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for matrix division
+// operator with scalar on the right side,
+// for example:
+//
+// let a = matrix![5, 5; 5, 5] / 6;
+//
+impl<T> ops::Div<T> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn div(self, value: T) -> Matrix<T> {
+        let vec = self.vec / value;
+        Matrix {
+            nrows: self.nrows,
+            ncols: self.ncols,
+            vec,
+        }
+    }
+}
+
+// This macro is to generate support for matrix division
+// operator with scalar on the left side,
+// for example:
+//
+// let a = 6 / matrix![5, 5; 5, 5];
+//
+macro_rules! impl_div_matrix_for_type {
+    ($t: ty) => {
+        impl ops::Div<Matrix<$t>> for $t {
+            type Output = Matrix<$t>;
+
+            fn div(self, m: Matrix<$t>) -> Matrix<$t> {
+                let vec = self / m.vec;
+                Matrix {
+                    nrows: m.nrows,
+                    ncols: m.ncols,
+                    vec,
+                }
+            }
+        }
+    };
+}
+
+impl_div_matrix_for_type!(usize);
+impl_div_matrix_for_type!(i8);
+impl_div_matrix_for_type!(i16);
+impl_div_matrix_for_type!(i32);
+impl_div_matrix_for_type!(i64);
+impl_div_matrix_for_type!(i128);
+impl_div_matrix_for_type!(u8);
+impl_div_matrix_for_type!(u16);
+impl_div_matrix_for_type!(u32);
+impl_div_matrix_for_type!(u64);
+impl_div_matrix_for_type!(u128);
+impl_div_matrix_for_type!(f32);
+impl_div_matrix_for_type!(f64);
+
+// This trait is implemented to support for matrix division
+// and assignment operator (/=)
+impl<T> ops::DivAssign<Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy + ops::DivAssign,
+{
+    fn div_assign(&mut self, other: Matrix<T>) {
+        if self.shape() == other.shape() {
+            self.vec /= other.vec;
+            return;
+        }
+
+        // Broadcasting in place can't grow `self`, so the broadcast shape
+        // must be exactly `self`'s own shape.
+        let shape = broadcast_shape(self.shape(), other.shape());
+        if shape != self.shape() {
+            panic!(
+                "cannot broadcast matrix shape {:?} into {:?} in place",
+                other.shape(),
+                self.shape()
+            );
+        }
+        for i in 0..self.nrows {
+            for j in 0..self.ncols {
+                let y = *other.at(i % other.nrows, j % other.ncols);
+                self.vec[(self.ncols * i) + j] /= y;
+            }
+        }
+    }
+}
+
+// This trait is implemented to support for matrix division
+// assignment operator (/=) with scalar on the right side,
+// for example:
+//
+// let a = matrix![5, 5; 5, 5];
+// a /= 6;
+//
+impl<T> ops::DivAssign<T> for Matrix<T>
+where
+    T: Num + Copy + ops::DivAssign,
+{
+    fn div_assign(&mut self, value: T) {
+        self.vec /= value;
+    }
+}
+
+impl<'a, T> RowMatrix<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Returns an owned `[size, 1]` column matrix with the same elements
+    /// as this borrowed row, e.g. for building outer products or for
+    /// feeding a row into [`Matrix::dot`] as a column operand.
+    ///
+    /// [`Matrix::dot`]: struct.Matrix.html#method.dot
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(w.row(0).transpose(), matrix![1; 2; 3]);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let data: Vec<T> = self.elements().collect();
+        Matrix {
+            nrows: data.len(),
+            ncols: 1,
+            vec: Vector::from(data),
+        }
+    }
+}
+
+impl<'a, T> ColumnMatrix<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Returns an owned `[1, size]` row matrix with the same elements as
+    /// this borrowed column, e.g. for building outer products or for
+    /// feeding a column into [`Matrix::dot`] as a row operand.
+    ///
+    /// [`Matrix::dot`]: struct.Matrix.html#method.dot
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![1, 2, 3; 4, 5, 6];
+    /// assert_eq!(w.col(0).transpose(), matrix![1, 4]);
+    /// ```
+    pub fn transpose(&self) -> Matrix<T> {
+        let data: Vec<T> = self.elements().collect();
+        Matrix {
+            nrows: 1,
+            ncols: data.len(),
+            vec: Vector::from(data),
+        }
+    }
+}
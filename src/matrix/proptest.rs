@@ -0,0 +1,69 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`proptest`] strategies for generating arbitrary [`Matrix`]es, gated
+//! behind the `proptest` feature.
+//!
+//! [`proptest`]: https://docs.rs/proptest
+//! [`Matrix`]: ../struct.Matrix.html
+
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num::Num;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// Build a [`Strategy`] that generates [`Matrix`] values whose row and
+/// column counts are drawn independently from `rows_range` and
+/// `cols_range`, and whose elements are drawn from `element_strategy`.
+/// The generated data is always rectangular, since a matrix is filled
+/// row-major from a single flat buffer of exactly `nrows * ncols`
+/// elements rather than from independently generated rows.
+///
+/// [`Matrix`]: ../struct.Matrix.html
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     #[test]
+///     fn test_matrix_strategy_is_rectangular(
+///         m in matrix_strategy(1..5, 1..5, any::<i32>())
+///     ) {
+///         let shape = m.shape();
+///         prop_assert_eq!(m.len(), shape[0] * shape[1]);
+///     }
+/// }
+/// ```
+pub fn matrix_strategy<T>(
+    rows_range: Range<usize>,
+    cols_range: Range<usize>,
+    element_strategy: impl Strategy<Value = T> + Clone,
+) -> impl Strategy<Value = Matrix<T>>
+where
+    T: Num + Copy + Debug,
+{
+    (rows_range, cols_range).prop_flat_map(move |(rows, cols)| {
+        vec(element_strategy.clone(), rows * cols)
+            .prop_map(move |data| Matrix {
+                nrows: rows,
+                ncols: cols,
+                vec: Vector::from(data),
+            })
+    })
+}
@@ -14,7 +14,7 @@
 
 // TODO(pyk): Add docs here
 
-use crate::matrix::{Matrix, Submatrix};
+use crate::matrix::{Matrix, Submatrix, SubmatrixMut};
 use num::Num;
 use std::ops;
 
@@ -32,394 +32,333 @@ where
     fn slice(&'a self, row_index: RowIdx, col_index: ColIdx) -> Self::Output;
 }
 
-// Check the slice index first, make sure the slice index is `start < end`
-// Set the panic behaviour same as Vec<T>
-fn check_range(range: &ops::Range<usize>) {
-    if range.start >= range.end {
-        panic!(
-            "Matrix slice index starts at {} but ends at {}",
-            range.start, range.end
-        )
-    }
-}
-
-fn check_range_inclusive(range: &ops::RangeInclusive<usize>) {
-    if *range.start() > *range.end() {
-        panic!(
-            "Matrix slice index starts at {} but ends at {}",
-            range.start(),
-            range.end()
-        )
-    }
-}
-
-impl<'a, T: 'a> MatrixSlice<'a, ops::Range<usize>, ops::Range<usize>>
-    for Matrix<T>
+/// Mutable matrix slice operation
+pub trait MatrixSliceMut<'a, RowIdx, ColIdx>
 where
-    T: Num + Copy,
+    RowIdx: ?Sized,
+    ColIdx: ?Sized,
 {
-    type Output = Submatrix<'a, T>;
-
-    fn slice(
-        &'a self,
-        irange: ops::Range<usize>,
-        jrange: ops::Range<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&irange);
-        check_range(&jrange);
-
-        // Make sure irange.end-1 < self.nrows and jrange.end-1 < self.ncols
-        // NOTE: range.end is excelusive, so we substract it by 1
-        self.check_bound(Some(irange.end - 1), Some(jrange.end - 1));
-
-        // Get the new nrows and new ncols
-        let nrows = irange.end - irange.start;
-        let ncols = jrange.end - jrange.start;
+    /// The returned type after indexing.
+    type Output: ?Sized;
 
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = jrange.start;
+    /// Performs the slicing (`container.slice_mut(index1, index2)`)
+    /// operation. It returns a mutable view over the sliced elements that
+    /// writes back into the parent matrix.
+    fn slice_mut(
+        &'a mut self,
+        row_index: RowIdx,
+        col_index: ColIdx,
+    ) -> Self::Output;
+}
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
-            source: self,
-        }
+/// A one-dimensional slice index along a single matrix axis of length `dim`.
+///
+/// `usize` and every `Range*<usize>` type implement this once, which lets
+/// `MatrixSlice` be implemented generically instead of by hand for every
+/// pair of range types (as nalgebra does for its own matrix views).
+trait DimRange {
+    /// The inclusive lower bound selected out of an axis of length `dim`.
+    /// `0` for `RangeTo*`/`RangeFull`, since they start from the beginning
+    /// of the axis.
+    fn lower(&self, dim: usize) -> usize;
+
+    /// The number of elements selected out of an axis of length `dim`.
+    /// Open-ended ranges are resolved against `dim`, e.g. `RangeFrom` gives
+    /// `dim - start` and `RangeFull` gives `dim`.
+    fn length(&self, dim: usize) -> usize;
+
+    /// Whether this index fits within an axis of length `dim`.
+    fn contained_by(&self, dim: usize) -> bool {
+        let lower = self.lower(dim);
+        lower < dim && lower + self.length(dim) <= dim
     }
 }
 
-impl<'a, T: 'a> MatrixSlice<'a, ops::Range<usize>, ops::RangeFrom<usize>>
-    for Matrix<T>
-where
-    T: Num + Copy,
-{
-    type Output = Submatrix<'a, T>;
-
-    fn slice(
-        &'a self,
-        irange: ops::Range<usize>,
-        jrange: ops::RangeFrom<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&irange);
-
-        // Make sure irange.end-1 < self.nrows and jrange.start < self.ncols
-        // NOTE: range.end is exclusive, so we substract it by 1
-        self.check_bound(Some(irange.end - 1), Some(jrange.start));
+impl DimRange for usize {
+    fn lower(&self, _dim: usize) -> usize {
+        *self
+    }
 
-        // Get the new nrows and new ncols
-        let nrows = irange.end - irange.start;
-        let ncols = self.ncols - jrange.start;
+    fn length(&self, _dim: usize) -> usize {
+        1
+    }
+}
 
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = jrange.start;
+impl DimRange for ops::Range<usize> {
+    fn lower(&self, _dim: usize) -> usize {
+        self.start
+    }
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
-            source: self,
+    fn length(&self, _dim: usize) -> usize {
+        if self.start >= self.end {
+            panic!(
+                "Matrix slice index starts at {} but ends at {}",
+                self.start, self.end
+            )
         }
+        self.end - self.start
     }
 }
 
-impl<'a, T: 'a> MatrixSlice<'a, ops::Range<usize>, ops::RangeTo<usize>>
-    for Matrix<T>
-where
-    T: Num + Copy,
-{
-    type Output = Submatrix<'a, T>;
-
-    fn slice(
-        &'a self,
-        irange: ops::Range<usize>,
-        jrange: ops::RangeTo<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&irange);
-
-        // Make sure irange.end-1 < self.nrows and jrange.end-1 < self.ncols
-        // NOTE: range.end is exclusive, so we substract it by 1
-        self.check_bound(Some(irange.end - 1), Some(jrange.end - 1));
-
-        // Get the new nrows and new ncols
-        let nrows = irange.end - irange.start;
-        let ncols = jrange.end;
-
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = 0;
+impl DimRange for ops::RangeFrom<usize> {
+    fn lower(&self, _dim: usize) -> usize {
+        self.start
+    }
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
-            source: self,
-        }
+    fn length(&self, dim: usize) -> usize {
+        dim - self.start
     }
 }
 
-impl<'a, T: 'a> MatrixSlice<'a, ops::Range<usize>, ops::RangeFull>
-    for Matrix<T>
-where
-    T: Num + Copy,
-{
-    type Output = Submatrix<'a, T>;
+impl DimRange for ops::RangeTo<usize> {
+    fn lower(&self, _dim: usize) -> usize {
+        0
+    }
 
-    fn slice(
-        &'a self,
-        irange: ops::Range<usize>,
-        _jrange: ops::RangeFull,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&irange);
+    fn length(&self, _dim: usize) -> usize {
+        self.end
+    }
+}
 
-        // Make sure irange.end-1 < self.nrows
-        // NOTE: range.end is exclusive, so we substract it by 1
-        self.check_bound(Some(irange.end - 1), None);
+impl DimRange for ops::RangeFull {
+    fn lower(&self, _dim: usize) -> usize {
+        0
+    }
 
-        // Get the new nrows and new ncols
-        let nrows = irange.end - irange.start;
-        let ncols = self.ncols;
+    fn length(&self, dim: usize) -> usize {
+        dim
+    }
+}
 
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = 0;
+impl DimRange for ops::RangeInclusive<usize> {
+    fn lower(&self, _dim: usize) -> usize {
+        *self.start()
+    }
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
-            source: self,
+    fn length(&self, _dim: usize) -> usize {
+        if self.start() > self.end() {
+            panic!(
+                "Matrix slice index starts at {} but ends at {}",
+                self.start(),
+                self.end()
+            )
         }
+        *self.end() + 1 - *self.start()
     }
 }
 
-impl<'a, T: 'a> MatrixSlice<'a, ops::Range<usize>, ops::RangeInclusive<usize>>
-    for Matrix<T>
-where
-    T: Num + Copy,
-{
-    type Output = Submatrix<'a, T>;
-
-    fn slice(
-        &'a self,
-        irange: ops::Range<usize>,
-        jrange: ops::RangeInclusive<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&irange);
-        check_range_inclusive(&jrange);
-
-        // Make sure irange.end-1 < self.nrows and jrange.end < self.ncols
-        // NOTE: range.end is exclusive, so we substract it by 1
-        self.check_bound(Some(irange.end - 1), Some(*jrange.end()));
-
-        // Get the new nrows and new ncols
-        let nrows = irange.end - irange.start;
-        let ncols = (*jrange.end() + 1) - *jrange.start();
-
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = *jrange.start();
+impl DimRange for ops::RangeToInclusive<usize> {
+    fn lower(&self, _dim: usize) -> usize {
+        0
+    }
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
-            source: self,
-        }
+    fn length(&self, _dim: usize) -> usize {
+        self.end + 1
     }
 }
 
-impl<'a, T: 'a>
-    MatrixSlice<'a, ops::Range<usize>, ops::RangeToInclusive<usize>>
+// One blanket implementation replaces the 36 hand-written `MatrixSlice`
+// impls (one per pair of range types): any pair of `DimRange` indices
+// works, including `usize` (e.g. `m.slice(2, 0..3)` for a single-row band).
+impl<'a, T: 'a, RI: DimRange, CI: DimRange> MatrixSlice<'a, RI, CI>
     for Matrix<T>
 where
     T: Num + Copy,
 {
     type Output = Submatrix<'a, T>;
 
-    fn slice(
-        &'a self,
-        irange: ops::Range<usize>,
-        jrange: ops::RangeToInclusive<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&irange);
-
-        // Make sure irange.end-1 < self.nrows and jrange.end < self.ncols
-        // NOTE: range.end is exclusive, so we substract it by 1
-        self.check_bound(Some(irange.end - 1), Some(jrange.end));
-
-        // Get the new nrows and new ncols
-        let nrows = irange.end - irange.start;
-        let ncols = jrange.end + 1;
-
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = 0;
+    fn slice(&'a self, row_index: RI, col_index: CI) -> Submatrix<'a, T> {
+        if !row_index.contained_by(self.nrows) {
+            panic!(
+                "Row index out of range for matrix with number of rows {}",
+                self.nrows
+            )
+        }
+        if !col_index.contained_by(self.ncols) {
+            panic!(
+                "Column index out of range for matrix with number of columns {}",
+                self.ncols
+            )
+        }
 
-        // Return a sub matrix
         Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
+            nrows: row_index.length(self.nrows),
+            ncols: col_index.length(self.ncols),
+            row_offset: row_index.lower(self.nrows),
+            col_offset: col_index.lower(self.ncols),
+            row_stride: 1,
+            col_stride: 1,
             source: self,
         }
     }
 }
 
-impl<'a, T: 'a> MatrixSlice<'a, ops::RangeFrom<usize>, ops::Range<usize>>
+// Mutable counterpart of the blanket `MatrixSlice` impl above: any pair of
+// `DimRange` indices works, yielding a `SubmatrixMut` that writes back into
+// the parent matrix.
+impl<'a, T: 'a, RI: DimRange, CI: DimRange> MatrixSliceMut<'a, RI, CI>
     for Matrix<T>
 where
     T: Num + Copy,
 {
-    type Output = Submatrix<'a, T>;
-
-    fn slice(
-        &'a self,
-        irange: ops::RangeFrom<usize>,
-        jrange: ops::Range<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure the range is valid
-        check_range(&jrange);
-
-        // Make sure irange.start < self.nrows and jrange.end-1 < self.ncols
-        // NOTE: range.end is excelusive, so we substract it by 1
-        self.check_bound(Some(irange.start), Some(jrange.end - 1));
-
-        // Get the new nrows and new ncols
-        let nrows = self.nrows - irange.start;
-        let ncols = jrange.end - jrange.start;
-
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = jrange.start;
+    type Output = SubmatrixMut<'a, T>;
+
+    fn slice_mut(
+        &'a mut self,
+        row_index: RI,
+        col_index: CI,
+    ) -> SubmatrixMut<'a, T> {
+        if !row_index.contained_by(self.nrows) {
+            panic!(
+                "Row index out of range for matrix with number of rows {}",
+                self.nrows
+            )
+        }
+        if !col_index.contained_by(self.ncols) {
+            panic!(
+                "Column index out of range for matrix with number of columns {}",
+                self.ncols
+            )
+        }
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
+        SubmatrixMut {
+            nrows: row_index.length(self.nrows),
+            ncols: col_index.length(self.ncols),
+            row_offset: row_index.lower(self.nrows),
+            col_offset: col_index.lower(self.ncols),
             source: self,
         }
     }
 }
 
-impl<'a, T: 'a> MatrixSlice<'a, ops::RangeFrom<usize>, ops::RangeFrom<usize>>
-    for Matrix<T>
+impl<T> Matrix<T>
 where
     T: Num + Copy,
 {
-    type Output = Submatrix<'a, T>;
-
-    fn slice(
+    /// Slice the matrix with an explicit `(row_stride, col_stride)` step,
+    /// selecting every `row_stride`-th row and every `col_stride`-th column
+    /// out of the given ranges, e.g. `m.slice_step(0..6, 0..6, (2, 3))`
+    /// picks rows `0, 2, 4` and columns `0, 3`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     1, 2, 3, 4, 5, 6;
+    ///     7, 8, 9, 10, 11, 12;
+    ///     13, 14, 15, 16, 17, 18;
+    ///     19, 20, 21, 22, 23, 24;
+    ///     25, 26, 27, 28, 29, 30;
+    ///     31, 32, 33, 34, 35, 36;
+    /// ];
+    /// let sub = w.slice_step(0..6, 0..6, (2, 3));
+    /// assert_eq!(sub.shape(), [3, 2]);
+    /// assert_eq!(sub.at(0, 0), &1);
+    /// assert_eq!(sub.at(0, 1), &4);
+    /// assert_eq!(sub.at(1, 0), &13);
+    /// assert_eq!(sub.at(2, 1), &34);
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `row_stride == 0` or `col_stride == 0`, if either range is
+    /// out of bounds for the matrix, or if the last sampled row/column would
+    /// fall outside the matrix.
+    pub fn slice_step<'a, RI: DimRange, CI: DimRange>(
         &'a self,
-        irange: ops::RangeFrom<usize>,
-        jrange: ops::RangeFrom<usize>,
+        row_index: RI,
+        col_index: CI,
+        (row_stride, col_stride): (usize, usize),
     ) -> Submatrix<'a, T> {
-        // Make sure irange.start < self.nrows and jrange.start < self.ncols
-        self.check_bound(Some(irange.start), Some(jrange.start));
-
-        // Get the new nrows and new ncols
-        let nrows = self.nrows - irange.start;
-        let ncols = self.ncols - jrange.start;
+        if row_stride == 0 || col_stride == 0 {
+            panic!("Matrix slice step must be greater than zero")
+        }
+        if !row_index.contained_by(self.nrows) {
+            panic!(
+                "Row index out of range for matrix with number of rows {}",
+                self.nrows
+            )
+        }
+        if !col_index.contained_by(self.ncols) {
+            panic!(
+                "Column index out of range for matrix with number of columns {}",
+                self.ncols
+            )
+        }
 
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = jrange.start;
+        let row_offset = row_index.lower(self.nrows);
+        let col_offset = col_index.lower(self.ncols);
+        // ceil(len / stride): the number of rows/columns sampled out of the
+        // range when taking every `stride`-th one.
+        let nrows =
+            (row_index.length(self.nrows) + row_stride - 1) / row_stride;
+        let ncols =
+            (col_index.length(self.ncols) + col_stride - 1) / col_stride;
+
+        if nrows > 0 && row_offset + (nrows - 1) * row_stride >= self.nrows {
+            panic!(
+                "Row step {} out of range for matrix with number of rows {}",
+                row_stride, self.nrows
+            )
+        }
+        if ncols > 0 && col_offset + (ncols - 1) * col_stride >= self.ncols {
+            panic!(
+                "Column step {} out of range for matrix with number of columns {}",
+                col_stride, self.ncols
+            )
+        }
 
-        // Return a sub matrix
         Submatrix {
             nrows,
             ncols,
             row_offset,
             col_offset,
+            row_stride,
+            col_stride,
             source: self,
         }
     }
-}
-
-impl<'a, T: 'a> MatrixSlice<'a, ops::RangeFrom<usize>, ops::RangeTo<usize>>
-    for Matrix<T>
-where
-    T: Num + Copy,
-{
-    type Output = Submatrix<'a, T>;
 
-    fn slice(
+    /// Slice the matrix like [`slice`], but return `None` instead of
+    /// panicking if either range is out of bounds. Reuses the same
+    /// `contained_by` bounds predicate that backs the panicking `slice`, so
+    /// callers can validate user-supplied ranges (e.g. parsed from input)
+    /// without catching a panic.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let w = matrix![
+    ///     3, 1, 4;
+    ///     1, 5, 9;
+    /// ];
+    /// assert!(w.try_slice(0..2, 1..3).is_some());
+    /// assert!(w.try_slice(0..100, 1..3).is_none());
+    /// ```
+    ///
+    /// [`slice`]: trait.MatrixSlice.html#tymethod.slice
+    pub fn try_slice<'a, RI: DimRange, CI: DimRange>(
         &'a self,
-        irange: ops::RangeFrom<usize>,
-        jrange: ops::RangeTo<usize>,
-    ) -> Submatrix<'a, T> {
-        // Make sure irange.start < self.nrows and jrange.end-1 < self.ncols
-        // NOTE: jrange.end is exlusive, so we must substract it by 1
-        self.check_bound(Some(irange.start), Some(jrange.end - 1));
-
-        // Get the new number of rows and the new number of columns
-        let nrows = self.nrows - irange.start;
-        let ncols = jrange.end;
-
-        // Get the row & column offset
-        let row_offset = irange.start;
-        let col_offset = 0;
+        row_index: RI,
+        col_index: CI,
+    ) -> Option<Submatrix<'a, T>> {
+        if !row_index.contained_by(self.nrows)
+            || !col_index.contained_by(self.ncols)
+        {
+            return None;
+        }
 
-        // Return a sub matrix
-        Submatrix {
-            nrows,
-            ncols,
-            row_offset,
-            col_offset,
+        Some(Submatrix {
+            nrows: row_index.length(self.nrows),
+            ncols: col_index.length(self.ncols),
+            row_offset: row_index.lower(self.nrows),
+            col_offset: col_index.lower(self.ncols),
+            row_stride: 1,
+            col_stride: 1,
             source: self,
-        }
+        })
     }
 }
 
-// TODO(pyk): Implement the following slice combination
-// (RangeFrom, RangeFull)
-// (RangeFrom, RangeInclusive)
-// (RangeFrom, RangeToInclusive)
-// (RangeTo, Range)
-// (RangeTo, RangeFrom)
-// (RangeTo, RangeTo)
-// (RangeTo, RangeFull)
-// (RangeTo, RangeInclusive)
-// (RangeTo, RangeToInclusive)
-// (RangeFull, Range)
-// (RangeFull, RangeFrom)
-// (RangeFull, RangeTo)
-// (RangeFull, RangeFull)
-// (RangeFull, RangeInclusive)
-// (RangeFull, RangeToInclusive)
-// (RangeInclusive, Range)
-// (RangeInclusive, RangeFrom)
-// (RangeInclusive, RangeTo)
-// (RangeInclusive, RangeFull)
-// (RangeInclusive, RangeInclusive)
-// (RangeInclusive, RangeToInclusive)
-// (RangeToInclusive, Range)
-// (RangeToInclusive, RangeFrom)
-// (RangeToInclusive, RangeTo)
-// (RangeToInclusive, RangeFull)
-// (RangeToInclusive, RangeInclusive)
-// (RangeToInclusive, RangeToInclusive)
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -439,6 +378,8 @@ mod tests {
             ncols: 2,
             row_offset: 0,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -498,6 +439,8 @@ mod tests {
             ncols: 2,
             row_offset: 0,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -547,6 +490,8 @@ mod tests {
             ncols: 1,
             row_offset: 0,
             col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -596,6 +541,8 @@ mod tests {
             ncols: 3,
             row_offset: 0,
             col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -635,6 +582,8 @@ mod tests {
             ncols: 2,
             row_offset: 0,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -694,6 +643,8 @@ mod tests {
             ncols: 2,
             row_offset: 0,
             col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -743,6 +694,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -792,6 +745,8 @@ mod tests {
             ncols: 2,
             row_offset: 1,
             col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -831,6 +786,8 @@ mod tests {
             ncols: 1,
             row_offset: 0,
             col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
             source: &m,
         };
         assert_eq!(submatrix, expected);
@@ -855,4 +812,273 @@ mod tests {
         ];
         m.slice(0.., ..100);
     }
+
+    // The combinations below were previously missing entirely; the blanket
+    // `DimRange` impl closes all of them at once.
+
+    // Test Slice(RangeFull, RangeFull)
+    // matrix.slice(.., ..)
+    #[test]
+    fn test_slice_rangefull_rangefull() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let submatrix = m.slice(.., ..);
+        let expected = Submatrix {
+            nrows: 2,
+            ncols: 3,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            source: &m,
+        };
+        assert_eq!(submatrix, expected);
+    }
+
+    // Test Slice(RangeInclusive, RangeInclusive)
+    // matrix.slice(start..=end, start..=end)
+    #[test]
+    fn test_slice_rangeinclusive_rangeinclusive() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let submatrix = m.slice(0..=1, 1..=2);
+        let expected = Submatrix {
+            nrows: 2,
+            ncols: 2,
+            row_offset: 0,
+            col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
+            source: &m,
+        };
+        assert_eq!(submatrix, expected);
+    }
+
+    // Test Slice(RangeToInclusive, RangeToInclusive)
+    // matrix.slice(..=end, ..=end)
+    #[test]
+    fn test_slice_rangetoinclusive_rangetoinclusive() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let submatrix = m.slice(..=0, ..=1);
+        let expected = Submatrix {
+            nrows: 1,
+            ncols: 2,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            source: &m,
+        };
+        assert_eq!(submatrix, expected);
+    }
+
+    // Test Slice(usize, Range)
+    // matrix.slice(row, start..end) selects a single-row band
+    #[test]
+    fn test_slice_usize_range() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let submatrix = m.slice(1, 0..2);
+        let expected = Submatrix {
+            nrows: 1,
+            ncols: 2,
+            row_offset: 1,
+            col_offset: 0,
+            row_stride: 1,
+            col_stride: 1,
+            source: &m,
+        };
+        assert_eq!(submatrix, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_usize_invalid_row_out_of_bond() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        m.slice(100, 0..2);
+    }
+
+    // Test try_slice(Range, Range)
+    #[test]
+    fn test_try_slice_range_range() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let submatrix = m.try_slice(0..2, 1..3).unwrap();
+        let expected = Submatrix {
+            nrows: 2,
+            ncols: 2,
+            row_offset: 0,
+            col_offset: 1,
+            row_stride: 1,
+            col_stride: 1,
+            source: &m,
+        };
+        assert_eq!(submatrix, expected);
+    }
+
+    #[test]
+    fn test_try_slice_row_out_of_bond() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        assert!(m.try_slice(0..100, 1..3).is_none());
+    }
+
+    #[test]
+    fn test_try_slice_col_out_of_bond() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        assert!(m.try_slice(0..2, 1..100).is_none());
+    }
+
+    // Test SliceMut(Range, Range)
+    // matrix.slice_mut(start..end, start..end).fill(value)
+    #[test]
+    fn test_slice_mut_fill() {
+        let mut m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        m.slice_mut(0..2, 1..3).fill(0);
+        let expected = matrix![
+            3, 0, 0;
+            1, 0, 0;
+        ];
+        assert_eq!(m, expected);
+    }
+
+    #[test]
+    fn test_slice_mut_get_mut() {
+        let mut m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let mut submatrix = m.slice_mut(1.., 1..);
+        *submatrix.get_mut(0, 0) = 42;
+        assert_eq!(m.at(1, 1), &42);
+    }
+
+    #[test]
+    fn test_slice_mut_index_mut() {
+        let mut m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        let mut submatrix = m.slice_mut(0..2, ..);
+        submatrix[(1, 2)] = 42;
+        assert_eq!(m.at(1, 2), &42);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_mut_invalid_row_out_of_bond() {
+        let mut m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        m.slice_mut(100.., 0..2);
+    }
+
+    // Test slice_step(Range, Range, (row_stride, col_stride))
+    #[test]
+    fn test_slice_step() {
+        let m = matrix![
+            1, 2, 3, 4, 5, 6;
+            7, 8, 9, 10, 11, 12;
+            13, 14, 15, 16, 17, 18;
+            19, 20, 21, 22, 23, 24;
+            25, 26, 27, 28, 29, 30;
+            31, 32, 33, 34, 35, 36;
+        ];
+        let submatrix = m.slice_step(0..6, 0..6, (2, 3));
+        let expected = Submatrix {
+            nrows: 3,
+            ncols: 2,
+            row_offset: 0,
+            col_offset: 0,
+            row_stride: 2,
+            col_stride: 3,
+            source: &m,
+        };
+        assert_eq!(submatrix, expected);
+    }
+
+    #[test]
+    fn test_slice_step_elements() {
+        let m = matrix![
+            1, 2, 3, 4, 5, 6;
+            7, 8, 9, 10, 11, 12;
+            13, 14, 15, 16, 17, 18;
+            19, 20, 21, 22, 23, 24;
+            25, 26, 27, 28, 29, 30;
+            31, 32, 33, 34, 35, 36;
+        ];
+        let submatrix = m.slice_step(0..6, 0..6, (2, 3));
+        assert_eq!(submatrix.at(0, 0), &1);
+        assert_eq!(submatrix.at(0, 1), &4);
+        assert_eq!(submatrix.at(1, 0), &13);
+        assert_eq!(submatrix.at(2, 1), &34);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_step_invalid_zero_row_stride() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        m.slice_step(0..2, 0..3, (0, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_step_invalid_zero_col_stride() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        m.slice_step(0..2, 0..3, (1, 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_slice_step_invalid_row_out_of_bond() {
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+        ];
+        m.slice_step(0..100, 0..3, (1, 1));
+    }
+
+    #[test]
+    fn test_slice_step_uneven_division() {
+        // 3 rows with a stride of 2 samples rows 0 and 2, rounding the
+        // count up via `ceil(len / stride)` instead of truncating it.
+        let m = matrix![
+            3, 1, 4;
+            1, 5, 9;
+            2, 6, 5;
+        ];
+        let submatrix = m.slice_step(0..3, 0..3, (2, 1));
+        assert_eq!(submatrix.shape(), [2, 3]);
+        assert_eq!(submatrix.at(0, 0), &3);
+        assert_eq!(submatrix.at(1, 0), &2);
+    }
 }
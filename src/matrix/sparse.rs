@@ -0,0 +1,289 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Compressed-row sparse matrices.
+//!
+//! [`CsMatrix`] stores only the nonzero elements of a matrix, in the
+//! standard compressed sparse row (CSR) layout: row `r`'s nonzeros occupy
+//! `data[indptr[r]..indptr[r + 1]]`, with their column positions in
+//! `indices` over the same range, sorted within each row. This is a lot
+//! cheaper than the dense [`Matrix`] for large, mostly-zero 2-D data.
+//!
+//! [`Matrix`]: ../struct.Matrix.html
+
+use crate::matrix::errors::{MatrixBuilderError, MatrixBuilderErrorKind};
+use crate::vector::Vector;
+use num::Num;
+
+/// A compressed sparse row (CSR) matrix, storing only its nonzero
+/// elements.
+///
+/// See the [module documentation] for more details.
+///
+/// [module documentation]: index.html
+pub struct CsMatrix<T>
+where
+    T: Num + Copy,
+{
+    nrows: usize,
+    ncols: usize,
+    // Row pointers: row `r`'s nonzeros are at `data[indptr[r]..indptr[r+1]]`.
+    // Has length `nrows + 1`.
+    indptr: Vec<usize>,
+    // Column index of each stored nonzero, sorted within each row.
+    indices: Vec<usize>,
+    // Value of each stored nonzero, parallel to `indices`.
+    data: Vec<T>,
+}
+
+impl<T> CsMatrix<T>
+where
+    T: Num + Copy,
+{
+    // Checks that `indptr`/`indices`/`data` form a valid CSR layout:
+    // `indptr` has length `nrows + 1`, is monotonically nondecreasing,
+    // starts at 0 and ends at `data.len()`, `indices` and `data` have
+    // equal length, every column index is within `ncols`, and the column
+    // indices of each row are sorted.
+    fn validate(
+        nrows: usize,
+        ncols: usize,
+        indptr: &[usize],
+        indices: &[usize],
+        data: &[T],
+    ) -> Result<(), MatrixBuilderError> {
+        if indices.len() != data.len() {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidRange,
+                format!(
+                    "indices has length {} but data has length {}",
+                    indices.len(),
+                    data.len()
+                ),
+            ));
+        }
+        if indptr.len() != nrows + 1 {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidRange,
+                format!(
+                    "indptr should have length {} for {} rows, found {}",
+                    nrows + 1,
+                    nrows,
+                    indptr.len()
+                ),
+            ));
+        }
+        if indptr.first() != Some(&0) || indptr.last() != Some(&data.len()) {
+            return Err(MatrixBuilderError::new(
+                MatrixBuilderErrorKind::InvalidRange,
+                "indptr should start at 0 and end at data.len()".to_string(),
+            ));
+        }
+        for window in indptr.windows(2) {
+            if window[0] > window[1] {
+                return Err(MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::InvalidRange,
+                    "indptr should be monotonically nondecreasing".to_string(),
+                ));
+            }
+        }
+        if let Some(&max_col) = indices.iter().max() {
+            if max_col >= ncols {
+                return Err(MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::InvalidRange,
+                    format!("column index {} is out of range for ncols {}", max_col, ncols),
+                ));
+            }
+        }
+        for r in 0..nrows {
+            let row = &indices[indptr[r]..indptr[r + 1]];
+            if row.windows(2).any(|pair| pair[0] > pair[1]) {
+                return Err(MatrixBuilderError::new(
+                    MatrixBuilderErrorKind::InvalidRange,
+                    format!("column indices of row {} should be sorted", r),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a CSR matrix from its raw compressed layout, validating
+    /// the structural invariants described in the [module documentation].
+    ///
+    /// [module documentation]: index.html
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// // [[0, 3], [0, 0], [5, 0]]
+    /// let m = CsMatrix::from_parts(3, 2, vec![0, 1, 1, 2], vec![1, 0], vec![3, 5]).unwrap();
+    /// assert_eq!(m.nnz(), 2);
+    /// ```
+    pub fn from_parts(
+        nrows: usize,
+        ncols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        data: Vec<T>,
+    ) -> Result<CsMatrix<T>, MatrixBuilderError> {
+        CsMatrix::validate(nrows, ncols, &indptr, &indices, &data)?;
+        Ok(CsMatrix {
+            nrows,
+            ncols,
+            indptr,
+            indices,
+            data,
+        })
+    }
+
+    /// Creates a CSR matrix holding the nonzero elements of the dense,
+    /// row-major matrix `source`.
+    ///
+    /// # Panics
+    /// Panics if the rows of `source` don't all have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    /// let m = CsMatrix::from_dense(&source);
+    /// assert_eq!(m.nnz(), 2);
+    /// ```
+    pub fn from_dense(source: &Vec<Vec<T>>) -> CsMatrix<T> {
+        let nrows = source.len();
+        let ncols = if nrows == 0 { 0 } else { source[0].len() };
+        if source.iter().any(|row| row.len() != ncols) {
+            panic!("CsMatrix: the number of columns is inconsistent")
+        }
+
+        let zero = T::zero();
+        let mut indptr = Vec::with_capacity(nrows + 1);
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        indptr.push(0);
+        for row in source {
+            for (c, &value) in row.iter().enumerate() {
+                if value != zero {
+                    indices.push(c);
+                    data.push(value);
+                }
+            }
+            indptr.push(data.len());
+        }
+
+        CsMatrix {
+            nrows,
+            ncols,
+            indptr,
+            indices,
+            data,
+        }
+    }
+
+    /// Expands this CSR matrix into a dense, row-major `Vec<Vec<T>>`,
+    /// filling absent positions with zero.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    /// let m = CsMatrix::from_dense(&source);
+    /// assert_eq!(m.to_dense(), source);
+    /// ```
+    pub fn to_dense(&self) -> Vec<Vec<T>> {
+        let mut result = vec![vec![T::zero(); self.ncols]; self.nrows];
+        for r in 0..self.nrows {
+            for (c, &value) in self.row(r) {
+                result[r][c] = value;
+            }
+        }
+        result
+    }
+
+    /// The shape of the matrix, as `[nrows, ncols]`.
+    pub fn shape(&self) -> [usize; 2] {
+        [self.nrows, self.ncols]
+    }
+
+    /// The number of stored nonzero elements.
+    pub fn nnz(&self) -> usize {
+        self.data.len()
+    }
+
+    /// Iterates over row `r`'s nonzero elements, as `(col, &value)` pairs
+    /// in increasing column order.
+    ///
+    /// # Panics
+    /// Panics if `r >= self.shape()[0]`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    /// let m = CsMatrix::from_dense(&source);
+    /// let cols: Vec<usize> = m.row(0).map(|(c, _)| c).collect();
+    /// assert_eq!(cols, vec![1]);
+    /// ```
+    pub fn row(&self, r: usize) -> impl Iterator<Item = (usize, &T)> {
+        if r >= self.nrows {
+            panic!(
+                "CsMatrix row {} out of range for matrix with {} rows",
+                r, self.nrows
+            )
+        }
+        let start = self.indptr[r];
+        let end = self.indptr[r + 1];
+        self.indices[start..end]
+            .iter()
+            .copied()
+            .zip(self.data[start..end].iter())
+    }
+
+    /// Computes the matrix-vector product `self * x`, summing
+    /// `data[k] * x[indices[k]]` over each row's nonzeros, an `O(nnz)`
+    /// multiply rather than the dense `O(nrows * ncols)`.
+    ///
+    /// # Panics
+    /// Panics if `self.shape()[1] != x.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    /// let m = CsMatrix::from_dense(&source);
+    /// let x = vector![1, 2];
+    /// assert_eq!(m.dot(&x), vector![6, 0, 5]);
+    /// ```
+    pub fn dot(&self, x: &Vector<T>) -> Vector<T> {
+        if self.ncols != x.len() {
+            panic!(
+                "CsMatrix dot: dimension mismatch, {} != {}",
+                self.ncols,
+                x.len()
+            )
+        }
+
+        let mut result = Vec::with_capacity(self.nrows);
+        for r in 0..self.nrows {
+            let mut sum = T::zero();
+            for (c, &value) in self.row(r) {
+                sum = sum + value * x[c];
+            }
+            result.push(sum);
+        }
+
+        Vector::from(result)
+    }
+}
@@ -0,0 +1,181 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Gluing matrices together from sub-blocks.
+//!
+//! TODO(pyk): Add docs here
+//!
+
+use crate::error::CrabsformerError;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num::Num;
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Stack a slice of matrices vertically (row-wise), i.e. on top of
+    /// each other. Every matrix must have the same number of columns.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5, 6];
+    /// let w = Matrix::vstack(&[a, b]).unwrap();
+    /// assert_eq!(w, matrix![1, 2; 3, 4; 5, 6]);
+    /// ```
+    pub fn vstack(matrices: &[Matrix<T>]) -> Result<Matrix<T>, CrabsformerError> {
+        if matrices.is_empty() {
+            panic!("Matrix::vstack: at least one matrix is required");
+        }
+
+        let ncols = matrices[0].ncols;
+        let mut nrows = 0;
+        for m in matrices {
+            if m.ncols != ncols {
+                return Err(CrabsformerError::ShapeMismatch {
+                    lhs: vec![matrices[0].nrows, ncols],
+                    rhs: vec![m.nrows, m.ncols],
+                });
+            }
+            nrows += m.nrows;
+        }
+
+        let mut elements = Vec::with_capacity(nrows * ncols);
+        for m in matrices {
+            for i in 0..m.nrows {
+                for j in 0..m.ncols {
+                    elements.push(*m.at(i, j));
+                }
+            }
+        }
+
+        Ok(Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(elements),
+        })
+    }
+
+    /// Stack a slice of matrices horizontally (column-wise), i.e. side by
+    /// side. Every matrix must have the same number of rows.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = matrix![1, 2; 3, 4];
+    /// let b = matrix![5; 6];
+    /// let w = Matrix::hstack(&[a, b]).unwrap();
+    /// assert_eq!(w, matrix![1, 2, 5; 3, 4, 6]);
+    /// ```
+    pub fn hstack(matrices: &[Matrix<T>]) -> Result<Matrix<T>, CrabsformerError> {
+        if matrices.is_empty() {
+            panic!("Matrix::hstack: at least one matrix is required");
+        }
+
+        let nrows = matrices[0].nrows;
+        let mut ncols = 0;
+        for m in matrices {
+            if m.nrows != nrows {
+                return Err(CrabsformerError::ShapeMismatch {
+                    lhs: vec![nrows, matrices[0].ncols],
+                    rhs: vec![m.nrows, m.ncols],
+                });
+            }
+            ncols += m.ncols;
+        }
+
+        let mut elements = Vec::with_capacity(nrows * ncols);
+        for i in 0..nrows {
+            for m in matrices {
+                for j in 0..m.ncols {
+                    elements.push(*m.at(i, j));
+                }
+            }
+        }
+
+        Ok(Matrix {
+            nrows,
+            ncols,
+            vec: Vector::from(elements),
+        })
+    }
+
+    /// Assemble a matrix from a grid of sub-matrix blocks, verifying that
+    /// block heights line up across each row and block widths line up
+    /// down each column before gluing them together with [`hstack`]/
+    /// [`vstack`]. Used by [`matrix_block!`].
+    ///
+    /// [`hstack`]: #method.hstack
+    /// [`vstack`]: #method.vstack
+    /// [`matrix_block!`]: ../macro.matrix_block.html
+    pub fn block(rows: &[Vec<Matrix<T>>]) -> Result<Matrix<T>, CrabsformerError> {
+        if rows.is_empty() {
+            panic!("Matrix::block: at least one row is required");
+        }
+
+        // The widths of the first row's blocks, in order; every other
+        // row's block widths must match this, position by position, or
+        // the column blocks wouldn't actually line up.
+        let col_widths: Vec<usize> = rows[0].iter().map(|m| m.ncols).collect();
+        for row in rows {
+            let widths: Vec<usize> = row.iter().map(|m| m.ncols).collect();
+            if widths != col_widths {
+                return Err(CrabsformerError::ShapeMismatch {
+                    lhs: col_widths,
+                    rhs: widths,
+                });
+            }
+        }
+
+        let stacked_rows: Vec<Matrix<T>> = rows
+            .iter()
+            .map(|row| Matrix::hstack(row))
+            .collect::<Result<_, _>>()?;
+        Matrix::vstack(&stacked_rows)
+    }
+}
+
+/// Creates a [matrix] by assembling a grid of already-built sub-matrices.
+///
+/// `matrix_block!` takes sub-matrix expressions separated by `,` within a
+/// row and `;` between rows, verifies that block heights line up across
+/// each row and block widths line up down each column, and then
+/// [`hstack`]s each row together and [`vstack`]s the resulting rows,
+/// returning a `Result<Matrix<T>, ...>` so a shape mismatch between blocks
+/// is reported instead of causing a panic or silently producing a matrix
+/// whose column blocks don't actually align.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// let a = matrix![1, 2; 3, 4];
+/// let b = matrix![5; 6];
+/// let c = matrix![7, 8];
+/// let d = matrix![9];
+/// let w = matrix_block![a, b; c, d].unwrap();
+/// assert_eq!(w, matrix![1, 2, 5; 3, 4, 6; 7, 8, 9]);
+/// ```
+///
+/// [matrix]: struct.Matrix.html
+/// [`hstack`]: struct.Matrix.html#method.hstack
+/// [`vstack`]: struct.Matrix.html#method.vstack
+#[macro_export]
+macro_rules! matrix_block {
+    ($($($x:expr),*);*) => {{
+        $crate::matrix::Matrix::block(&vec![$(vec![$($x),*]),*])
+    }};
+}
@@ -31,13 +31,23 @@ pub use crate::matrix::indexing::*;
 pub use crate::matrix::iterators::*;
 pub use crate::matrix::loaders::*;
 pub use crate::matrix::operations::*;
+#[cfg(feature = "proptest")]
+pub use crate::matrix::proptest::*;
 pub use crate::matrix::slicing::*;
+pub use crate::matrix::sparse::*;
+pub use crate::matrix::stacking::*;
 pub use crate::matrix::*;
+pub use crate::smatrix::*;
+pub use crate::stackvec::*;
+pub use crate::svector::*;
 pub use crate::vector::builders::*;
 pub use crate::vector::indexing::*;
 pub use crate::vector::iterators::*;
 pub use crate::vector::loaders::*;
 pub use crate::vector::operations::*;
+#[cfg(feature = "proptest")]
+pub use crate::vector::proptest::*;
 pub use crate::vector::slicing::*;
+pub use crate::vector::sparse::*;
 pub use crate::vector::*;
 pub use crate::*;
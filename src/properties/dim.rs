@@ -24,39 +24,32 @@ where
     fn dim(&self) -> usize;
 }
 
-impl<T> Dimension<T> for Vec<T>
-where
-    T: Copy,
-{
-    fn dim(&self) -> usize {
-        1
-    }
+// Base case: a flat vector of scalars has dimension 1. This is implemented
+// for each concrete scalar type instead of a generic `Vec<T>` blanket impl
+// so that it doesn't overlap with the recursive impl below.
+macro_rules! impl_dim_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Dimension<$t> for Vec<$t> {
+                fn dim(&self) -> usize {
+                    1
+                }
+            }
+        )*
+    };
 }
 
-impl<T> Dimension<T> for Vec<Vec<T>>
-where
-    T: Copy,
-{
-    fn dim(&self) -> usize {
-        2
-    }
-}
+impl_dim_scalar!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
-impl<T> Dimension<T> for Vec<Vec<Vec<T>>>
+// Recursive case: a vector of vectors has one more dimension than its
+// elements, so this works for any rank instead of capping out at 4.
+impl<T, U> Dimension<T> for Vec<U>
 where
     T: Copy,
+    U: Dimension<T>,
 {
     fn dim(&self) -> usize {
-        3
-    }
-}
-
-impl<T> Dimension<T> for Vec<Vec<Vec<Vec<T>>>>
-where
-    T: Copy,
-{
-    fn dim(&self) -> usize {
-        4
+        1 + self[0].dim()
     }
 }
 
@@ -77,6 +70,10 @@ mod tests {
 
         let arr4: Vec<Vec<Vec<Vec<i32>>>> = Vec::four_dim(2, 2, 2, 3).zeros();
         assert_eq!(arr4.dim(), 4);
+
+        let arr5: Vec<Vec<Vec<Vec<Vec<i32>>>>> =
+            vec![vec![vec![vec![vec![0; 2]; 2]; 2]; 2]; 2];
+        assert_eq!(arr5.dim(), 5);
     }
 
 }
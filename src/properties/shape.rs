@@ -25,44 +25,34 @@ where
     fn shape(&self) -> Vec<usize>;
 }
 
-impl<T> Shape<T> for Vec<T>
-where
-    T: Copy,
-{
-    fn shape(&self) -> Vec<usize> {
-        vec![self.len()]
-    }
+// Base case: a flat vector of scalars has shape `[len]`. This is implemented
+// for each concrete scalar type instead of a generic `Vec<T>` blanket impl
+// so that it doesn't overlap with the recursive impl below.
+macro_rules! impl_shape_scalar {
+    ($($t:ty),*) => {
+        $(
+            impl Shape<$t> for Vec<$t> {
+                fn shape(&self) -> Vec<usize> {
+                    vec![self.len()]
+                }
+            }
+        )*
+    };
 }
 
-impl<T> Shape<T> for Vec<Vec<T>>
-where
-    T: Copy,
-{
-    fn shape(&self) -> Vec<usize> {
-        vec![self.len(), self[0].len()]
-    }
-}
+impl_shape_scalar!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
 
-impl<T> Shape<T> for Vec<Vec<Vec<T>>>
+// Recursive case: a vector of vectors prepends its own length to the shape
+// of its elements, so this works for any rank instead of capping out at 4.
+impl<T, U> Shape<T> for Vec<U>
 where
     T: Copy,
+    U: Shape<T>,
 {
     fn shape(&self) -> Vec<usize> {
-        vec![self.len(), self[0].len(), self[0][0].len()]
-    }
-}
-
-impl<T> Shape<T> for Vec<Vec<Vec<Vec<T>>>>
-where
-    T: Copy,
-{
-    fn shape(&self) -> Vec<usize> {
-        vec![
-            self.len(),
-            self[0].len(),
-            self[0][0].len(),
-            self[0][0][0].len(),
-        ]
+        let mut shape = vec![self.len()];
+        shape.extend(self[0].shape());
+        shape
     }
 }
 
@@ -84,5 +74,9 @@ mod tests {
 
         let arr4: Vec<Vec<Vec<Vec<i32>>>> = Vec::four_dim(2, 2, 2, 3).zeros();
         assert_eq!(arr4.shape(), [2, 2, 2, 3]);
+
+        let arr5: Vec<Vec<Vec<Vec<Vec<i32>>>>> =
+            vec![vec![vec![vec![vec![0; 3]; 2]; 2]; 2]; 2];
+        assert_eq!(arr5.shape(), [2, 2, 2, 2, 3]);
     }
 }
@@ -27,37 +27,13 @@ where
     fn size(&self) -> usize;
 }
 
-impl<T> Size<T> for Vec<T>
-where
-    T: Copy,
-{
-    fn size(&self) -> usize {
-        self.shape().iter().product()
-    }
-}
-
-impl<T> Size<T> for Vec<Vec<T>>
-where
-    T: Copy,
-{
-    fn size(&self) -> usize {
-        let shape: Vec<usize> = self.shape();
-        shape.iter().product()
-    }
-}
-
-impl<T> Size<T> for Vec<Vec<Vec<T>>>
-where
-    T: Copy,
-{
-    fn size(&self) -> usize {
-        self.shape().iter().product()
-    }
-}
-
-impl<T> Size<T> for Vec<Vec<Vec<Vec<T>>>>
+// `size()` is just the product of `shape()`, and `Shape<T>` is already
+// implemented recursively for every rank, so a single blanket impl covers
+// all of them instead of one hand-written impl per dimension.
+impl<T, U> Size<T> for U
 where
     T: Copy,
+    U: Shape<T>,
 {
     fn size(&self) -> usize {
         self.shape().iter().product()
@@ -82,5 +58,9 @@ mod tests {
 
         let arr4: Vec<Vec<Vec<Vec<i32>>>> = Vec::four_dim(2, 2, 2, 3).zeros();
         assert_eq!(arr4.size(), 24);
+
+        let arr5: Vec<Vec<Vec<Vec<Vec<i32>>>>> =
+            vec![vec![vec![vec![vec![0; 3]; 2]; 2]; 2]; 2];
+        assert_eq!(arr5.size(), 48);
     }
 }
@@ -0,0 +1,224 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A statically-sized companion to [`Matrix`], backed by a fixed-size
+//! array of arrays instead of a heap-allocated buffer.
+//!
+//! [`Matrix`]'s shape is only known at runtime, so a shape mismatch in
+//! `+`, `-` or `dot` is a panic (or, via `try_add`/`try_sub`/`try_dot`, a
+//! runtime `Result`). [`SMatrix<T, M, N>`] bakes `M` and `N` into the
+//! type instead: `Add`/`Sub` only exist when both operands share the
+//! same `M, N`, and `dot` only exists for `SMatrix<T, M, N> x
+//! SMatrix<T, N, P>`, so a shape mismatch is rejected at compile time
+//! rather than at runtime. Use it when a shape is known at compile time;
+//! fall back to [`Matrix`] when it's only known at runtime (e.g. loaded
+//! from a file).
+//!
+//! [`Matrix`]: ../matrix/struct.Matrix.html
+//! [`SMatrix<T, M, N>`]: struct.SMatrix.html
+
+use crate::error::CrabsformerError;
+use crate::matrix::Matrix;
+use crate::vector::Vector;
+use num::{FromPrimitive, Num};
+use std::convert::TryFrom;
+use std::ops;
+
+/// A statically-sized matrix of exactly `M` rows and `N` columns.
+///
+/// See the [module docs] for the rationale behind this type.
+///
+/// [module docs]: index.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<T, const M: usize, const N: usize>
+where
+    T: Num + Copy,
+{
+    data: [[T; N]; M],
+}
+
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Num + Copy,
+{
+    /// Creates a new static matrix from a fixed-size array of rows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::smatrix::SMatrix;
+    /// let w = SMatrix::new([[3, 1, 4], [1, 5, 9]]);
+    /// assert_eq!(w.shape(), [2, 3]);
+    /// ```
+    pub fn new(data: [[T; N]; M]) -> SMatrix<T, M, N> {
+        SMatrix { data }
+    }
+
+    /// The shape of the static matrix `[M, N]`, known at compile time.
+    pub fn shape(&self) -> [usize; 2] {
+        [M, N]
+    }
+
+    /// Returns a reference to the element at row `i`, column `j`.
+    pub fn at(&self, i: usize, j: usize) -> &T {
+        &self.data[i][j]
+    }
+}
+
+/// Static matrix multiplication. Only defined for `SMatrix<T, M, N> x
+/// SMatrix<T, N, P> -> SMatrix<T, M, P>`, so an inner-dimension mismatch
+/// is a compile error, not a panic.
+impl<T, const M: usize, const N: usize> SMatrix<T, M, N>
+where
+    T: Num + Copy + FromPrimitive,
+{
+    /// Returns the matrix product of `self` and `other`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::smatrix::SMatrix;
+    /// let a = SMatrix::new([[1, 2], [3, 4]]);
+    /// let b = SMatrix::new([[5, 6], [7, 8]]);
+    /// assert_eq!(a.dot(&b), SMatrix::new([[19, 22], [43, 50]]));
+    /// ```
+    pub fn dot<const P: usize>(
+        &self,
+        other: &SMatrix<T, N, P>,
+    ) -> SMatrix<T, M, P> {
+        let mut data = [[T::zero(); P]; M];
+        for i in 0..M {
+            for j in 0..P {
+                let mut sum = T::zero();
+                for k in 0..N {
+                    sum = sum + self.data[i][k] * other.data[k][j];
+                }
+                data[i][j] = sum;
+            }
+        }
+        SMatrix { data }
+    }
+}
+
+/// Static matrix addition. Only defined when both operands share the
+/// same `M, N`, so a shape mismatch is a compile error, not a panic.
+impl<T, const M: usize, const N: usize> ops::Add for SMatrix<T, M, N>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, M, N>;
+
+    fn add(self, other: SMatrix<T, M, N>) -> SMatrix<T, M, N> {
+        let mut data = self.data;
+        for i in 0..M {
+            for j in 0..N {
+                data[i][j] = data[i][j] + other.data[i][j];
+            }
+        }
+        SMatrix { data }
+    }
+}
+
+/// Static matrix substraction. Only defined when both operands share the
+/// same `M, N`, so a shape mismatch is a compile error, not a panic.
+impl<T, const M: usize, const N: usize> ops::Sub for SMatrix<T, M, N>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, M, N>;
+
+    fn sub(self, other: SMatrix<T, M, N>) -> SMatrix<T, M, N> {
+        let mut data = self.data;
+        for i in 0..M {
+            for j in 0..N {
+                data[i][j] = data[i][j] - other.data[i][j];
+            }
+        }
+        SMatrix { data }
+    }
+}
+
+/// Converts a static matrix into a dynamic [`Matrix`]. Always succeeds,
+/// since a fixed shape is also a valid dynamic shape.
+///
+/// [`Matrix`]: ../matrix/struct.Matrix.html
+impl<T, const M: usize, const N: usize> From<SMatrix<T, M, N>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn from(m: SMatrix<T, M, N>) -> Matrix<T> {
+        let mut data = Vec::with_capacity(M * N);
+        for row in m.data.iter() {
+            data.extend_from_slice(row);
+        }
+        Matrix::from_vector(Vector::from(data), N).unwrap()
+    }
+}
+
+/// Converts a dynamic [`Matrix`] into a static [`SMatrix<T, M, N>`],
+/// failing with [`CrabsformerError::ShapeMismatch`] if its shape isn't
+/// `[M, N]`.
+///
+/// [`Matrix`]: ../matrix/struct.Matrix.html
+/// [`SMatrix<T, M, N>`]: struct.SMatrix.html
+/// [`CrabsformerError::ShapeMismatch`]: ../error/enum.CrabsformerError.html#variant.ShapeMismatch
+impl<T, const M: usize, const N: usize> TryFrom<Matrix<T>> for SMatrix<T, M, N>
+where
+    T: Num + Copy,
+{
+    type Error = CrabsformerError;
+
+    fn try_from(m: Matrix<T>) -> Result<SMatrix<T, M, N>, CrabsformerError> {
+        if m.shape() != [M, N] {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: m.shape().to_vec(),
+                rhs: vec![M, N],
+            });
+        }
+        let mut data = [[T::zero(); N]; M];
+        for i in 0..M {
+            for j in 0..N {
+                data[i][j] = *m.at(i, j);
+            }
+        }
+        Ok(SMatrix { data })
+    }
+}
+
+/// Creates a [static matrix] containing the arguments, with its shape
+/// `[M, N]` fixed at compile time.
+///
+/// `smatrix!` allows a static matrix to be defined with the same syntax
+/// as the dynamic [`matrix!`] macro.
+///
+/// # Examples
+///
+/// ```
+/// # use crabsformer::smatrix;
+/// let w = smatrix![
+///     3, 1, 4;
+///     1, 5, 9;
+/// ];
+/// assert_eq!(w.shape(), [2, 3]);
+/// assert_eq!(*w.at(0, 0), 3);
+/// ```
+///
+/// [static matrix]: smatrix/struct.SMatrix.html
+/// [`matrix!`]: ../macro.matrix.html
+#[macro_export]
+macro_rules! smatrix {
+    ($($($x:expr),+);+ $(;)?) => {{
+        $crate::smatrix::SMatrix::new([$([$($x),+]),+])
+    }};
+}
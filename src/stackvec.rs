@@ -0,0 +1,228 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Fixed-capacity, stack-allocated numeric array.
+//!
+//! [`StackVec<T, N>`] is the stack-allocated counterpart of [`Vector<T>`]:
+//! its capacity `N` is fixed at compile time, so it never heap-allocates.
+//! This is useful for numeric code where the dimension is known to be
+//! small, for example a 3D coordinate or a small batch of samples.
+//!
+//! [`StackVec<T, N>`]: struct.StackVec.html
+//! [`Vector<T>`]: ../vector/struct.Vector.html
+
+use num::{FromPrimitive, Num};
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::ops::{AddAssign, Deref, DerefMut};
+use std::ptr;
+use std::slice;
+
+/// An error which can be returned when an operation on a [`StackVec`] would
+/// need more than its fixed capacity `N`.
+///
+/// [`StackVec`]: struct.StackVec.html
+pub struct CapacityError {
+    message: String,
+}
+
+impl CapacityError {
+    /// Creates a new `CapacityError` with the given error message.
+    pub fn new(message: String) -> Self {
+        CapacityError { message }
+    }
+}
+
+impl fmt::Debug for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StackVec capacity exceeded: {}", self.message)
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "StackVec capacity exceeded: {}", self.message)
+    }
+}
+
+/// A fixed-capacity, stack-allocated numeric array.
+///
+/// `StackVec<T, N>` is backed by `[MaybeUninit<T>; N]` plus a length
+/// cursor, so creating or growing one never heap-allocates. Operations
+/// that would need more than `N` slots, such as [`push`] or [`full_of`],
+/// return a [`CapacityError`] instead of panicking or reallocating.
+///
+/// [`push`]: #method.push
+/// [`full_of`]: #method.full_of
+/// [`CapacityError`]: struct.CapacityError.html
+///
+/// # Examples
+/// ```
+/// use crabsformer::stackvec::StackVec;
+///
+/// let mut v: StackVec<i32, 4> = StackVec::new();
+/// v.push(1).unwrap();
+/// v.push(2).unwrap();
+/// assert_eq!(&v[..], &[1, 2]);
+/// ```
+pub struct StackVec<T, const N: usize> {
+    data: [MaybeUninit<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> StackVec<T, N> {
+    /// Creates a new, empty `StackVec`.
+    pub fn new() -> Self {
+        StackVec {
+            data: unsafe { MaybeUninit::uninit().assume_init() },
+            len: 0,
+        }
+    }
+
+    /// Returns the number of initialized elements currently stored.
+    ///
+    /// This plays the same role as `Size<T>::size` does for the `Vec`-based
+    /// builders, but is an inherent method rather than a `Size<T>` impl:
+    /// `StackVec` doesn't implement `Size<T>`, so it can't be passed to code
+    /// that's generic over `T: Size<U>`.
+    pub fn size(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the fixed capacity `N` of this `StackVec`.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    /// Appends `value` to the end of the `StackVec`.
+    ///
+    /// Returns a [`CapacityError`] instead of reallocating when the
+    /// `StackVec` is already holding `N` elements.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    pub fn push(&mut self, value: T) -> Result<(), CapacityError> {
+        if self.len == N {
+            return Err(CapacityError::new(format!(
+                "cannot push onto a full StackVec of capacity {}",
+                N
+            )));
+        }
+        self.data[self.len] = MaybeUninit::new(value);
+        self.len += 1;
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> StackVec<T, N>
+where
+    T: Copy,
+{
+    /// Creates a `StackVec` of length `len`, filled with `value`.
+    ///
+    /// This plays the same role as `Full<T>::full` does for the `Vec`-based
+    /// builders, but is an inherent constructor rather than a `Full<T>`
+    /// impl: `StackVec` doesn't implement `Full<T>`, so it can't be used by
+    /// code that's generic over `T: Full<U>`. Returns a [`CapacityError`]
+    /// if `len` is greater than the fixed capacity `N`.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::stackvec::StackVec;
+    ///
+    /// let v: StackVec<i32, 4> = StackVec::full_of(2, 10).unwrap();
+    /// assert_eq!(&v[..], &[10, 10]);
+    /// ```
+    pub fn full_of(len: usize, value: T) -> Result<Self, CapacityError> {
+        if len > N {
+            return Err(CapacityError::new(format!(
+                "cannot fill {} elements into a StackVec of capacity {}",
+                len, N
+            )));
+        }
+        let mut vec = StackVec::new();
+        for _ in 0..len {
+            vec.push(value).unwrap();
+        }
+        Ok(vec)
+    }
+}
+
+impl<T, const N: usize> StackVec<T, N>
+where
+    T: Num + FromPrimitive + PartialOrd + AddAssign + Copy,
+{
+    /// Creates a `StackVec` of evenly spaced values within the half-open
+    /// interval `[start, stop)`.
+    ///
+    /// The resulting length is `ceil((stop - start) / step)`. Returns a
+    /// [`CapacityError`] if that length would exceed the fixed capacity
+    /// `N`.
+    ///
+    /// [`CapacityError`]: struct.CapacityError.html
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::stackvec::StackVec;
+    ///
+    /// let v: StackVec<i32, 4> = StackVec::range(0, 4, 1).unwrap();
+    /// assert_eq!(&v[..], &[0, 1, 2, 3]);
+    /// ```
+    pub fn range(start: T, stop: T, step: T) -> Result<Self, CapacityError> {
+        let mut vec = StackVec::new();
+        let mut current = start;
+        while current < stop {
+            vec.push(current).map_err(|_| {
+                CapacityError::new(format!(
+                    "range does not fit in a StackVec of capacity {}",
+                    N
+                ))
+            })?;
+            current += step;
+        }
+        Ok(vec)
+    }
+}
+
+impl<T, const N: usize> Drop for StackVec<T, N> {
+    fn drop(&mut self) {
+        for i in 0..self.len {
+            unsafe {
+                ptr::drop_in_place(self.data[i].as_mut_ptr());
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Deref for StackVec<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        unsafe {
+            slice::from_raw_parts(self.data.as_ptr() as *const T, self.len)
+        }
+    }
+}
+
+impl<T, const N: usize> DerefMut for StackVec<T, N> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        unsafe {
+            slice::from_raw_parts_mut(
+                self.data.as_mut_ptr() as *mut T,
+                self.len,
+            )
+        }
+    }
+}
@@ -0,0 +1,270 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A statically-sized companion to [`Vector`], backed by a fixed-size
+//! array instead of a heap-allocated `Vec`.
+//!
+//! [`Vector`]'s length is only known at runtime, so a shape mismatch in
+//! `+`, `-` or `dot` is a panic (or, via `try_add`/`try_sub`/`try_dot`, a
+//! runtime `Result`). [`SVector<T, N>`] bakes `N` into the type instead,
+//! so the compiler rejects mismatched operands before the program ever
+//! runs. Use it when a length is known at compile time; fall back to
+//! [`Vector`] when it's only known at runtime (e.g. loaded from a file).
+//!
+//! [`Vector`]: ../vector/struct.Vector.html
+//! [`SVector<T, N>`]: struct.SVector.html
+
+use crate::error::CrabsformerError;
+use crate::utils::TypeName;
+use crate::vector::Vector;
+use num::Num;
+use std::convert::TryFrom;
+use std::ops;
+
+/// A statically-sized numeric vector of exactly `N` elements.
+///
+/// See the [module docs] for the rationale behind this type.
+///
+/// [module docs]: index.html
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SVector<T, const N: usize>
+where
+    T: Num + Copy,
+{
+    data: [T; N],
+}
+
+impl<T, const N: usize> SVector<T, N>
+where
+    T: Num + Copy,
+{
+    /// Creates a new static numeric vector from a fixed-size array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::svector::SVector;
+    /// let v = SVector::new([3, 1, 4]);
+    /// assert_eq!(v.len(), 3);
+    /// ```
+    pub fn new(data: [T; N]) -> SVector<T, N> {
+        SVector { data }
+    }
+
+    /// Creates a static numeric vector of `N` elements, all set to zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::svector::SVector;
+    /// let v = SVector::<i32, 3>::zeros();
+    /// assert_eq!(v, SVector::new([0, 0, 0]));
+    /// ```
+    pub fn zeros() -> SVector<T, N> {
+        SVector::full(T::zero())
+    }
+
+    /// Creates a static numeric vector of `N` elements, all set to
+    /// `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::svector::SVector;
+    /// let v = SVector::<i32, 3>::full(7);
+    /// assert_eq!(v, SVector::new([7, 7, 7]));
+    /// ```
+    pub fn full(value: T) -> SVector<T, N> {
+        SVector { data: [value; N] }
+    }
+
+    /// The total number of elements of the static numeric vector. This is
+    /// `N`, known at compile time.
+    pub fn len(&self) -> usize {
+        N
+    }
+
+    /// Borrows the underlying elements as a contiguous slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+}
+
+impl<T, const N: usize> SVector<T, N>
+where
+    T: Num + Copy + TypeName,
+{
+    /// A human-readable name for this static numeric vector's type,
+    /// reporting both its element type and its compile-time dimension,
+    /// e.g. `"SVector<f64, 3>"`. Intended for diagnostics (error
+    /// messages, `Debug` wrappers), the same role [`TypeName`] already
+    /// plays for the element types it covers.
+    ///
+    /// [`TypeName`]: ../utils/trait.TypeName.html
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::svector::SVector;
+    /// let v = SVector::<f64, 3>::zeros();
+    /// assert_eq!(v.type_name(), "SVector<f64, 3>");
+    /// ```
+    pub fn type_name(&self) -> String {
+        format!("SVector<{}, {}>", T::type_name(), N)
+    }
+}
+
+impl<T, const N: usize> ops::Index<usize> for SVector<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        &self.data[index]
+    }
+}
+
+/// Static numeric vector addition. Only defined when both operands share
+/// the same `N`, so a length mismatch is a compile error, not a panic.
+///
+/// # Examples
+///
+/// ```
+/// # use crabsformer::svector::SVector;
+/// let a = SVector::new([3, 1, 4]);
+/// let b = SVector::new([1, 5, 9]);
+/// assert_eq!(a + b, SVector::new([4, 6, 13]));
+/// ```
+impl<T, const N: usize> ops::Add for SVector<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = SVector<T, N>;
+
+    fn add(self, other: SVector<T, N>) -> SVector<T, N> {
+        let mut data = self.data;
+        for i in 0..N {
+            data[i] = data[i] + other.data[i];
+        }
+        SVector { data }
+    }
+}
+
+/// Static numeric vector substraction. Only defined when both operands
+/// share the same `N`, so a length mismatch is a compile error, not a
+/// panic.
+impl<T, const N: usize> ops::Sub for SVector<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = SVector<T, N>;
+
+    fn sub(self, other: SVector<T, N>) -> SVector<T, N> {
+        let mut data = self.data;
+        for i in 0..N {
+            data[i] = data[i] - other.data[i];
+        }
+        SVector { data }
+    }
+}
+
+/// Static numeric vector elementwise multiplication. Only defined when
+/// both operands share the same `N`, so a length mismatch is a compile
+/// error, not a panic.
+///
+/// # Examples
+///
+/// ```
+/// # use crabsformer::svector::SVector;
+/// let a = SVector::new([3, 1, 4]);
+/// let b = SVector::new([1, 5, 9]);
+/// assert_eq!(a * b, SVector::new([3, 5, 36]));
+/// ```
+impl<T, const N: usize> ops::Mul for SVector<T, N>
+where
+    T: Num + Copy,
+{
+    type Output = SVector<T, N>;
+
+    fn mul(self, other: SVector<T, N>) -> SVector<T, N> {
+        let mut data = self.data;
+        for i in 0..N {
+            data[i] = data[i] * other.data[i];
+        }
+        SVector { data }
+    }
+}
+
+/// Converts a static numeric vector into a dynamic [`Vector`]. Always
+/// succeeds, since a fixed length is also a valid dynamic length.
+///
+/// [`Vector`]: ../vector/struct.Vector.html
+impl<T, const N: usize> From<SVector<T, N>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    fn from(v: SVector<T, N>) -> Vector<T> {
+        Vector::from(v.data.to_vec())
+    }
+}
+
+/// Converts a dynamic [`Vector`] into a static [`SVector<T, N>`], failing
+/// with [`CrabsformerError::ShapeMismatch`] if its length isn't `N`.
+///
+/// [`Vector`]: ../vector/struct.Vector.html
+/// [`SVector<T, N>`]: struct.SVector.html
+/// [`CrabsformerError::ShapeMismatch`]: ../error/enum.CrabsformerError.html#variant.ShapeMismatch
+impl<T, const N: usize> TryFrom<Vector<T>> for SVector<T, N>
+where
+    T: Num + Copy,
+{
+    type Error = CrabsformerError;
+
+    fn try_from(v: Vector<T>) -> Result<SVector<T, N>, CrabsformerError> {
+        if v.len() != N {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![v.len()],
+                rhs: vec![N],
+            });
+        }
+        let mut data = [T::zero(); N];
+        for (i, x) in v.elements().enumerate() {
+            data[i] = *x;
+        }
+        Ok(SVector { data })
+    }
+}
+
+/// Creates a [static numeric vector] containing the arguments, with its
+/// length `N` fixed at compile time.
+///
+/// `svector!` allows a static numeric vector to be defined with the same
+/// syntax as array expressions.
+///
+/// # Examples
+///
+/// ```
+/// # use crabsformer::svector;
+/// let v = svector![3, 1, 4];
+/// assert_eq!(v.len(), 3);
+/// ```
+///
+/// [static numeric vector]: svector/struct.SVector.html
+#[macro_export]
+macro_rules! svector {
+    ($($x:expr),* $(,)?) => {{
+        $crate::svector::SVector::new([$($x),*])
+    }};
+}
@@ -40,6 +40,229 @@ implement_type_name_for_type!(u128, "u128");
 implement_type_name_for_type!(f32, "f32");
 implement_type_name_for_type!(f64, "f64");
 
+/// Elements that can be converted to and from a fixed-width little-endian
+/// byte representation, used by the binary `.npy`-style matrix format.
+pub trait LittleEndianBytes: Sized {
+    /// Number of bytes used to represent a single element.
+    const WIDTH: usize;
+
+    /// Encode `self` as little-endian bytes.
+    fn to_le_bytes_vec(&self) -> Vec<u8>;
+
+    /// Decode an element from a little-endian byte slice of length
+    /// [`WIDTH`](#associatedconstant.WIDTH).
+    fn from_le_bytes_slice(bytes: &[u8]) -> Self;
+}
+
+macro_rules! implement_little_endian_bytes_for_type {
+    ($t: ty) => {
+        impl LittleEndianBytes for $t {
+            const WIDTH: usize = std::mem::size_of::<$t>();
+
+            fn to_le_bytes_vec(&self) -> Vec<u8> {
+                self.to_le_bytes().to_vec()
+            }
+
+            fn from_le_bytes_slice(bytes: &[u8]) -> Self {
+                let mut buf = [0u8; std::mem::size_of::<$t>()];
+                buf.copy_from_slice(bytes);
+                <$t>::from_le_bytes(buf)
+            }
+        }
+    };
+}
+
+implement_little_endian_bytes_for_type!(usize);
+implement_little_endian_bytes_for_type!(i8);
+implement_little_endian_bytes_for_type!(i16);
+implement_little_endian_bytes_for_type!(i32);
+implement_little_endian_bytes_for_type!(i64);
+implement_little_endian_bytes_for_type!(i128);
+implement_little_endian_bytes_for_type!(u8);
+implement_little_endian_bytes_for_type!(u16);
+implement_little_endian_bytes_for_type!(u32);
+implement_little_endian_bytes_for_type!(u64);
+implement_little_endian_bytes_for_type!(u128);
+implement_little_endian_bytes_for_type!(f32);
+implement_little_endian_bytes_for_type!(f64);
+
+/// NumPy "array-protocol type string" for an element type, e.g. `<f8` for
+/// `f64` or `<i4` for `i32`: `<` for little-endian, a one-letter kind
+/// (`f` float, `i` signed int, `u` unsigned int), then the element width
+/// in bytes. Used by the `.npy` reader/writer to detect a dtype mismatch
+/// instead of silently misinterpreting the file's bytes.
+pub trait NumpyDescr {
+    const DESCR: &'static str;
+}
+
+macro_rules! implement_numpy_descr_for_type {
+    ($t: ty, $descr: expr) => {
+        impl NumpyDescr for $t {
+            const DESCR: &'static str = $descr;
+        }
+    };
+}
+
+implement_numpy_descr_for_type!(usize, "<u8");
+implement_numpy_descr_for_type!(i8, "<i1");
+implement_numpy_descr_for_type!(i16, "<i2");
+implement_numpy_descr_for_type!(i32, "<i4");
+implement_numpy_descr_for_type!(i64, "<i8");
+implement_numpy_descr_for_type!(i128, "<i16");
+implement_numpy_descr_for_type!(u8, "<u1");
+implement_numpy_descr_for_type!(u16, "<u2");
+implement_numpy_descr_for_type!(u32, "<u4");
+implement_numpy_descr_for_type!(u64, "<u8");
+implement_numpy_descr_for_type!(u128, "<u16");
+implement_numpy_descr_for_type!(f32, "<f4");
+implement_numpy_descr_for_type!(f64, "<f8");
+
+// Shared building blocks for the NumPy `.npy` reader/writer used by both
+// `Vector::{save_npy, load_npy}` and `Matrix::{save_npy, load_npy}`, so the
+// two don't duplicate the header format.
+const NPY_MAGIC: &[u8; 6] = b"\x93NUMPY";
+const NPY_VERSION_MAJOR: u8 = 1;
+const NPY_VERSION_MINOR: u8 = 0;
+// NumPy pads the header so that the data section starts on a 64-byte
+// boundary.
+const NPY_ALIGNMENT: usize = 64;
+
+fn npy_shape_literal(shape: &[usize]) -> String {
+    match shape {
+        [n] => format!("({},)", n),
+        _ => format!(
+            "({})",
+            shape
+                .iter()
+                .map(|d| d.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+/// Write a `.npy` magic, version and header dict (`descr`, `fortran_order:
+/// False`, `shape`) to `writer`, padded per the NumPy spec so the element
+/// data that follows starts on a 64-byte boundary.
+pub(crate) fn write_npy_header<W: std::io::Write>(
+    writer: &mut W,
+    descr: &str,
+    shape: &[usize],
+) -> std::io::Result<()> {
+    let dict = format!(
+        "{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}",
+        descr,
+        npy_shape_literal(shape)
+    );
+    let prefix_len = NPY_MAGIC.len() + 2 + 2;
+    let unpadded_len = prefix_len + dict.len() + 1;
+    let padded_len =
+        (unpadded_len + NPY_ALIGNMENT - 1) / NPY_ALIGNMENT * NPY_ALIGNMENT;
+
+    let mut header = dict.into_bytes();
+    header.resize(padded_len - prefix_len - 1, b' ');
+    header.push(b'\n');
+
+    writer.write_all(NPY_MAGIC)?;
+    writer.write_all(&[NPY_VERSION_MAJOR, NPY_VERSION_MINOR])?;
+    writer.write_all(&(header.len() as u16).to_le_bytes())?;
+    writer.write_all(&header)
+}
+
+// Find the value of `'key': '...'` in a NumPy header dict.
+fn extract_quoted_value(header: &str, key: &str) -> Result<String, String> {
+    let needle = format!("'{}':", key);
+    let pos = header
+        .find(&needle)
+        .ok_or_else(|| format!("missing '{}' key", key))?;
+    let rest = &header[pos + needle.len()..];
+    let start = rest
+        .find('\'')
+        .ok_or_else(|| format!("malformed '{}' value", key))?;
+    let rest = &rest[start + 1..];
+    let end = rest
+        .find('\'')
+        .ok_or_else(|| format!("malformed '{}' value", key))?;
+    Ok(rest[..end].to_string())
+}
+
+// Find the value of `'key': (...)` in a NumPy header dict.
+fn extract_paren_value(header: &str, key: &str) -> Result<String, String> {
+    let needle = format!("'{}':", key);
+    let pos = header
+        .find(&needle)
+        .ok_or_else(|| format!("missing '{}' key", key))?;
+    let rest = &header[pos + needle.len()..];
+    let start = rest
+        .find('(')
+        .ok_or_else(|| format!("malformed '{}' value", key))?;
+    let rest = &rest[start + 1..];
+    let end = rest
+        .find(')')
+        .ok_or_else(|| format!("malformed '{}' value", key))?;
+    Ok(rest[..end].to_string())
+}
+
+/// Parse the `.npy` magic, version and header dict at the start of `buf`,
+/// check that `descr` matches `T`, and return the parsed `shape` together
+/// with the remaining bytes (the raw element data).
+///
+/// # Errors
+/// Returns a human-readable message (not a crate error type, so both
+/// `Vector::load_npy` and `Matrix::load_npy` can wrap it into their own
+/// `*LoadError`) if the magic, version, dtype or header are invalid.
+pub(crate) fn read_npy_header<'a, T: NumpyDescr>(
+    buf: &'a [u8],
+) -> Result<(Vec<usize>, &'a [u8]), String> {
+    if buf.len() < NPY_MAGIC.len() + 4 || &buf[..NPY_MAGIC.len()] != NPY_MAGIC
+    {
+        return Err(String::from("bad magic bytes"));
+    }
+    let mut offset = NPY_MAGIC.len();
+    let major = buf[offset];
+    offset += 2;
+    if major != NPY_VERSION_MAJOR {
+        return Err(format!("unsupported format version: {}", major));
+    }
+
+    let header_len =
+        u16::from_le_bytes([buf[offset], buf[offset + 1]]) as usize;
+    offset += 2;
+    if buf.len() < offset + header_len {
+        return Err(String::from(
+            "file is too small to contain the declared header",
+        ));
+    }
+    let header = std::str::from_utf8(&buf[offset..offset + header_len])
+        .map_err(|_| String::from("header is not valid utf-8"))?;
+    offset += header_len;
+
+    let descr = extract_quoted_value(header, "descr")?;
+    if descr != T::DESCR {
+        return Err(format!(
+            "dtype mismatch: file contains {:?}, expected {:?}",
+            descr,
+            T::DESCR
+        ));
+    }
+    if header.contains("'fortran_order': True") {
+        return Err(String::from("fortran-order arrays are not supported"));
+    }
+
+    let shape_str = extract_paren_value(header, "shape")?;
+    let shape = shape_str
+        .split(',')
+        .map(|s| s.trim())
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            s.parse::<usize>()
+                .map_err(|_| format!("invalid shape entry: {:?}", s))
+        })
+        .collect::<Result<Vec<usize>, String>>()?;
+
+    Ok((shape, &buf[offset..]))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -60,4 +283,49 @@ mod tests {
         assert_eq!(<f32>::type_name(), "f32");
         assert_eq!(<f64>::type_name(), "f64");
     }
+
+    #[test]
+    fn test_little_endian_bytes_round_trip() {
+        assert_eq!(i32::WIDTH, 4);
+        assert_eq!(i32::from_le_bytes_slice(&(-7i32).to_le_bytes_vec()), -7);
+
+        assert_eq!(f64::WIDTH, 8);
+        assert_eq!(f64::from_le_bytes_slice(&(3.14f64).to_le_bytes_vec()), 3.14);
+
+        assert_eq!(u8::WIDTH, 1);
+        assert_eq!(u8::from_le_bytes_slice(&(255u8).to_le_bytes_vec()), 255);
+    }
+
+    #[test]
+    fn test_numpy_descr() {
+        assert_eq!(f32::DESCR, "<f4");
+        assert_eq!(f64::DESCR, "<f8");
+        assert_eq!(i32::DESCR, "<i4");
+        assert_eq!(u8::DESCR, "<u1");
+    }
+
+    #[test]
+    fn test_npy_header_round_trip() {
+        let mut buf = Vec::new();
+        write_npy_header(&mut buf, f64::DESCR, &[3, 4]).unwrap();
+        // The data section must start on a 64-byte boundary.
+        assert_eq!(buf.len() % 64, 0);
+
+        let (shape, data) = read_npy_header::<f64>(&buf).unwrap();
+        assert_eq!(shape, vec![3, 4]);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn test_npy_header_rejects_dtype_mismatch() {
+        let mut buf = Vec::new();
+        write_npy_header(&mut buf, f64::DESCR, &[3]).unwrap();
+        assert!(read_npy_header::<f32>(&buf).is_err());
+    }
+
+    #[test]
+    fn test_npy_shape_literal() {
+        assert_eq!(npy_shape_literal(&[6]), "(6,)");
+        assert_eq!(npy_shape_literal(&[3, 4]), "(3, 4)");
+    }
 }
@@ -21,6 +21,10 @@
 //!
 
 use num::Num;
+#[cfg(feature = "serde")]
+use serde::de::{Deserialize, Deserializer};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, Serializer};
 use std::fmt;
 
 // Import all sub modules
@@ -30,7 +34,11 @@ pub mod indexing;
 pub mod iterators;
 pub mod loaders;
 pub mod operations;
+#[cfg(feature = "proptest")]
+pub mod proptest;
 pub mod slicing;
+pub mod sparse;
+pub mod transcendental;
 
 /// Numeric vectors.
 ///
@@ -90,6 +98,26 @@ where
     pub fn len(&self) -> usize {
         self.data.len()
     }
+
+    /// Borrows the underlying elements as a contiguous slice.
+    ///
+    /// This is crate-internal plumbing for code that needs raw, contiguous
+    /// access (e.g. the `blas` feature's GEMM dispatch); use [`elements`]
+    /// for the public iterator-based API.
+    ///
+    /// [`elements`]: vector/iterators/struct.VectorElementIterator.html
+    pub(crate) fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Borrows the underlying elements as a contiguous mutable slice.
+    ///
+    /// This is crate-internal plumbing for code that needs raw,
+    /// contiguous mutable access (e.g. `Matrix::apply`/`zip_apply` and
+    /// their row/column views).
+    pub(crate) fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
 }
 
 // Numeric vector comparison
@@ -190,5 +218,50 @@ where
     }
 }
 
+/// Mutable counterpart of [`SubVector`], a view over contiguous elements
+/// of the numeric vector that writes back into the parent vector.
+///
+/// [`SubVector`]: struct.SubVector.html
+pub struct SubVectorMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // Offset sub numeric vector from the start of the vector
+    offset: usize,
+    // The size of the sub numeric vector
+    size: usize,
+    // The original numeric vector; where to get and write the elements to
+    source: &'a mut Vector<T>,
+}
+
+// Numeric vector is serialized as a plain sequence of its elements, so that
+// the JSON representation of `vector![3, 1, 4]` is simply `[3, 1, 4]`.
+#[cfg(feature = "serde")]
+impl<T> Serialize for Vector<T>
+where
+    T: Num + Copy + Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.data.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T> Deserialize<'de> for Vector<T>
+where
+    T: Num + Copy + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = Vec::<T>::deserialize(deserializer)?;
+        Ok(Vector { data })
+    }
+}
+
 // TODO: implement exponent operator
 // TODO: implement all operators https://www.tutorialspoint.com/numpy/numpy_arithmetic_operations.htm
@@ -135,15 +135,59 @@
 //!         values.
 //!     - [`Vector::linspace`]: Create a new numeric vector of the given length
 //!          and populate it with linearly spaced values.
-//!     - `Vector::logspace` ([#20][issue-20]): Create a new numeric vector of
-//!         the given length and populate it with logarithmically spaced values.
-//!     - `Vector::geomspace` ([#21][issue-21]): Create a new numeric vector of
-//!         the given length and populate it with evenly spaced values on a log
-//!         scale (a geometric progression).
+//!     - [`Vector::logspace`]: Create a new numeric vector of the given
+//!         length and populate it with logarithmically spaced values.
+//!     - [`Vector::geomspace`]: Create a new numeric vector of the given
+//!         length and populate it with evenly spaced values on a log scale
+//!         (a geometric progression).
 //!
-//! - Simple random data
-//! - Permutations
-//! - Distributions
+//! 4. **Random sampling**
+//!     - [`RandomVectorBuilder::uniform`]: Draw samples from a uniform
+//!         distribution.
+//!     - [`RandomVectorBuilder::uniform_with_rng`]: Like `uniform`, but
+//!         draws from a caller-supplied `rand::Rng` for reproducible
+//!         results.
+//!     - [`RandomVectorBuilder::bytes`]: Fill a numeric vector with
+//!         uniformly random bytes via a single buffered RNG call.
+//!     - [`RandomVectorBuilder::normal`]: Draw samples from a normal
+//!         (Gaussian) distribution.
+//!     - [`RandomVectorBuilder::normal_with_rng`]: Like `normal`, but
+//!         draws from a caller-supplied `rand::Rng` for reproducible
+//!         results.
+//!     - [`RandomVectorBuilder::standard_normal`]: Draw samples from the
+//!         standard normal distribution `N(0, 1)`.
+//!     - [`RandomVectorBuilder::exponential`]: Draw samples from an
+//!         exponential distribution.
+//!     - [`RandomVectorBuilder::gamma`]: Draw samples from a gamma
+//!         distribution.
+//!     - [`RandomVectorBuilder::lognormal`]: Draw samples from a
+//!         log-normal distribution.
+//!     - [`RandomVectorBuilder::poisson`]: Draw samples from a Poisson
+//!         distribution.
+//!     - [`RandomVectorBuilder::binomial`]: Draw samples from a binomial
+//!         distribution.
+//!     - [`RandomVectorBuilder::bernoulli`]: Draw samples from a
+//!         Bernoulli distribution.
+//!     - [`RandomVectorBuilder::cauchy`]: Draw samples from a Cauchy
+//!         distribution.
+//!     - [`RandomVectorBuilder::pareto`]: Draw samples from a Pareto
+//!         distribution.
+//!     - [`RandomVectorBuilder::weibull`]: Draw samples from a Weibull
+//!         distribution.
+//!     - [`RandomVectorBuilder::triangular`]: Draw samples from a
+//!         triangular distribution.
+//!     - [`RandomVectorBuilder::choice_weighted`]: Draw elements from a
+//!         source vector with probabilities proportional to given weights.
+//!     - [`RandomVectorBuilder::dirichlet`]: Draw a single vector from a
+//!         Dirichlet distribution.
+//!
+//! 5. **Permutations**
+//!     - [`RandomVectorBuilder::permutation`]: Create a random permutation
+//!         of `0..n`.
+//!     - [`RandomVectorBuilder::shuffle`]: Randomly reorder an existing
+//!         numeric vector in place.
+//!     - [`RandomVectorBuilder::permuted`]: Create a randomly reordered
+//!         copy of a numeric vector.
 //!
 //! [`Vector::copy`]: ../struct.Vector.html#method.copy
 //! [`Vector::zeros`]: ../struct.Vector.html#method.zeros
@@ -154,17 +198,38 @@
 //! [`Vector::full_like`]: ../struct.Vector.html#method.full_like
 //! [`Vector::range`]: ../struct.Vector.html#method.range
 //! [`Vector::linspace`]: ../struct.Vector.html#method.linspace
-//! [issue-20]: https://github.com/pyk/Crabsformer/issues/20
-//! [issue-21]: https://github.com/pyk/Crabsformer/issues/21
+//! [`Vector::logspace`]: ../struct.Vector.html#method.logspace
+//! [`Vector::geomspace`]: ../struct.Vector.html#method.geomspace
+//! [`RandomVectorBuilder::uniform`]: struct.RandomVectorBuilder.html#method.uniform
+//! [`RandomVectorBuilder::uniform_with_rng`]: struct.RandomVectorBuilder.html#method.uniform_with_rng
+//! [`RandomVectorBuilder::bytes`]: struct.RandomVectorBuilder.html#method.bytes
+//! [`RandomVectorBuilder::normal`]: struct.RandomVectorBuilder.html#method.normal
+//! [`RandomVectorBuilder::normal_with_rng`]: struct.RandomVectorBuilder.html#method.normal_with_rng
+//! [`RandomVectorBuilder::standard_normal`]: struct.RandomVectorBuilder.html#method.standard_normal
+//! [`RandomVectorBuilder::exponential`]: struct.RandomVectorBuilder.html#method.exponential
+//! [`RandomVectorBuilder::gamma`]: struct.RandomVectorBuilder.html#method.gamma
+//! [`RandomVectorBuilder::lognormal`]: struct.RandomVectorBuilder.html#method.lognormal
+//! [`RandomVectorBuilder::poisson`]: struct.RandomVectorBuilder.html#method.poisson
+//! [`RandomVectorBuilder::binomial`]: struct.RandomVectorBuilder.html#method.binomial
+//! [`RandomVectorBuilder::bernoulli`]: struct.RandomVectorBuilder.html#method.bernoulli
+//! [`RandomVectorBuilder::cauchy`]: struct.RandomVectorBuilder.html#method.cauchy
+//! [`RandomVectorBuilder::pareto`]: struct.RandomVectorBuilder.html#method.pareto
+//! [`RandomVectorBuilder::weibull`]: struct.RandomVectorBuilder.html#method.weibull
+//! [`RandomVectorBuilder::triangular`]: struct.RandomVectorBuilder.html#method.triangular
+//! [`RandomVectorBuilder::choice_weighted`]: struct.RandomVectorBuilder.html#method.choice_weighted
+//! [`RandomVectorBuilder::dirichlet`]: struct.RandomVectorBuilder.html#method.dirichlet
+//! [`RandomVectorBuilder::permutation`]: struct.RandomVectorBuilder.html#method.permutation
+//! [`RandomVectorBuilder::shuffle`]: struct.RandomVectorBuilder.html#method.shuffle
+//! [`RandomVectorBuilder::permuted`]: struct.RandomVectorBuilder.html#method.permuted
 //!
 
 use crate::vector::errors::{VectorBuilderError, VectorBuilderErrorKind};
 use crate::vector::Vector;
-use num::{Float, FromPrimitive, Num};
+use num::{Float, FromPrimitive, Num, ToPrimitive};
 use rand::distributions::uniform::SampleUniform;
-use rand::distributions::{Distribution, Normal, Uniform};
-use rand::{FromEntropy, SeedableRng};
+use rand::distributions::{Distribution, Uniform};
 use rand::rngs::SmallRng;
+use rand::{FromEntropy, Rng, RngCore, SeedableRng};
 use std::fmt;
 use std::ops;
 
@@ -263,6 +328,18 @@ where
     }
 }
 
+// Collect an iterator of elements into a numeric vector, so a stream of
+// values produced by an iterator pipeline can be folded directly into a
+// `Vector<T>` via `.collect()`.
+impl<T> std::iter::FromIterator<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
 impl<T> Vector<T>
 where
     T: Num + Copy,
@@ -447,6 +524,9 @@ where
     /// with linearly spaced values within a given closed interval `[start,
     /// stop]`.
     ///
+    /// **Note that**: If `len == 1` it returns a single-element vector
+    /// containing just `start`, matching NumPy's `linspace`.
+    ///
     /// # Examples
     /// ```
     /// # use crabsformer::prelude::*;
@@ -456,6 +536,14 @@ where
     where
         T: Float,
     {
+        // With a single point there's no step to take; NumPy returns
+        // just the start of the interval in this case.
+        if len == 1 {
+            return Vector {
+                data: vec![start],
+            };
+        }
+
         // Convert len to float type
         let divisor = T::from_usize(len).unwrap();
         let mut elements = Vec::with_capacity(len);
@@ -487,14 +575,14 @@ where
     ///
     /// ```
     /// # use crabsformer::prelude::*;
-    /// // TODO(pyk): Uncomment this if the function is already implemented
-    /// // let a = Vector::logspace(5, 2.0, 3.0);
+    /// let a = Vector::logspace(5, 2.0, 3.0);
     /// ```
-    pub fn logspace(_len: usize, _a: T, _b: T) -> Vector<T>
+    pub fn logspace(len: usize, a: T, b: T) -> Vector<T>
     where
         T: Float,
     {
-        unimplemented!();
+        let base = T::from_f32(10.0).unwrap();
+        Vector::linspace(len, a, b).map(|exponent| base.powf(exponent))
     }
 
     /// Create a new numeric vector of the given length `len` and populate it
@@ -504,20 +592,82 @@ where
     /// This is similar to `Vector::logspace`, but with endpoints specified
     /// directly. Each output sample is a constant multiple of the previous.
     ///
+    /// **Note that**: `start` and `end` must be nonzero and share the same
+    /// sign, since no real geometric progression crosses or touches zero.
+    ///
     /// # Examples
     ///
     /// ```
     /// # use crabsformer::prelude::*;
-    /// // TODO(pyk): Uncomment this if the function is already implemented
-    /// // let a = Vector::geomspace(5, 100.0, 1000.0);
     /// // similar to:
     /// // let b = Vector::logspace(5, 2.0, 3.0);
+    /// let a = Vector::geomspace(5, 100.0, 1000.0);
     /// ```
-    pub fn geomspace(_len: usize, _start: T, _end: T) -> Vector<T>
+    pub fn geomspace(
+        len: usize,
+        start: T,
+        end: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
     where
         T: Float,
     {
-        unimplemented!();
+        let zero = T::from_i32(0).unwrap();
+        if start == zero || end == zero {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                "the start and end value should not equal to zero".to_string(),
+            ));
+        }
+        if (start > zero) != (end > zero) {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                "the start and end value should have the same sign"
+                    .to_string(),
+            ));
+        }
+
+        // With a single point there's no ratio to take; mirrors
+        // `linspace`'s `len == 1` case.
+        if len == 1 {
+            return Ok(Vector { data: vec![start] });
+        }
+
+        let divisor = T::from_usize(len - 1).unwrap();
+        let ratio = (end / start).powf(T::from_f32(1.0).unwrap() / divisor);
+        let mut elements = Vec::with_capacity(len);
+        let mut current = start;
+        for _ in 0..len {
+            elements.push(current);
+            current = current * ratio;
+        }
+
+        // Pin the last element exactly to `end` to avoid float drift,
+        // matching how `linspace` pins its final element.
+        elements[len - 1] = end;
+
+        Ok(Vector { data: elements })
+    }
+}
+
+// Draw one sample from the standard normal distribution `N(0, 1)` via the
+// Box-Muller transform, using the given `rng`. Shared by
+// `RandomVectorBuilder::sample_standard_normal` and `normal_with_rng` so the
+// formula only lives in one place.
+fn sample_standard_normal_with_rng<R: Rng>(
+    rng: &mut R,
+    cached_z1: &mut Option<f64>,
+) -> f64 {
+    match cached_z1.take() {
+        Some(z1) => z1,
+        None => {
+            let unit_uniform = Uniform::new(0.0f64, 1.0);
+            let u1: f64 = unit_uniform.sample(rng);
+            let u2: f64 = unit_uniform.sample(rng);
+            let radius = (-2.0 * u1.ln()).sqrt();
+            let theta = 2.0 * ::std::f64::consts::PI * u2;
+            *cached_z1 = Some(radius * theta.sin());
+            radius * theta.cos()
+        }
     }
 }
 
@@ -589,11 +739,81 @@ impl RandomVectorBuilder {
         Ok(Vector::from(elements))
     }
 
+    /// Create a new numeric vector of the given length `len`, populated with
+    /// random samples from a uniform distribution over the half-open
+    /// interval `[low, high)`, drawing from the given `rng` instead of this
+    /// builder's own seeded generator. This lets callers pass any
+    /// `rand::Rng` (e.g. a seeded ISAAC or Xorshift generator) to get
+    /// identical vectors across runs.
+    ///
+    /// **Note that**: If `low >= high` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(12);
+    /// let v = RandomVectorBuilder::uniform_with_rng(5, 0.0, 1.0, &mut rng).unwrap();
+    /// ```
+    pub fn uniform_with_rng<T, R>(
+        len: usize,
+        low: T,
+        high: T,
+        rng: &mut R,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy + SampleUniform + PartialOrd + fmt::Display,
+        R: Rng,
+    {
+        if low >= high {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                format!("low={} should less than high={}", low, high),
+            ));
+        }
+
+        let mut elements = Vec::with_capacity(len);
+        let uniform_distribution = Uniform::new(low, high);
+        for _ in 0..len {
+            elements.push(uniform_distribution.sample(rng));
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len`, filled with
+    /// uniformly random bytes drawn in a single buffered call rather than
+    /// `len` individual trait dispatches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.bytes(1_000_000);
+    /// assert_eq!(v.len(), 1_000_000);
+    /// ```
+    pub fn bytes(&mut self, len: usize) -> Vector<u8> {
+        let mut data = Vec::with_capacity(len);
+        // Safe: the buffer is immediately filled below, and `u8` has no
+        // invalid bit patterns, so the uninitialized memory is never
+        // observed.
+        unsafe {
+            data.set_len(len);
+        }
+        self.rng.fill_bytes(&mut data);
+        Vector::from(data)
+    }
+
     /// Create a new numeric vector of the given length `len` and populate it
-    /// with random samples from a normal distribution `N(mean, std_dev**2)`.
+    /// with random samples from a normal distribution `N(mean, std_dev**2)`,
+    /// computed via the [Box-Muller transform].
     ///
     /// **Note that**: If `std_dev < 0` it will returns an error.
     ///
+    /// [Box-Muller transform]: https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform
+    ///
     /// # Examples
     /// ```
     /// # use crabsformer::prelude::*;
@@ -601,24 +821,802 @@ impl RandomVectorBuilder {
     /// // Gaussian mean=0.0 std_dev=1.0
     /// let v = rvb.normal(5, 0.0, 1.0).unwrap();
     /// ```
-    pub fn normal(
+    pub fn normal<T>(
         &mut self,
         len: usize,
-        mean: f64,
-        std_dev: f64,
-    ) -> Result<Vector<f64>, VectorBuilderError> {
-        if std_dev < 0.0 {
+        mean: T,
+        std_dev: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+    {
+        if std_dev < T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::NegativeStandardDeviation,
+                format!("{}", std_dev),
+            ));
+        }
+        let mut elements = Vec::with_capacity(len);
+        let mut cached_z1 = None;
+        for _ in 0..len {
+            let z = T::from_f64(self.sample_standard_normal(&mut cached_z1)).unwrap();
+            elements.push(mean + std_dev * z);
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate
+    /// it with random samples from a normal distribution `N(mean,
+    /// std_dev**2)`, drawing from the given `rng` instead of this builder's
+    /// own seeded generator. See [`uniform_with_rng`] for why one would
+    /// want to do that.
+    ///
+    /// **Note that**: If `std_dev < 0` it will returns an error.
+    ///
+    /// [`uniform_with_rng`]: #method.uniform_with_rng
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::SmallRng;
+    ///
+    /// let mut rng = SmallRng::seed_from_u64(12);
+    /// let v = RandomVectorBuilder::normal_with_rng(5, 0.0, 1.0, &mut rng).unwrap();
+    /// ```
+    pub fn normal_with_rng<T, R>(
+        len: usize,
+        mean: T,
+        std_dev: T,
+        rng: &mut R,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + fmt::Display,
+        R: Rng,
+    {
+        if std_dev < T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::NegativeStandardDeviation,
+                format!("{}", std_dev),
+            ));
+        }
+        let mut elements = Vec::with_capacity(len);
+        let mut cached_z1 = None;
+        for _ in 0..len {
+            let z = T::from_f64(sample_standard_normal_with_rng(rng, &mut cached_z1))
+                .unwrap();
+            elements.push(mean + std_dev * z);
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from the standard normal distribution `N(0, 1)`.
+    /// Equivalent to `normal(len, 0.0, 1.0)`, but infallible since there is
+    /// no `std_dev` to validate.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v: Vector<f64> = rvb.standard_normal(5);
+    /// ```
+    pub fn standard_normal<T>(&mut self, len: usize) -> Vector<T>
+    where
+        T: Float + FromPrimitive,
+    {
+        let mut elements = Vec::with_capacity(len);
+        let mut cached_z1 = None;
+        for _ in 0..len {
+            let z = self.sample_standard_normal(&mut cached_z1);
+            elements.push(T::from_f64(z).unwrap());
+        }
+
+        Vector::from(elements)
+    }
+
+    /// Draw one sample from the standard normal distribution `N(0, 1)`
+    /// via the [Box-Muller transform]. Each transform produces a pair of
+    /// independent samples, so the second one is cached in `cached_z1` and
+    /// handed back on the following call instead of drawing two fresh
+    /// uniform samples every time.
+    ///
+    /// [Box-Muller transform]: https://en.wikipedia.org/wiki/Box%E2%80%93Muller_transform
+    fn sample_standard_normal(&mut self, cached_z1: &mut Option<f64>) -> f64 {
+        sample_standard_normal_with_rng(&mut self.rng, cached_z1)
+    }
+
+    /// Draw one sample from the standard gamma distribution `Gamma(shape,
+    /// 1)` via the Marsaglia & Tsang method. For `shape < 1`, samples
+    /// `Gamma(shape + 1, 1)` and applies the standard boost correction
+    /// (`x * u.powf(1 / shape)`) to account for the shift.
+    fn sample_standard_gamma(
+        &mut self,
+        shape: f64,
+        cached_z1: &mut Option<f64>,
+    ) -> f64 {
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let (d, boost) = if shape < 1.0 {
+            (shape + 1.0 - 1.0 / 3.0, Some(shape))
+        } else {
+            (shape - 1.0 / 3.0, None)
+        };
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let mut x;
+            let mut v;
+            loop {
+                x = self.sample_standard_normal(cached_z1);
+                v = 1.0 + c * x;
+                if v > 0.0 {
+                    break;
+                }
+            }
+            let v3 = v * v * v;
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            if u < 1.0 - 0.0331 * x * x * x * x
+                || u.ln() < 0.5 * x * x + d * (1.0 - v3 + v3.ln())
+            {
+                let sample = d * v3;
+                return match boost {
+                    Some(shape) => {
+                        let u2: f64 = unit_uniform.sample(&mut self.rng);
+                        sample * u2.powf(1.0 / shape)
+                    }
+                    None => sample,
+                };
+            }
+        }
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from an exponential distribution with rate
+    /// `lambda`, drawn via inverse transform sampling.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.exponential(5, 1.0).unwrap();
+    /// ```
+    pub fn exponential<T>(
+        &mut self,
+        len: usize,
+        lambda: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if lambda <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("lambda={} should be positive", lambda),
+            ));
+        }
+
+        let lambda = lambda.to_f64().unwrap();
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            // `1 - u` keeps the argument of `ln` in `(0, 1]`, never 0.
+            let x = -(1.0 - u).ln() / lambda;
+            elements.push(T::from_f64(x).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a gamma distribution with the given `shape`
+    /// (k) and `scale` (theta), drawn via the Marsaglia & Tsang method.
+    ///
+    /// **Note that**: If `shape <= 0` or `scale <= 0` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.gamma(5, 2.0, 1.0).unwrap();
+    /// ```
+    pub fn gamma<T>(
+        &mut self,
+        len: usize,
+        shape: T,
+        scale: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if shape <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("shape={} should be positive", shape),
+            ));
+        }
+        if scale <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("scale={} should be positive", scale),
+            ));
+        }
+
+        let shape = shape.to_f64().unwrap();
+        let scale = scale.to_f64().unwrap();
+        let mut elements = Vec::with_capacity(len);
+        let mut cached_z1 = None;
+        for _ in 0..len {
+            let x = self.sample_standard_gamma(shape, &mut cached_z1);
+            elements.push(T::from_f64(x * scale).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a log-normal distribution, i.e. `exp(X)`
+    /// where `X ~ N(mean, std_dev**2)`.
+    ///
+    /// **Note that**: If `std_dev < 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.lognormal(5, 0.0, 1.0).unwrap();
+    /// ```
+    pub fn lognormal<T>(
+        &mut self,
+        len: usize,
+        mean: T,
+        std_dev: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if std_dev < T::zero() {
             return Err(VectorBuilderError::new(
                 VectorBuilderErrorKind::NegativeStandardDeviation,
                 format!("{}", std_dev),
             ));
         }
+
+        let mean = mean.to_f64().unwrap();
+        let std_dev = std_dev.to_f64().unwrap();
         let mut elements = Vec::with_capacity(len);
-        let normal_distribution = Normal::new(mean, std_dev);
+        let mut cached_z1 = None;
         for _ in 0..len {
-            elements.push(normal_distribution.sample(&mut self.rng));
+            let z = self.sample_standard_normal(&mut cached_z1);
+            let x = (mean + std_dev * z).exp();
+            elements.push(T::from_f64(x).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Poisson distribution with rate `lambda`,
+    /// drawn via Knuth's algorithm.
+    ///
+    /// **Note that**: If `lambda <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v: Vector<u32> = rvb.poisson(5, 4.0).unwrap();
+    /// ```
+    pub fn poisson<T>(
+        &mut self,
+        len: usize,
+        lambda: f64,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        if lambda <= 0.0 {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("lambda={} should be positive", lambda),
+            ));
+        }
+
+        let threshold = (-lambda).exp();
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let mut k: u64 = 0;
+            let mut p = 1.0;
+            loop {
+                let u: f64 = unit_uniform.sample(&mut self.rng);
+                p *= u;
+                if p <= threshold {
+                    break;
+                }
+                k += 1;
+            }
+            elements.push(T::from_u64(k).unwrap());
         }
 
         Ok(Vector::from(elements))
     }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a binomial distribution of `n` trials with
+    /// success probability `p`, drawn by summing `n` Bernoulli trials.
+    ///
+    /// **Note that**: If `p` is not within `[0, 1]` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v: Vector<u32> = rvb.binomial(5, 10, 0.5).unwrap();
+    /// ```
+    pub fn binomial<T>(
+        &mut self,
+        len: usize,
+        n: u64,
+        p: f64,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        if p < 0.0 || p > 1.0 {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("p={} should be within [0, 1]", p),
+            ));
+        }
+
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let mut successes: u64 = 0;
+            for _ in 0..n {
+                let u: f64 = unit_uniform.sample(&mut self.rng);
+                if u < p {
+                    successes += 1;
+                }
+            }
+            elements.push(T::from_u64(successes).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Bernoulli distribution, i.e. `1` with
+    /// probability `p` and `0` otherwise. Equivalent to `binomial(len, 1,
+    /// p)`.
+    ///
+    /// **Note that**: If `p` is not within `[0, 1]` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v: Vector<u32> = rvb.bernoulli(5, 0.5).unwrap();
+    /// ```
+    pub fn bernoulli<T>(
+        &mut self,
+        len: usize,
+        p: f64,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy + FromPrimitive,
+    {
+        if p < 0.0 || p > 1.0 {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("p={} should be within [0, 1]", p),
+            ));
+        }
+
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            elements.push(T::from_u64(if u < p { 1 } else { 0 }).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Cauchy distribution with the given
+    /// `median` and `scale`, drawn via inverse transform sampling.
+    ///
+    /// **Note that**: If `scale <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.cauchy(5, 0.0, 1.0).unwrap();
+    /// ```
+    pub fn cauchy<T>(
+        &mut self,
+        len: usize,
+        median: T,
+        scale: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if scale <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("scale={} should be positive", scale),
+            ));
+        }
+
+        let median = median.to_f64().unwrap();
+        let scale = scale.to_f64().unwrap();
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            let x = median + scale * (::std::f64::consts::PI * (u - 0.5)).tan();
+            elements.push(T::from_f64(x).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Pareto (Type I) distribution with the
+    /// given `scale` (x_m) and `shape` (alpha), drawn via inverse transform
+    /// sampling.
+    ///
+    /// **Note that**: If `scale <= 0` or `shape <= 0` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.pareto(5, 1.0, 3.0).unwrap();
+    /// ```
+    pub fn pareto<T>(
+        &mut self,
+        len: usize,
+        scale: T,
+        shape: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if scale <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("scale={} should be positive", scale),
+            ));
+        }
+        if shape <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("shape={} should be positive", shape),
+            ));
+        }
+
+        let scale = scale.to_f64().unwrap();
+        let shape = shape.to_f64().unwrap();
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            // `1 - u` keeps the base in `(0, 1]`, never 0.
+            let x = scale / (1.0 - u).powf(1.0 / shape);
+            elements.push(T::from_f64(x).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a Weibull distribution with the given
+    /// `scale` (lambda) and `shape` (k), drawn via inverse transform
+    /// sampling.
+    ///
+    /// **Note that**: If `scale <= 0` or `shape <= 0` it will returns an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.weibull(5, 1.0, 1.5).unwrap();
+    /// ```
+    pub fn weibull<T>(
+        &mut self,
+        len: usize,
+        scale: T,
+        shape: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if scale <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("scale={} should be positive", scale),
+            ));
+        }
+        if shape <= T::zero() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!("shape={} should be positive", shape),
+            ));
+        }
+
+        let scale = scale.to_f64().unwrap();
+        let shape = shape.to_f64().unwrap();
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            // `1 - u` keeps the argument of `ln` in `(0, 1]`, never 0.
+            let x = scale * (-(1.0 - u).ln()).powf(1.0 / shape);
+            elements.push(T::from_f64(x).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Create a new numeric vector of the given length `len` and populate it
+    /// with random samples from a triangular distribution over `[min, max]`
+    /// with the given `mode`, drawn via inverse transform sampling.
+    ///
+    /// **Note that**: If `min <= mode <= max` does not hold it will returns
+    /// an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = rvb.triangular(5, 0.0, 10.0, 3.0).unwrap();
+    /// ```
+    pub fn triangular<T>(
+        &mut self,
+        len: usize,
+        min: T,
+        max: T,
+        mode: T,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Float + FromPrimitive + ToPrimitive + fmt::Display,
+    {
+        if !(min <= mode && mode <= max) {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                format!(
+                    "min={} <= mode={} <= max={} should hold",
+                    min, mode, max
+                ),
+            ));
+        }
+
+        let min = min.to_f64().unwrap();
+        let max = max.to_f64().unwrap();
+        let mode = mode.to_f64().unwrap();
+        let mode_fraction = (mode - min) / (max - min);
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(len);
+        for _ in 0..len {
+            let u: f64 = unit_uniform.sample(&mut self.rng);
+            let x = if u < mode_fraction {
+                min + (u * (max - min) * (mode - min)).sqrt()
+            } else {
+                max - ((1.0 - u) * (max - min) * (max - mode)).sqrt()
+            };
+            elements.push(T::from_f64(x).unwrap());
+        }
+
+        Ok(Vector::from(elements))
+    }
+
+    /// Draw `count` elements from `source` with probabilities proportional
+    /// to `weights`, via [Vose's alias method]. Once the `O(n)` alias
+    /// table is built, each draw is `O(1)`, which makes this considerably
+    /// faster than a linear-scan weighted selection when `count` is large.
+    ///
+    /// **Note that**: If `weights.len() != source.len()` or any weight is
+    /// negative, it will returns an error.
+    ///
+    /// [Vose's alias method]: https://en.wikipedia.org/wiki/Alias_method
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let source = vector![0, 1];
+    /// let weights = vector![0.5, 0.5];
+    /// let v = rvb.choice_weighted(&source, &weights, 10).unwrap();
+    /// ```
+    pub fn choice_weighted<T>(
+        &mut self,
+        source: &Vector<T>,
+        weights: &Vector<f64>,
+        count: usize,
+    ) -> Result<Vector<T>, VectorBuilderError>
+    where
+        T: Num + Copy,
+    {
+        let n = source.len();
+        if weights.len() != n {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                format!(
+                    "weights.len()={} should be equal to source.len()={}",
+                    weights.len(),
+                    n
+                ),
+            ));
+        }
+        if weights.elements().any(|&w| w < 0.0) {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidParameter,
+                "weights should not be negative".to_string(),
+            ));
+        }
+
+        // Build the alias table: normalize weights so they sum to `n`,
+        // then partition indices into `small` (scaled weight < 1) and
+        // `large` (>= 1) stacks.
+        let total: f64 = weights.elements().sum();
+        let mut scaled: Vec<f64> =
+            weights.elements().map(|w| w * (n as f64) / total).collect();
+        let mut prob = vec![0.0f64; n];
+        let mut alias = vec![0usize; n];
+        let mut small = Vec::new();
+        let mut large = Vec::new();
+        for (i, &s) in scaled.iter().enumerate() {
+            if s < 1.0 {
+                small.push(i);
+            } else {
+                large.push(i);
+            }
+        }
+
+        while let (Some(s), Some(l)) = (small.pop(), large.pop()) {
+            prob[s] = scaled[s];
+            alias[s] = l;
+            scaled[l] -= 1.0 - scaled[s];
+            if scaled[l] < 1.0 {
+                small.push(l);
+            } else {
+                large.push(l);
+            }
+        }
+        // Leftover entries accumulated floating-point error rather than
+        // a genuine excess, so they are always drawn outright.
+        for i in large.into_iter().chain(small.into_iter()) {
+            prob[i] = 1.0;
+        }
+
+        let index_uniform = Uniform::new(0, n);
+        let unit_uniform = Uniform::new(0.0f64, 1.0);
+        let mut elements = Vec::with_capacity(count);
+        for _ in 0..count {
+            let i = index_uniform.sample(&mut self.rng);
+            let f: f64 = unit_uniform.sample(&mut self.rng);
+            let chosen = if f < prob[i] { i } else { alias[i] };
+            elements.push(source[chosen]);
+        }
+
+        Ok(Vector { data: elements })
+    }
+
+    /// Draw a single vector from a Dirichlet distribution with the given
+    /// concentration parameters `alpha`. The returned vector has the same
+    /// length as `alpha`, with non-negative entries summing to 1.
+    ///
+    /// This is implemented by drawing one `Gamma(alpha[i], 1)` sample per
+    /// component and dividing each by their total, which is the standard
+    /// way of generating Dirichlet-distributed vectors.
+    ///
+    /// **Note that**: If any `alpha[i] <= 0` it will returns an error.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let alpha = vector![1.0, 1.0, 1.0];
+    /// let v = rvb.dirichlet(&alpha).unwrap();
+    /// ```
+    pub fn dirichlet(
+        &mut self,
+        alpha: &Vector<f64>,
+    ) -> Result<Vector<f64>, VectorBuilderError> {
+        if alpha.elements().any(|&a| a <= 0.0) {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                "every alpha[i] should be positive".to_string(),
+            ));
+        }
+
+        let mut cached_z1 = None;
+        let mut samples: Vec<f64> = alpha
+            .elements()
+            .map(|&a| self.sample_standard_gamma(a, &mut cached_z1))
+            .collect();
+        let total: f64 = samples.iter().sum();
+        for x in samples.iter_mut() {
+            *x /= total;
+        }
+
+        Ok(Vector::from(samples))
+    }
+
+    /// Create a random permutation of `0..n` via the Fisher-Yates shuffle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let p = rvb.permutation(5);
+    /// assert_eq!(p.len(), 5);
+    /// ```
+    pub fn permutation(&mut self, n: usize) -> Vector<usize> {
+        let mut indices = Vector::from((0..n).collect::<Vec<usize>>());
+        self.shuffle(&mut indices);
+        indices
+    }
+
+    /// Randomly reorder the elements of `v` in place, via the
+    /// Fisher-Yates shuffle: iterate `i` from `len - 1` down to `1`, draw
+    /// `j` uniformly from `0..=i`, and swap elements `i` and `j`. Every
+    /// ordering is equally likely, in `O(n)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let mut v = vector![1, 2, 3, 4, 5];
+    /// rvb.shuffle(&mut v);
+    /// assert_eq!(v.len(), 5);
+    /// ```
+    pub fn shuffle<T>(&mut self, v: &mut Vector<T>)
+    where
+        T: Num + Copy,
+    {
+        let len = v.len();
+        for i in (1..len).rev() {
+            let j = Uniform::new(0, i + 1).sample(&mut self.rng);
+            let tmp = v[i];
+            v[i] = v[j];
+            v[j] = tmp;
+        }
+    }
+
+    /// Create a new numeric vector that is a randomly reordered copy of
+    /// `v`, leaving `v` itself untouched. This is the non-mutating
+    /// counterpart of [`RandomVectorBuilder::shuffle`].
+    ///
+    /// [`RandomVectorBuilder::shuffle`]: #method.shuffle
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut rvb = RandomVectorBuilder::new();
+    /// let v = vector![1, 2, 3, 4, 5];
+    /// let shuffled = rvb.permuted(&v);
+    /// assert_eq!(shuffled.len(), v.len());
+    /// ```
+    pub fn permuted<T>(&mut self, v: &Vector<T>) -> Vector<T>
+    where
+        T: Num + Copy,
+    {
+        let mut result = v.clone();
+        self.shuffle(&mut result);
+        result
+    }
 }
@@ -16,10 +16,14 @@
 //!
 //! An error which can be returned when creating, operating, loading and
 //! indexing numeric vectors.
+use csv;
+use std::convert;
 use std::fmt;
+use std::io;
 
 /// Enum to store the various types of errors that can cause creating a numeric
 /// vector to fail.
+#[derive(Clone, Copy)]
 pub enum VectorBuilderErrorKind {
     /// Invalid step value for numeric vectors range.
     ///
@@ -38,6 +42,14 @@ pub enum VectorBuilderErrorKind {
     /// Among other causes, this variant will be constructed when creating
     /// new random numeric vector using normal distribution with `std_dev < 0`.
     NegativeStandardDeviation,
+
+    /// A distribution parameter is outside the range the distribution
+    /// requires.
+    ///
+    /// Among other causes, this variant will be constructed when creating
+    /// a new random numeric vector with, for example, a non-positive
+    /// `lambda`/`shape`/`scale` or a `p` outside `[0, 1]`.
+    InvalidParameter,
 }
 
 /// An error which can be returned when creating new numeric vectors.
@@ -70,6 +82,9 @@ impl VectorBuilderError {
                  be negative: {}",
                 self.message
             ),
+            VectorBuilderErrorKind::InvalidParameter => {
+                format!("Random vector builder invalid parameter: {}", self.message)
+            }
         }
     }
 }
@@ -85,3 +100,228 @@ impl fmt::Display for VectorBuilderError {
         write!(f, "{}", self.description())
     }
 }
+
+impl std::error::Error for VectorBuilderError {}
+
+/// Enum to store the various types of errors that can cause loading a
+/// numeric vector to fail.
+pub enum VectorLoadErrorKind {
+    /// I/O Error
+    ///
+    /// Among other causes, this variant will be constructed when failed
+    /// loading a file due to I/O problem.
+    IOError,
+    /// CSV Error
+    ///
+    /// Among other causes, this variant will be constructed when failed
+    /// loading a CSV file.
+    CSVError,
+    /// File being loaded is empty.
+    ///
+    /// Among other causes, this variant will be constructed when loading an
+    /// empty file.
+    Empty,
+    /// Contains an invalid element.
+    ///
+    /// Among other causes, this variant will be constructed when parsing a
+    /// string that contains non-numeric letter.
+    InvalidElement,
+    /// Binary `.npy` file has a malformed or mismatched header.
+    ///
+    /// Among other causes, this variant will be constructed when the
+    /// magic bytes, version, dtype or shape of a file loaded with
+    /// [`Vector::load_npy`] don't match what was expected.
+    ///
+    /// [`Vector::load_npy`]: ../struct.Vector.html#method.load_npy
+    InvalidFormat,
+}
+
+/// An error which can be returned when loading a numeric vector from a file.
+///
+/// # Potential causes
+/// Among other causes, `VectorLoadError` can be thrown because the loaded
+/// file does not exist.
+pub struct VectorLoadError {
+    pub(crate) kind: VectorLoadErrorKind,
+    pub(crate) message: String,
+    pub(crate) source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl VectorLoadError {
+    /// Creates a new `VectorLoadError` from a known kind of error as well
+    /// as an error message.
+    pub fn new(kind: VectorLoadErrorKind, message: String) -> VectorLoadError {
+        VectorLoadError {
+            kind,
+            message,
+            source: None,
+        }
+    }
+
+    /// Outputs the detailed cause of loading file failing.
+    pub fn kind(&self) -> &VectorLoadErrorKind {
+        &self.kind
+    }
+
+    fn description(&self) -> String {
+        match self.kind {
+            VectorLoadErrorKind::IOError => format!(
+                "Cannot load Vector from file due to: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::CSVError => {
+                format!("Cannot load Vector, {}", self.message)
+            }
+            VectorLoadErrorKind::Empty => {
+                format!("Cannot load Vector from empty file")
+            }
+            VectorLoadErrorKind::InvalidElement => format!(
+                "Cannot load Vector, invalid element: {}",
+                self.message
+            ),
+            VectorLoadErrorKind::InvalidFormat => format!(
+                "Cannot load Vector, invalid .npy format: {}",
+                self.message
+            ),
+        }
+    }
+}
+
+/// Convert `io::Error` to `vector::VectorLoadError`
+impl convert::From<io::Error> for VectorLoadError {
+    fn from(error: io::Error) -> Self {
+        VectorLoadError {
+            kind: VectorLoadErrorKind::IOError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+/// Convert `csv::Error` to `vector::VectorLoadError`
+impl convert::From<csv::Error> for VectorLoadError {
+    fn from(error: csv::Error) -> Self {
+        VectorLoadError {
+            kind: VectorLoadErrorKind::CSVError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+impl fmt::Debug for VectorLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for VectorLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl std::error::Error for VectorLoadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|error| error.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
+
+/// Enum to store the various types of errors that can cause saving a
+/// numeric vector to fail.
+pub enum VectorSaveErrorKind {
+    /// I/O Error
+    ///
+    /// Among other causes, this variant will be constructed when failed
+    /// saving a file due to I/O problem.
+    IOError,
+    /// CSV Error
+    ///
+    /// Among other causes, this variant will be constructed when failed
+    /// writing a CSV file.
+    CSVError,
+}
+
+/// An error which can be returned when saving a numeric vector to a file.
+///
+/// # Potential causes
+/// Among other causes, `VectorSaveError` can be thrown because the
+/// destination file or its parent directory is not writable.
+pub struct VectorSaveError {
+    pub(crate) kind: VectorSaveErrorKind,
+    pub(crate) message: String,
+    pub(crate) source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
+}
+
+impl VectorSaveError {
+    /// Creates a new `VectorSaveError` from a known kind of error as well
+    /// as an error message.
+    pub fn new(kind: VectorSaveErrorKind, message: String) -> VectorSaveError {
+        VectorSaveError {
+            kind,
+            message,
+            source: None,
+        }
+    }
+
+    /// Outputs the detailed cause of saving file failing.
+    pub fn kind(&self) -> &VectorSaveErrorKind {
+        &self.kind
+    }
+
+    fn description(&self) -> String {
+        match self.kind {
+            VectorSaveErrorKind::IOError => format!(
+                "Cannot save Vector to file due to: {}",
+                self.message
+            ),
+            VectorSaveErrorKind::CSVError => {
+                format!("Cannot save Vector, {}", self.message)
+            }
+        }
+    }
+}
+
+/// Convert `io::Error` to `vector::VectorSaveError`
+impl convert::From<io::Error> for VectorSaveError {
+    fn from(error: io::Error) -> Self {
+        VectorSaveError {
+            kind: VectorSaveErrorKind::IOError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+/// Convert `csv::Error` to `vector::VectorSaveError`
+impl convert::From<csv::Error> for VectorSaveError {
+    fn from(error: csv::Error) -> Self {
+        VectorSaveError {
+            kind: VectorSaveErrorKind::CSVError,
+            message: format!("{}", error),
+            source: Some(Box::new(error)),
+        }
+    }
+}
+
+impl fmt::Debug for VectorSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl fmt::Display for VectorSaveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.description())
+    }
+}
+
+impl std::error::Error for VectorSaveError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|error| error.as_ref() as &(dyn std::error::Error + 'static))
+    }
+}
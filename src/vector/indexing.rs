@@ -19,7 +19,7 @@
 //!
 //!
 
-use crate::vector::Vector;
+use crate::vector::{SubVectorMut, Vector};
 use num::Num;
 use std::ops;
 
@@ -63,3 +63,69 @@ where
         &mut self.data[i]
     }
 }
+
+impl<'a, T> SubVectorMut<'a, T>
+where
+    T: Num + Copy,
+{
+    // Bound check
+    pub(crate) fn bound_check(&self, i: usize) {
+        if i >= self.size {
+            panic!(
+                "Vector index {} out of range for vector with length {}",
+                i, self.size
+            )
+        }
+    }
+
+    /// Get a mutable reference to the element of the sub numeric vector at
+    /// index `i`.
+    ///
+    /// # Panics
+    /// Panics if `i >= size`.
+    pub fn get_mut(&mut self, i: usize) -> &mut T {
+        self.bound_check(i);
+        &mut self.source[self.offset + i]
+    }
+
+    /// Overwrite every element of the sub numeric vector with `value`,
+    /// writing back into the parent vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let mut x = vector![3, 1, 4, 1, 5, 9];
+    /// x.slice_mut(1..4).fill(0);
+    /// assert_eq!(x, vector![3, 0, 0, 0, 5, 9]);
+    /// ```
+    pub fn fill(&mut self, value: T) {
+        for i in 0..self.size {
+            *self.get_mut(i) = value;
+        }
+    }
+}
+
+// Implement sub numeric vector indexing in mutable context
+// sub_vector[index] = value
+impl<'a, T> ops::IndexMut<usize> for SubVectorMut<'a, T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        self.get_mut(i)
+    }
+}
+
+// Implement sub numeric vector indexing
+// sub_vector[index]
+impl<'a, T> ops::Index<usize> for SubVectorMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        self.bound_check(i);
+        &self.source[self.offset + i]
+    }
+}
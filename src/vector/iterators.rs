@@ -21,6 +21,7 @@
 use crate::vector::Vector;
 use num::Num;
 use std::iter;
+use std::slice;
 
 // Implement row iterator for matrix
 pub struct VectorElementIterator<'a, T: 'a>
@@ -29,6 +30,7 @@ where
 {
     vector: &'a Vector<T>,
     pos: usize,
+    end: usize,
 }
 
 impl<'a, T> Iterator for VectorElementIterator<'a, T>
@@ -38,7 +40,7 @@ where
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.pos >= self.vector.len() {
+        if self.pos >= self.end {
             return None;
         }
         // Increment the position of the row iterator.
@@ -46,36 +48,29 @@ where
         // Return the reference to the element
         Some(&self.vector[self.pos - 1])
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.pos;
+        (remaining, Some(remaining))
+    }
 }
 
-// Implement mutable row iterator for matrix
-// Currently we can't implement a safe mutable Iterator
-// https://www.reddit.com/r/rust/comments/6ffrbs/implementing_a_safe_mutable_iterator/
-// https://stackoverflow.com/a/30422716
-//pub struct VectorElementIMutableterator<'a, T: 'a>
-//where
-//    T: Num + Copy,
-//{
-//    vector: &'a mut Vector<T>,
-//    pos: usize,
-//}
-//
-//impl<'a, T> Iterator for VectorElementIMutableterator<'a, T>
-//where
-//    T: Num + Copy,
-//{
-//    type Item = &'a mut T;
-//
-//    fn next(&mut self) -> Option<Self::Item> {
-//        if self.pos >= self.vector.len() {
-//            return None;
-//        }
-//        // Increment the position of the row iterator.
-//        self.pos += 1;
-//        // Return the reference to the element
-//        Some(&mut self.vector.data[self.pos])
-//    }
-//}
+impl<'a, T> DoubleEndedIterator for VectorElementIterator<'a, T>
+where
+    T: Num + Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.pos >= self.end {
+            return None;
+        }
+        // Decrement the end of the row iterator.
+        self.end -= 1;
+        // Return the reference to the element
+        Some(&self.vector[self.end])
+    }
+}
+
+impl<'a, T> ExactSizeIterator for VectorElementIterator<'a, T> where T: Num + Copy {}
 
 // Create numeric vector from an iterator
 impl<T> iter::FromIterator<T> for Vector<T>
@@ -111,29 +106,233 @@ where
     /// assert_eq!(elements.next(), None);
     /// ```
     pub fn elements<'a>(&'a self) -> VectorElementIterator<'a, T> {
+        let len = self.len();
         VectorElementIterator {
             vector: self,
             pos: 0,
+            end: len,
+        }
+    }
+
+    /// Iterates over elements of the numeric vector with mutable
+    /// references.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let mut x = vector![1, 2, 3];
+    /// for value in x.elements_mut() {
+    ///     *value = 314;
+    /// }
+    /// assert_eq!(x, vector![314, 314, 314]);
+    /// ```
+    pub fn elements_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.data.iter_mut()
+    }
+
+    /// Applies `f` to every element of the vector in place, avoiding the
+    /// allocation of a new vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let mut x = vector![1, 2, 3];
+    /// x.apply(|value| *value *= 10);
+    /// assert_eq!(x, vector![10, 20, 30]);
+    /// ```
+    pub fn apply<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        for x in self.elements_mut() {
+            f(x);
+        }
+    }
+
+    /// Folds `other`'s elements into `self` in place via `f(self_elem,
+    /// other_elem)`, avoiding the allocation of a new vector.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` don't have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let mut x = vector![1, 2, 3];
+    /// let y = vector![10, 20, 30];
+    /// x.zip_apply(&y, |a, b| *a += b);
+    /// assert_eq!(x, vector![11, 22, 33]);
+    /// ```
+    pub fn zip_apply<F>(&mut self, other: &Vector<T>, mut f: F)
+    where
+        F: FnMut(&mut T, T),
+    {
+        if self.len() != other.len() {
+            panic!(
+                "cannot zip_apply vector of len {} with len {}",
+                other.len(),
+                self.len()
+            );
+        }
+        for (x, y) in self.data.iter_mut().zip(other.elements()) {
+            f(x, *y);
         }
     }
 
-    // NOTE: Currently we can't implement mutable iterator in safe
-    // manner. https://stackoverflow.com/a/30422716
-    // Iterates over elements of the numeric vector with mutable
-    // references.
-    //
-    // # Examples
-    // ```
-    // # use crabsformer::*;
-    // let mut x = vector![1, 2, 3];
-    // for value in x.elements_mut() {
-    //     *value = 314;
-    // }
-    // ```
-    //    pub fn elements_mut<'a>(&'a mut self) -> VectorElementIterator<'a, T> {
-    //        VectorElementIterator {
-    //            vector: self,
-    //            pos: 0,
-    //        }
-    //    }
+    /// Pairs up the elements of `self` and `other` and maps each pair into
+    /// a new numeric vector with `f`. Unlike `self` and `other`, the
+    /// element type of the result, `V`, can differ from both of theirs.
+    ///
+    /// # Panics
+    /// Panics if `self` and `other` don't have the same length.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let a = vector![1, 2, 3];
+    /// let b = vector![10, 20, 30];
+    /// let c = a.zip_with(&b, |x, y| x + y);
+    /// assert_eq!(c, vector![11, 22, 33]);
+    /// ```
+    pub fn zip_with<U, V, F>(&self, other: &Vector<U>, mut f: F) -> Vector<V>
+    where
+        U: Num + Copy,
+        V: Num + Copy,
+        F: FnMut(T, U) -> V,
+    {
+        if self.len() != other.len() {
+            panic!(
+                "cannot zip_with vector of len {} with len {}",
+                other.len(),
+                self.len()
+            );
+        }
+        self.elements()
+            .zip(other.elements())
+            .map(|(a, b)| f(*a, *b))
+            .collect()
+    }
+
+    /// Maps each element of the numeric vector into a new numeric vector,
+    /// whose element type `U` can differ from `self`'s.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let x = vector![1, 2, 3];
+    /// let y = x.map(|value| value as f64 * 0.5);
+    /// assert_eq!(y, vector![0.5, 1.0, 1.5]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Vector<U>
+    where
+        U: Num + Copy,
+        F: Fn(T) -> U,
+    {
+        self.elements().map(|x| f(*x)).collect()
+    }
+
+    /// Iterates over `(index, element)` pairs of the numeric vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let x = vector![3, 1, 4];
+    /// let pairs: Vec<(usize, &i32)> = x.enumerate().collect();
+    /// assert_eq!(pairs, [(0, &3), (1, &1), (2, &4)]);
+    /// ```
+    pub fn enumerate<'a>(&'a self) -> iter::Enumerate<VectorElementIterator<'a, T>> {
+        self.elements().enumerate()
+    }
+
+    /// Folds every element into an accumulator by applying `f`, returning
+    /// the final result. This is a thin convenience wrapper around
+    /// [`elements()`]`.fold()`.
+    ///
+    /// [`elements()`]: #method.elements
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let x = vector![1, 2, 3, 4];
+    /// let sum = x.fold(0, |acc, value| acc + value);
+    /// assert_eq!(sum, 10);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.elements().fold(init, f)
+    }
+
+    /// Produces an iterator of running values by threading a mutable
+    /// state through `f`, stopping as soon as `f` returns `None`. This is
+    /// a thin convenience wrapper around [`elements()`]`.scan()`.
+    ///
+    /// [`elements()`]: #method.elements
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::*;
+    /// let x = vector![1, 2, 3, 4];
+    /// let running_sum: Vec<i32> = x
+    ///     .scan(0, |state, value| {
+    ///         *state += value;
+    ///         Some(*state)
+    ///     })
+    ///     .collect();
+    /// assert_eq!(running_sum, [1, 3, 6, 10]);
+    /// ```
+    pub fn scan<'a, St, B, F>(
+        &'a self,
+        initial_state: St,
+        f: F,
+    ) -> iter::Scan<VectorElementIterator<'a, T>, St, F>
+    where
+        F: FnMut(&mut St, &'a T) -> Option<B>,
+    {
+        self.elements().scan(initial_state, f)
+    }
+}
+
+// Iterating over `Vector<T>` by value consumes it and yields owned
+// elements, the same way iterating over a `Vec<T>` by value does.
+impl<T> IntoIterator for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+// Iterating over `&Vector<T>` yields borrowed elements, equivalent to
+// calling `elements()`.
+impl<'a, T> IntoIterator for &'a Vector<T>
+where
+    T: Num + Copy,
+{
+    type Item = &'a T;
+    type IntoIter = VectorElementIterator<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elements()
+    }
+}
+
+// Iterating over `&mut Vector<T>` yields mutable references to its
+// elements, so `for value in &mut x { ... }` works the same way it does
+// for a plain `&mut [T]`.
+impl<'a, T> IntoIterator for &'a mut Vector<T>
+where
+    T: Num + Copy,
+{
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter_mut()
+    }
 }
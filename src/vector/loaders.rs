@@ -0,0 +1,365 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+// TODO(pyk): Add docs about how to load numeric vector from external file here
+
+use crate::vector::errors::{
+    VectorLoadError, VectorLoadErrorKind, VectorSaveError,
+};
+use crate::vector::Vector;
+use crate::utils;
+use crate::utils::LittleEndianBytes;
+use csv;
+use num::{FromPrimitive, Num};
+use std::fs::File;
+use std::io::{Read, Write};
+use std::marker::PhantomData;
+use std::path::Path;
+
+/// Numeric vector loader for CSV formatted file.
+///
+/// See also: [`Vector::from_csv`].
+///
+/// [`Vector::from_csv`]: struct.Vector.html#method.from_csv
+#[derive(Debug)]
+pub struct VectorLoaderForCSV<T, P>
+where
+    P: AsRef<Path>,
+{
+    file_path: P,
+    has_headers: bool,
+    delimiter: u8,
+    // We use this to make compiler happy
+    phantom: PhantomData<T>,
+}
+
+impl<T, P> VectorLoaderForCSV<T, P>
+where
+    P: AsRef<Path>,
+{
+    /// Set to true to treat the first row as a special header row. By
+    /// default, it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Vector<f32> = Vector::from_csv("tests/data/dataset.csv")
+    ///     .has_headers(true)
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn has_headers(self, yes: bool) -> VectorLoaderForCSV<T, P> {
+        VectorLoaderForCSV {
+            has_headers: yes,
+            ..self
+        }
+    }
+
+    /// Set the field delimiter used when parsing the CSV file. By default,
+    /// it is set to `b','`. Use `b'\t'` to load TSV files.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Vector<f32> = Vector::from_csv("tests/data/dataset.csv")
+    ///     .delimiter(b'\t')
+    ///     .load()
+    ///     .unwrap();
+    /// ```
+    pub fn delimiter(self, delimiter: u8) -> VectorLoaderForCSV<T, P> {
+        VectorLoaderForCSV { delimiter, ..self }
+    }
+
+    /// Load Vector from a CSV file with a single record. You need to
+    /// explicitly annotate the numeric type.
+    ///
+    /// # Examples
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Vector<f32> = Vector::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    pub fn load(self) -> Result<Vector<T>, VectorLoadError>
+    where
+        T: FromPrimitive + Num + Copy + utils::TypeName,
+    {
+        let mut rdr = csv::ReaderBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .from_path(self.file_path)?;
+
+        let mut elements = Vec::new();
+        for result in rdr.records() {
+            let record = result?;
+            for value in record.iter() {
+                let element = match T::from_str_radix(value.trim(), 10) {
+                    Ok(value) => value,
+                    Err(_err) => {
+                        return Err(VectorLoadError::new(
+                            VectorLoadErrorKind::InvalidElement,
+                            format!(
+                                "{:?} is not valid {}",
+                                value,
+                                T::type_name()
+                            ),
+                        ));
+                    }
+                };
+                elements.push(element);
+            }
+        }
+        if elements.len() == 0 {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::Empty,
+                String::from("Cannot load empty file"),
+            ));
+        }
+        Ok(Vector::from(elements))
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Num + Copy,
+{
+    /// Load Vector from CSV file. You need to explicitly annotate the
+    /// numeric type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Vector<f32> = Vector::from_csv("tests/data/weight.csv").load().unwrap();
+    /// ```
+    pub fn from_csv<P>(file_path: P) -> VectorLoaderForCSV<T, P>
+    where
+        P: AsRef<Path>,
+    {
+        VectorLoaderForCSV {
+            file_path,
+            has_headers: false,
+            delimiter: b',',
+            phantom: PhantomData,
+        }
+    }
+}
+
+/// Numeric vector writer for CSV formatted file.
+///
+/// See also: [`Vector::to_csv`].
+///
+/// [`Vector::to_csv`]: struct.Vector.html#method.to_csv
+#[derive(Debug)]
+pub struct VectorWriterForCSV<'a, T, P>
+where
+    T: Num + Copy,
+    P: AsRef<Path>,
+{
+    vector: &'a Vector<T>,
+    file_path: P,
+    has_headers: bool,
+    delimiter: u8,
+}
+
+impl<'a, T, P> VectorWriterForCSV<'a, T, P>
+where
+    T: Num + Copy,
+    P: AsRef<Path>,
+{
+    /// Set to true to write a special header row before the vector's
+    /// record. By default, it is set to false.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = vector![3.0, 1.0, 4.0];
+    /// w.to_csv("tests/data/weight.csv").has_headers(true).write().unwrap();
+    /// ```
+    pub fn has_headers(self, yes: bool) -> VectorWriterForCSV<'a, T, P> {
+        VectorWriterForCSV {
+            vector: self.vector,
+            file_path: self.file_path,
+            has_headers: yes,
+            delimiter: self.delimiter,
+        }
+    }
+
+    /// Set the field delimiter used when writing the CSV file. By default,
+    /// it is set to `b','`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = vector![3.0, 1.0, 4.0];
+    /// w.to_csv("tests/data/weight.csv").delimiter(b';').write().unwrap();
+    /// ```
+    pub fn delimiter(self, delimiter: u8) -> VectorWriterForCSV<'a, T, P> {
+        VectorWriterForCSV {
+            vector: self.vector,
+            file_path: self.file_path,
+            has_headers: self.has_headers,
+            delimiter,
+        }
+    }
+
+    /// Write the vector to the CSV file as a single record.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = vector![3.0, 1.0, 4.0];
+    /// w.to_csv("tests/data/weight.csv").write().unwrap();
+    /// ```
+    pub fn write(self) -> Result<(), VectorSaveError>
+    where
+        T: ToString,
+    {
+        let file = File::create(self.file_path)?;
+        let mut wtr = csv::WriterBuilder::new()
+            .has_headers(self.has_headers)
+            .delimiter(self.delimiter)
+            .from_writer(file);
+
+        let record: Vec<String> =
+            self.vector.elements().map(|value| value.to_string()).collect();
+        wtr.write_record(&record)?;
+        wtr.flush()?;
+        Ok(())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Num + Copy,
+{
+    /// Save the vector to a CSV file as a single record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = vector![3.0, 1.0, 4.0];
+    /// w.to_csv("tests/data/weight.csv").write().unwrap();
+    /// ```
+    pub fn to_csv<P>(&self, file_path: P) -> VectorWriterForCSV<T, P>
+    where
+        P: AsRef<Path>,
+    {
+        VectorWriterForCSV {
+            vector: self,
+            file_path,
+            has_headers: false,
+            delimiter: b',',
+        }
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Num + Copy + LittleEndianBytes + utils::NumpyDescr,
+{
+    /// Save the vector to a NumPy `.npy` file: the standard `\x93NUMPY`
+    /// header (format version, then an ASCII dict giving `descr`,
+    /// `fortran_order` and `shape`) followed by the raw little-endian
+    /// element bytes. Unlike [`Vector::to_csv`], this is lossless for
+    /// floating point elements and avoids any string round-trip, and the
+    /// file can be loaded back with `numpy.load`.
+    ///
+    /// [`Vector::to_csv`]: struct.Vector.html#method.to_csv
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let w = vector![3.0, 1.0, 4.0];
+    /// w.save_npy("tests/data/weight.npy").unwrap();
+    /// ```
+    pub fn save_npy<P>(&self, file_path: P) -> Result<(), VectorSaveError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::create(file_path)?;
+        utils::write_npy_header(&mut file, T::DESCR, &[self.len()])?;
+        for value in self.elements() {
+            file.write_all(&value.to_le_bytes_vec())?;
+        }
+        Ok(())
+    }
+
+    /// Load a vector from a NumPy `.npy` file previously saved with
+    /// [`Vector::save_npy`] (or with `numpy.save`). You need to explicitly
+    /// annotate the numeric type, which must match the `descr` the file
+    /// was saved with. The length is inferred from the header, which must
+    /// describe a 1-dimensional array.
+    ///
+    /// [`Vector::save_npy`]: struct.Vector.html#method.save_npy
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use crabsformer::prelude::*;
+    ///
+    /// let dataset: Vector<f64> = Vector::load_npy("tests/data/weight.npy").unwrap();
+    /// ```
+    pub fn load_npy<P>(file_path: P) -> Result<Vector<T>, VectorLoadError>
+    where
+        P: AsRef<Path>,
+    {
+        let mut file = File::open(file_path)?;
+        let mut buf = Vec::new();
+        file.read_to_end(&mut buf)?;
+
+        let (shape, data) = utils::read_npy_header::<T>(&buf).map_err(|msg| {
+            VectorLoadError::new(VectorLoadErrorKind::InvalidFormat, msg)
+        })?;
+        if shape.len() != 1 {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::InvalidFormat,
+                format!("expected a 1-dimensional shape, found {:?}", shape),
+            ));
+        }
+        let len = shape[0];
+        let expected_len = len * T::WIDTH;
+        if data.len() != expected_len {
+            return Err(VectorLoadError::new(
+                VectorLoadErrorKind::InvalidFormat,
+                format!(
+                    "expected {} bytes of element data, found {}",
+                    expected_len,
+                    data.len()
+                ),
+            ));
+        }
+
+        let elements: Vec<T> = (0..len)
+            .map(|i| {
+                let offset = i * T::WIDTH;
+                T::from_le_bytes_slice(&data[offset..offset + T::WIDTH])
+            })
+            .collect();
+        Ok(Vector::from(elements))
+    }
+}
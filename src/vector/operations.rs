@@ -21,8 +21,10 @@
 //!
 //!
 
+use crate::error::CrabsformerError;
+use crate::matrix::Matrix;
 use crate::vector::Vector;
-use num::{FromPrimitive, Num};
+use num::{Float, FromPrimitive, Num};
 use std::ops;
 
 impl<T> Vector<T>
@@ -84,6 +86,61 @@ where
         Vector { data }
     }
 
+    /// Clamps every element of the numeric vector into the range
+    /// `[min, max]`, returning a new numeric vector. If you want to
+    /// modify an existing numeric vector use [`clip_mut`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![-1, 5, 3, 10];
+    /// assert_eq!(x.clip(0, 4), vector![0, 4, 3, 4]);
+    /// ```
+    ///
+    /// [`clip_mut`]: #method.clip_mut
+    pub fn clip(&self, min: T, max: T) -> Vector<T>
+    where
+        T: PartialOrd,
+    {
+        self.elements()
+            .map(|&x| {
+                if x < min {
+                    min
+                } else if x > max {
+                    max
+                } else {
+                    x
+                }
+            })
+            .collect()
+    }
+
+    /// Clamps every element of the numeric vector into the range
+    /// `[min, max]` in place. If you want to create a new numeric vector
+    /// use [`clip`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut x = vector![-1, 5, 3, 10];
+    /// x.clip_mut(0, 4);
+    /// assert_eq!(x, vector![0, 4, 3, 4]);
+    /// ```
+    ///
+    /// [`clip`]: #method.clip
+    pub fn clip_mut(&mut self, min: T, max: T)
+    where
+        T: PartialOrd,
+    {
+        for x in self.data.iter_mut() {
+            if *x < min {
+                *x = min;
+            } else if *x > max {
+                *x = max;
+            }
+        }
+    }
+
     /// Sum of numeric vector elements.
     ///
     /// # Examples
@@ -101,46 +158,653 @@ where
             .fold(T::from_f32(0.0).unwrap(), |acc, x| acc + *x)
     }
 
+    /// Product of numeric vector elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3, 4];
+    /// assert_eq!(x.product(), 24);
+    /// ```
+    pub fn product(&self) -> T
+    where
+        T: FromPrimitive,
+    {
+        self.elements()
+            .fold(T::from_f32(1.0).unwrap(), |acc, x| acc * *x)
+    }
+
+    /// Returns the cumulative sum of the numeric vector elements, i.e.
+    /// a new numeric vector of the same length where element `i` is the
+    /// sum of elements `0..=i` of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3, 4];
+    /// assert_eq!(x.cumsum(), vector![1, 3, 6, 10]);
+    /// ```
+    pub fn cumsum(&self) -> Vector<T>
+    where
+        T: FromPrimitive,
+    {
+        let mut acc = T::from_f32(0.0).unwrap();
+        self.elements()
+            .map(|x| {
+                acc = acc + *x;
+                acc
+            })
+            .collect()
+    }
+
+    /// Returns the cumulative product of the numeric vector elements, i.e.
+    /// a new numeric vector of the same length where element `i` is the
+    /// product of elements `0..=i` of `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3, 4];
+    /// assert_eq!(x.cumprod(), vector![1, 2, 6, 24]);
+    /// ```
+    pub fn cumprod(&self) -> Vector<T>
+    where
+        T: FromPrimitive,
+    {
+        let mut acc = T::from_f32(1.0).unwrap();
+        self.elements()
+            .map(|x| {
+                acc = acc * *x;
+                acc
+            })
+            .collect()
+    }
+
     /// Returns the maximum element of a numeric vector.
     ///
-    /// Note that, it's only work for numeric vector
-    /// of integer due too the trait `std::cmp::Ord` is
-    /// not implemented for `f32` and `f64` in Rust
-    /// standard library. This may change in the future.
+    /// NaN policy: if any element is NaN, the result is that NaN
+    /// (it "poisons" the reduction), mirroring IEEE 754 comparisons.
+    /// To skip NaN elements instead, use [`nanmax`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1, 2, 3];
+    /// assert_eq!(x.max(), 3);
+    ///
+    /// let y = vector![3.0, 1.0, 4.0];
+    /// assert_eq!(y.max(), 4.0);
+    /// ```
+    ///
+    /// [`nanmax`]: #method.nanmax
+    pub fn max(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.elements().skip(1).fold(self.data[0], |acc, &x| {
+            if acc != acc {
+                acc
+            } else if x != x || x > acc {
+                x
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Returns the minimum element of a numeric vector.
+    ///
+    /// NaN policy: if any element is NaN, the result is that NaN
+    /// (it "poisons" the reduction), mirroring IEEE 754 comparisons.
+    /// To skip NaN elements instead, use [`nanmin`].
     ///
     /// # Examples
     ///
     /// ```
     /// # use crabsformer::prelude::*;
     /// let x = vector![1, 2, 3];
-    /// assert_eq!(*x.max(), 3);
+    /// assert_eq!(x.min(), 1);
+    ///
+    /// let y = vector![3.0, 1.0, 4.0];
+    /// assert_eq!(y.min(), 1.0);
+    /// ```
+    ///
+    /// [`nanmin`]: #method.nanmin
+    pub fn min(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.elements().skip(1).fold(self.data[0], |acc, &x| {
+            if acc != acc {
+                acc
+            } else if x != x || x < acc {
+                x
+            } else {
+                acc
+            }
+        })
+    }
+
+    /// Returns the maximum element of a numeric vector, ignoring NaNs.
+    ///
+    /// # Panics
+    /// Panics if every element is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![3.0, std::f64::NAN, 4.0];
+    /// assert_eq!(x.nanmax(), 4.0);
+    /// ```
+    pub fn nanmax(&self) -> T
+    where
+        T: PartialOrd,
+    {
+        self.elements()
+            .filter(|x| **x == **x)
+            .fold(None, |acc, &x| match acc {
+                Some(a) if a > x => Some(a),
+                _ => Some(x),
+            })
+            .unwrap_or_else(|| panic!("Vector nanmax of all-NaN elements"))
+    }
+
+    /// Returns the minimum element of a numeric vector, ignoring NaNs.
+    ///
+    /// # Panics
+    /// Panics if every element is NaN.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![3.0, std::f64::NAN, 4.0];
+    /// assert_eq!(x.nanmin(), 3.0);
     /// ```
-    pub fn max(&self) -> &T
+    pub fn nanmin(&self) -> T
     where
-        T: num::Integer + Copy,
+        T: PartialOrd,
     {
-        self.elements().max().unwrap()
+        self.elements()
+            .filter(|x| **x == **x)
+            .fold(None, |acc, &x| match acc {
+                Some(a) if a < x => Some(a),
+                _ => Some(x),
+            })
+            .unwrap_or_else(|| panic!("Vector nanmin of all-NaN elements"))
+    }
+
+    /// Returns the index of the maximum element of a numeric vector.
+    ///
+    /// Shares the NaN policy of [`max`]: if a NaN is encountered, its
+    /// index is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![3, 1, 4, 1];
+    /// assert_eq!(x.argmax(), 2);
+    /// ```
+    ///
+    /// [`max`]: #method.max
+    pub fn argmax(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        let mut arg = 0;
+        let mut best = self.data[0];
+        for (i, &x) in self.data.iter().enumerate().skip(1) {
+            if best != best {
+                break;
+            }
+            if x != x || x > best {
+                best = x;
+                arg = i;
+            }
+        }
+        arg
+    }
+
+    /// Returns the index of the minimum element of a numeric vector.
+    ///
+    /// Shares the NaN policy of [`min`]: if a NaN is encountered, its
+    /// index is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![3, 1, 4, 1];
+    /// assert_eq!(x.argmin(), 1);
+    /// ```
+    ///
+    /// [`min`]: #method.min
+    pub fn argmin(&self) -> usize
+    where
+        T: PartialOrd,
+    {
+        let mut arg = 0;
+        let mut best = self.data[0];
+        for (i, &x) in self.data.iter().enumerate().skip(1) {
+            if best != best {
+                break;
+            }
+            if x != x || x < best {
+                best = x;
+                arg = i;
+            }
+        }
+        arg
+    }
+
+    /// Returns the dot product (inner product) of two numeric vectors.
+    ///
+    /// # Panics
+    /// Panics if the length of both vectors doesn't match.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![1, 3, -5];
+    /// let b = vector![4, -2, -1];
+    /// assert_eq!(a.dot(&b), 3);
+    /// ```
+    pub fn dot(&self, other: &Vector<T>) -> T
+    where
+        T: FromPrimitive,
+    {
+        match self.try_dot(other) {
+            Ok(value) => value,
+            Err(_) => panic!(
+                "Vector dot product with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+
+    /// Returns the dot product (inner product) of two numeric vectors,
+    /// or a [`CrabsformerError::ShapeMismatch`] if the vectors don't
+    /// have the same length.
+    ///
+    /// This is the non-panicking counterpart of [`dot()`].
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    /// [`dot()`]: #method.dot
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![1, 3, -5];
+    /// let b = vector![4, -2, -1];
+    /// assert_eq!(a.try_dot(&b).unwrap(), 3);
+    ///
+    /// let c = vector![1, 2];
+    /// assert!(a.try_dot(&c).is_err());
+    /// ```
+    pub fn try_dot(&self, other: &Vector<T>) -> Result<T, CrabsformerError>
+    where
+        T: FromPrimitive,
+    {
+        if self.len() != other.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![other.len()],
+            });
+        }
+
+        Ok(self
+            .elements()
+            .zip(other.elements())
+            .fold(T::from_f32(0.0).unwrap(), |acc, (x, y)| acc + *x * *y))
+    }
+
+    /// Reinterprets this numeric vector as a matrix of shape `[rows, cols]`,
+    /// filling the matrix in row-major order.
+    ///
+    /// Returns a [`CrabsformerError::ShapeMismatch`] if `rows * cols`
+    /// doesn't equal the length of the vector.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let v = vector![1, 2, 3, 4, 5, 6];
+    /// let w = v.reshape(2, 3).unwrap();
+    /// assert_eq!(w, matrix![1, 2, 3; 4, 5, 6]);
+    /// ```
+    pub fn reshape(
+        self,
+        rows: usize,
+        cols: usize,
+    ) -> Result<Matrix<T>, CrabsformerError> {
+        if rows * cols != self.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![rows, cols],
+            });
+        }
+
+        Ok(Matrix::from_vector(self, cols).unwrap())
+    }
+
+    /// Returns the element-wise sum of two numeric vectors, or a
+    /// [`CrabsformerError::ShapeMismatch`] if their lengths don't match.
+    ///
+    /// This is the non-panicking counterpart of the `+` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![1, 2, 3];
+    /// let b = vector![4, 5, 6];
+    /// assert_eq!(a.try_add(&b).unwrap(), vector![5, 7, 9]);
+    ///
+    /// let c = vector![1, 2];
+    /// assert!(a.try_add(&c).is_err());
+    /// ```
+    pub fn try_add(&self, other: &Vector<T>) -> Result<Vector<T>, CrabsformerError> {
+        if self.len() != other.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![other.len()],
+            });
+        }
+
+        Ok(self
+            .elements()
+            .zip(other.elements())
+            .map(|(x, y)| *x + *y)
+            .collect())
+    }
+
+    /// Returns the element-wise difference of two numeric vectors, or a
+    /// [`CrabsformerError::ShapeMismatch`] if their lengths don't match.
+    ///
+    /// This is the non-panicking counterpart of the `-` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![4, 5, 6];
+    /// let b = vector![1, 2, 3];
+    /// assert_eq!(a.try_sub(&b).unwrap(), vector![3, 3, 3]);
+    ///
+    /// let c = vector![1, 2];
+    /// assert!(a.try_sub(&c).is_err());
+    /// ```
+    pub fn try_sub(&self, other: &Vector<T>) -> Result<Vector<T>, CrabsformerError> {
+        if self.len() != other.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![other.len()],
+            });
+        }
+
+        Ok(self
+            .elements()
+            .zip(other.elements())
+            .map(|(x, y)| *x - *y)
+            .collect())
+    }
+
+    /// Returns the element-wise product of two numeric vectors, or a
+    /// [`CrabsformerError::ShapeMismatch`] if their lengths don't match.
+    ///
+    /// This is the non-panicking counterpart of the `*` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![1, 2, 3];
+    /// let b = vector![4, 5, 6];
+    /// assert_eq!(a.try_mul(&b).unwrap(), vector![4, 10, 18]);
+    ///
+    /// let c = vector![1, 2];
+    /// assert!(a.try_mul(&c).is_err());
+    /// ```
+    pub fn try_mul(&self, other: &Vector<T>) -> Result<Vector<T>, CrabsformerError> {
+        if self.len() != other.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![other.len()],
+            });
+        }
+
+        Ok(self
+            .elements()
+            .zip(other.elements())
+            .map(|(x, y)| *x * *y)
+            .collect())
+    }
+
+    /// Returns the element-wise quotient of two numeric vectors, or a
+    /// [`CrabsformerError::ShapeMismatch`] if their lengths don't match.
+    ///
+    /// This is the non-panicking counterpart of the `/` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![4, 10, 18];
+    /// let b = vector![4, 5, 6];
+    /// assert_eq!(a.try_div(&b).unwrap(), vector![1, 2, 3]);
+    ///
+    /// let c = vector![1, 2];
+    /// assert!(a.try_div(&c).is_err());
+    /// ```
+    pub fn try_div(&self, other: &Vector<T>) -> Result<Vector<T>, CrabsformerError> {
+        if self.len() != other.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![other.len()],
+            });
+        }
+
+        Ok(self
+            .elements()
+            .zip(other.elements())
+            .map(|(x, y)| *x / *y)
+            .collect())
+    }
+
+    /// Returns the element-wise remainder of two numeric vectors, or a
+    /// [`CrabsformerError::ShapeMismatch`] if their lengths don't match.
+    ///
+    /// This is the non-panicking counterpart of the `%` operator.
+    ///
+    /// [`CrabsformerError::ShapeMismatch`]: ../../error/enum.CrabsformerError.html#variant.ShapeMismatch
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![4, 10, 18];
+    /// let b = vector![4, 4, 5];
+    /// assert_eq!(a.try_rem(&b).unwrap(), vector![0, 2, 3]);
+    ///
+    /// let c = vector![1, 2];
+    /// assert!(a.try_rem(&c).is_err());
+    /// ```
+    pub fn try_rem(&self, other: &Vector<T>) -> Result<Vector<T>, CrabsformerError> {
+        if self.len() != other.len() {
+            return Err(CrabsformerError::ShapeMismatch {
+                lhs: vec![self.len()],
+                rhs: vec![other.len()],
+            });
+        }
+
+        Ok(self
+            .elements()
+            .zip(other.elements())
+            .map(|(x, y)| *x % *y)
+            .collect())
+    }
+
+    /// Returns the cross product of two length-3 numeric vectors.
+    ///
+    /// # Panics
+    /// Panics if either vector doesn't have length 3.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = vector![1, 0, 0];
+    /// let b = vector![0, 1, 0];
+    /// assert_eq!(a.cross(&b), vector![0, 0, 1]);
+    /// ```
+    pub fn cross(&self, other: &Vector<T>) -> Vector<T> {
+        if self.len() != 3 || other.len() != 3 {
+            panic!(
+                "Vector cross product is only defined for length-3 vectors: {} != 3 or {} != 3",
+                self.len(),
+                other.len()
+            );
+        }
+
+        let data = vec![
+            self.data[1] * other.data[2] - self.data[2] * other.data[1],
+            self.data[2] * other.data[0] - self.data[0] * other.data[2],
+            self.data[0] * other.data[1] - self.data[1] * other.data[0],
+        ];
+        Vector { data }
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Float,
+{
+    /// Returns the Euclidean (L2) length of the numeric vector.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![3.0, 4.0];
+    /// assert_eq!(x.norm(), 5.0);
+    /// ```
+    pub fn norm(&self) -> T {
+        self.elements()
+            .fold(T::zero(), |acc, x| acc + *x * *x)
+            .sqrt()
+    }
+
+    /// Creates a new numeric vector scaled to unit length (`norm() == 1.0`).
+    /// If you want to modify an existing numeric vector use
+    /// [`normalize_mut`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![3.0, 4.0];
+    /// assert_eq!(x.normalize(), vector![0.6, 0.8]);
+    /// ```
+    ///
+    /// [`normalize_mut`]: #method.normalize_mut
+    pub fn normalize(&self) -> Vector<T> {
+        let norm = self.norm();
+        self.elements().map(|x| *x / norm).collect()
+    }
+
+    /// Scales the numeric vector to unit length (`norm() == 1.0`) in place.
+    /// If you want to create a new numeric vector use [`normalize`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let mut x = vector![3.0, 4.0];
+    /// x.normalize_mut();
+    /// assert_eq!(x, vector![0.6, 0.8]);
+    /// ```
+    ///
+    /// [`normalize`]: #method.normalize
+    pub fn normalize_mut(&mut self) {
+        let norm = self.norm();
+        self.data.iter_mut().for_each(|x| *x = *x / norm);
+    }
+
+    /// Returns the arithmetic mean of the numeric vector elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1.0, 2.0, 3.0];
+    /// assert_eq!(x.mean(), 2.0);
+    /// ```
+    pub fn mean(&self) -> T
+    where
+        T: FromPrimitive,
+    {
+        self.sum() / T::from_usize(self.len()).unwrap()
+    }
+
+    /// Returns the variance of the numeric vector elements, computed in a
+    /// single pass with Welford's online algorithm.
+    ///
+    /// `ddof` is the "delta degrees of freedom": the divisor used is
+    /// `len() - ddof`. Use `ddof = 0` for the population variance or
+    /// `ddof = 1` for the sample variance.
+    ///
+    /// # Panics
+    /// Panics if `ddof >= len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(x.var(0), 1.25);
+    /// ```
+    pub fn var(&self, ddof: usize) -> T
+    where
+        T: FromPrimitive,
+    {
+        if ddof >= self.len() {
+            panic!(
+                "ddof {} must be less than vector length {}",
+                ddof,
+                self.len()
+            );
+        }
+        let mut mean = T::zero();
+        let mut m2 = T::zero();
+        let mut n = 0usize;
+        for &x in self.elements() {
+            n += 1;
+            let delta = x - mean;
+            mean = mean + delta / T::from_usize(n).unwrap();
+            m2 = m2 + delta * (x - mean);
+        }
+        m2 / T::from_usize(n - ddof).unwrap()
     }
 
-    /// Returns the minimum element of a numeric vector.
+    /// Returns the standard deviation of the numeric vector elements, i.e.
+    /// the square root of [`var`].
     ///
-    /// Note that, it's only work for numeric vector
-    /// of integer due too the trait `std::cmp::Ord` is
-    /// not implemented for `f32` and `f64` in Rust
-    /// standard library. This may change in the future.
+    /// See [`var`] for the meaning of `ddof`.
     ///
-    /// # Examples
+    /// # Panics
+    /// Panics if `ddof >= len()`.
     ///
+    /// # Examples
     /// ```
     /// # use crabsformer::prelude::*;
-    /// let x = vector![1, 2, 3];
-    /// assert_eq!(*x.min(), 1);
+    /// let x = vector![1.0, 2.0, 3.0, 4.0];
+    /// assert_eq!(x.std(0), 1.118033988749895);
     /// ```
-    pub fn min(&self) -> &T
+    ///
+    /// [`var`]: #method.var
+    pub fn std(&self, ddof: usize) -> T
     where
-        T: num::Integer + Copy,
+        T: FromPrimitive,
     {
-        self.elements().min().unwrap()
+        self.var(ddof).sqrt()
     }
 }
 
@@ -154,22 +818,14 @@ where
     type Output = Vector<T>;
 
     fn add(self, other: Vector<T>) -> Vector<T> {
-        if self.len() != other.len() {
-            panic!(
+        match self.try_add(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
                 "Vector addition with invalid length: {} != {}",
                 self.len(),
                 other.len()
-            );
+            ),
         }
-
-        // Add the vectors
-        let data = self
-            .data
-            .iter()
-            .enumerate()
-            .map(|(i, x)| *x + other[i])
-            .collect();
-        Vector { data }
     }
 }
 
@@ -265,6 +921,45 @@ where
     }
 }
 
+// This trait is implemented to support for numeric vector addition
+// operator on borrowed operands, so combining vectors repeatedly
+// doesn't force a `.clone()` at every step, e.g.:
+//
+// let c = &a + &b;
+// let d = &a + &c;
+//
+impl<T> ops::Add<&Vector<T>> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn add(self, other: &Vector<T>) -> Vector<T> {
+        match self.try_add(other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Vector addition with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector addition
+// operator with scalar on the right side, on a borrowed vector.
+impl<T> ops::Add<T> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn add(self, value: T) -> Vector<T> {
+        let data = self.elements().map(|x| *x + value).collect();
+        Vector { data }
+    }
+}
+
 // This trait is implemented to support for numeric vector
 // substraction operator
 impl<T> ops::Sub<Vector<T>> for Vector<T>
@@ -274,22 +969,14 @@ where
     type Output = Vector<T>;
 
     fn sub(self, other: Vector<T>) -> Vector<T> {
-        if self.len() != other.len() {
-            panic!(
+        match self.try_sub(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
                 "Vector substraction with invalid length: {} != {}",
                 self.len(),
                 other.len()
-            );
+            ),
         }
-
-        // Add the vectors
-        let data = self
-            .data
-            .iter()
-            .enumerate()
-            .map(|(i, x)| *x - other[i])
-            .collect();
-        Vector { data }
     }
 }
 
@@ -384,6 +1071,41 @@ where
     }
 }
 
+// This trait is implemented to support for numeric vector substraction
+// operator on borrowed operands. See the `Add<&Vector<T>>` impl above
+// for why this exists alongside the by-value operator.
+impl<T> ops::Sub<&Vector<T>> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn sub(self, other: &Vector<T>) -> Vector<T> {
+        match self.try_sub(other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Vector substraction with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector substraction
+// operator with scalar on the right side, on a borrowed vector.
+impl<T> ops::Sub<T> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn sub(self, value: T) -> Vector<T> {
+        let data = self.elements().map(|x| *x - value).collect();
+        Vector { data }
+    }
+}
+
 impl<T> Clone for Vector<T>
 where
     T: Num + Copy,
@@ -403,21 +1125,13 @@ where
     type Output = Vector<T>;
 
     fn mul(self, other: Vector<T>) -> Vector<T> {
-        if self.len() != other.len() {
-            panic!(
+        match self.try_mul(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
                 "Vector multiplication with invalid length: {} != {}",
                 self.len(),
                 other.len()
-            );
-        }
-
-        Vector {
-            data: self
-                .data
-                .iter()
-                .enumerate()
-                .map(|(i, v)| *v * other[i])
-                .collect(),
+            ),
         }
     }
 }
@@ -512,3 +1226,298 @@ where
         }
     }
 }
+
+// This trait is implemented to support for numeric vector multiplication
+// operator on borrowed operands. See the `Add<&Vector<T>>` impl above
+// for why this exists alongside the by-value operator.
+impl<T> ops::Mul<&Vector<T>> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, other: &Vector<T>) -> Vector<T> {
+        match self.try_mul(other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Vector multiplication with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector multiplication
+// operator with scalar on the right side, on a borrowed vector.
+impl<T> ops::Mul<T> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, value: T) -> Vector<T> {
+        Vector {
+            data: self.elements().map(|x| *x * value).collect(),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector division operator.
+//
+// Division by a zero element panics for integer element types, the same
+// as Rust's `/` operator; for float element types it yields `inf`/`NaN`
+// instead, following IEEE 754.
+impl<T> ops::Div<Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn div(self, other: Vector<T>) -> Vector<T> {
+        match self.try_div(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Vector division with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector division
+// operator with scalar on the right side,
+// for example:
+//
+// let a = vector![5, 5, 5, 5] / 6;
+impl<T> ops::Div<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn div(self, value: T) -> Vector<T> {
+        Vector {
+            data: self.elements().map(|x| *x / value).collect(),
+        }
+    }
+}
+
+// This macro is to generate support for numeric vector division
+// operator with scalar on the left side,
+// for example:
+//
+// let a = 6 / vector![5, 5, 5, 5];
+//
+macro_rules! impl_div_vector_for_type {
+    ($t: ty) => {
+        impl ops::Div<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn div(self, v: Vector<$t>) -> Vector<$t> {
+                let data = v.elements().map(|x| self / *x).collect();
+                Vector { data }
+            }
+        }
+    };
+}
+
+impl_div_vector_for_type!(usize);
+impl_div_vector_for_type!(i8);
+impl_div_vector_for_type!(i16);
+impl_div_vector_for_type!(i32);
+impl_div_vector_for_type!(i64);
+impl_div_vector_for_type!(i128);
+impl_div_vector_for_type!(u8);
+impl_div_vector_for_type!(u16);
+impl_div_vector_for_type!(u32);
+impl_div_vector_for_type!(u64);
+impl_div_vector_for_type!(u128);
+impl_div_vector_for_type!(f32);
+impl_div_vector_for_type!(f64);
+
+// This trait is implemented to support for numeric vector division
+// assignment operator (/=)
+impl<T> ops::DivAssign<Vector<T>> for Vector<T>
+where
+    T: Num + Copy + ops::DivAssign,
+{
+    fn div_assign(&mut self, other: Vector<T>) {
+        if self.len() != other.len() {
+            panic!(
+                "Vector division with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            );
+        }
+
+        for (i, x) in self.data.iter_mut().enumerate() {
+            *x /= other[i];
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector division
+// assignment operator (/=) with scalar on the right side,
+// for example:
+//
+// let a = vector![5, 5, 5, 5];
+// a /= 6;
+//
+impl<T> ops::DivAssign<T> for Vector<T>
+where
+    T: Num + Copy + ops::DivAssign,
+{
+    fn div_assign(&mut self, value: T) {
+        for x in self.data.iter_mut() {
+            *x /= value
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector division
+// operator on borrowed operands. See the `Add<&Vector<T>>` impl above
+// for why this exists alongside the by-value operator.
+impl<T> ops::Div<&Vector<T>> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn div(self, other: &Vector<T>) -> Vector<T> {
+        match self.try_div(other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Vector division with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector division
+// operator with scalar on the right side, on a borrowed vector.
+impl<T> ops::Div<T> for &Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn div(self, value: T) -> Vector<T> {
+        Vector {
+            data: self.elements().map(|x| *x / value).collect(),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector remainder
+// operator.
+impl<T> ops::Rem<Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn rem(self, other: Vector<T>) -> Vector<T> {
+        match self.try_rem(&other) {
+            Ok(result) => result,
+            Err(_) => panic!(
+                "Vector remainder with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            ),
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector remainder
+// operator with scalar on the right side,
+// for example:
+//
+// let a = vector![5, 5, 5, 5] % 6;
+impl<T> ops::Rem<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+
+    fn rem(self, value: T) -> Vector<T> {
+        Vector {
+            data: self.elements().map(|x| *x % value).collect(),
+        }
+    }
+}
+
+// This macro is to generate support for numeric vector remainder
+// operator with scalar on the left side,
+// for example:
+//
+// let a = 6 % vector![5, 5, 5, 5];
+//
+macro_rules! impl_rem_vector_for_type {
+    ($t: ty) => {
+        impl ops::Rem<Vector<$t>> for $t {
+            type Output = Vector<$t>;
+
+            fn rem(self, v: Vector<$t>) -> Vector<$t> {
+                let data = v.elements().map(|x| self % *x).collect();
+                Vector { data }
+            }
+        }
+    };
+}
+
+impl_rem_vector_for_type!(usize);
+impl_rem_vector_for_type!(i8);
+impl_rem_vector_for_type!(i16);
+impl_rem_vector_for_type!(i32);
+impl_rem_vector_for_type!(i64);
+impl_rem_vector_for_type!(i128);
+impl_rem_vector_for_type!(u8);
+impl_rem_vector_for_type!(u16);
+impl_rem_vector_for_type!(u32);
+impl_rem_vector_for_type!(u64);
+impl_rem_vector_for_type!(u128);
+impl_rem_vector_for_type!(f32);
+impl_rem_vector_for_type!(f64);
+
+// This trait is implemented to support for numeric vector remainder
+// assignment operator (%=)
+impl<T> ops::RemAssign<Vector<T>> for Vector<T>
+where
+    T: Num + Copy + ops::RemAssign,
+{
+    fn rem_assign(&mut self, other: Vector<T>) {
+        if self.len() != other.len() {
+            panic!(
+                "Vector remainder with invalid length: {} != {}",
+                self.len(),
+                other.len()
+            );
+        }
+
+        for (i, x) in self.data.iter_mut().enumerate() {
+            *x %= other[i];
+        }
+    }
+}
+
+// This trait is implemented to support for numeric vector remainder
+// assignment operator (%=) with scalar on the right side,
+// for example:
+//
+// let a = vector![5, 5, 5, 5];
+// a %= 6;
+//
+impl<T> ops::RemAssign<T> for Vector<T>
+where
+    T: Num + Copy + ops::RemAssign,
+{
+    fn rem_assign(&mut self, value: T) {
+        for x in self.data.iter_mut() {
+            *x %= value
+        }
+    }
+}
@@ -0,0 +1,57 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`proptest`] strategies for generating arbitrary [`Vector`]s, gated
+//! behind the `proptest` feature.
+//!
+//! [`proptest`]: https://docs.rs/proptest
+//! [`Vector`]: ../struct.Vector.html
+
+use crate::vector::Vector;
+use num::Num;
+use proptest::collection::vec;
+use proptest::prelude::*;
+use std::fmt::Debug;
+use std::ops::Range;
+
+/// Build a [`Strategy`] that generates [`Vector`] values whose length is
+/// drawn from `len_range` and whose elements are drawn from
+/// `element_strategy`, shrinking towards shorter vectors of simpler
+/// elements.
+///
+/// [`Vector`]: ../struct.Vector.html
+///
+/// # Examples
+/// ```
+/// # use crabsformer::prelude::*;
+/// use proptest::prelude::*;
+///
+/// proptest! {
+///     #[test]
+///     fn test_vector_strategy_respects_len_range(
+///         v in vector_strategy(0..10, any::<i32>())
+///     ) {
+///         prop_assert!(v.len() < 10);
+///     }
+/// }
+/// ```
+pub fn vector_strategy<T>(
+    len_range: Range<usize>,
+    element_strategy: impl Strategy<Value = T>,
+) -> impl Strategy<Value = Vector<T>>
+where
+    T: Num + Copy + Debug,
+{
+    vec(element_strategy, len_range).prop_map(Vector::from)
+}
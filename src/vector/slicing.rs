@@ -18,7 +18,7 @@
 //!
 //!
 
-use crate::vector::{SubVector, Vector};
+use crate::vector::{SubVector, SubVectorMut, Vector};
 use num::Num;
 use std::ops;
 
@@ -215,6 +215,175 @@ where
     }
 }
 
+/// Mutable counterpart of [`VectorSlice`], implementing sub-numeric-vector
+/// slicing with syntax `x.slice_mut(begin .. end)`.
+///
+/// Returns a mutable view over elements in the numeric vector from the
+/// range [`begin`..`end`) that writes back into the parent vector. This
+/// operation is `O(1)`.
+///
+/// [`VectorSlice`]: trait.VectorSlice.html
+///
+/// # Panics
+/// Requires that `begin <= end` and `end <= len` where `len` is the
+/// length of the numeric vector. Otherwise it will panic.
+///
+/// # Examples
+/// ```
+/// # use crabsformer::*;
+/// let mut x = vector![3, 1, 2, 3];
+/// x.slice_mut(0..2).fill(0);
+/// assert_eq!(x, vector![0, 0, 2, 3]);
+/// ```
+pub trait VectorSliceMut<'a, Idx>
+where
+    Idx: ?Sized,
+{
+    /// The returned type after indexing.
+    type Output: ?Sized;
+
+    /// Performs the mutable slicing (`container.slice_mut(index)`)
+    /// operation. It returns a mutable sub numeric vector, a mutable view
+    /// of elements in the numeric vector.
+    fn slice_mut(&'a mut self, range: Idx) -> Self::Output;
+}
+
+// vector.slice_mut(start..end)
+impl<'a, T: 'a> VectorSliceMut<'a, ops::Range<usize>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = SubVectorMut<'a, T>;
+
+    fn slice_mut(&'a mut self, range: ops::Range<usize>) -> SubVectorMut<'a, T> {
+        // Make sure the range is valid
+        check_range(&range);
+
+        // Performs bound checking
+        // range.end is exclusive, so we need to substract it by 1.
+        self.check_bound(range.end - 1);
+
+        // Returns new mutable sub numeric vector
+        SubVectorMut {
+            offset: range.start,
+            size: range.end - range.start,
+            source: self,
+        }
+    }
+}
+
+// vector.slice_mut(start..)
+impl<'a, T: 'a> VectorSliceMut<'a, ops::RangeFrom<usize>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = SubVectorMut<'a, T>;
+
+    fn slice_mut(
+        &'a mut self,
+        range: ops::RangeFrom<usize>,
+    ) -> SubVectorMut<'a, T> {
+        // Performs bound checking
+        self.check_bound(range.start);
+
+        // Returns new mutable sub numeric vector
+        let len = self.len();
+        SubVectorMut {
+            offset: range.start,
+            size: len - range.start,
+            source: self,
+        }
+    }
+}
+
+// vector.slice_mut(..end)
+impl<'a, T: 'a> VectorSliceMut<'a, ops::RangeTo<usize>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = SubVectorMut<'a, T>;
+
+    fn slice_mut(&'a mut self, range: ops::RangeTo<usize>) -> SubVectorMut<'a, T> {
+        // Performs bound checking
+        // range.end is exlusive, so we need to substract it by one.
+        self.check_bound(range.end - 1);
+
+        // Returns new mutable sub numeric vector
+        SubVectorMut {
+            offset: 0,
+            size: range.end,
+            source: self,
+        }
+    }
+}
+
+// vector.slice_mut(..)
+impl<'a, T: 'a> VectorSliceMut<'a, ops::RangeFull> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = SubVectorMut<'a, T>;
+
+    fn slice_mut(&'a mut self, _range: ops::RangeFull) -> SubVectorMut<'a, T> {
+        // Returns new mutable sub numeric vector
+        let len = self.len();
+        SubVectorMut {
+            offset: 0,
+            size: len,
+            source: self,
+        }
+    }
+}
+
+// vector.slice_mut(start..=end)
+impl<'a, T: 'a> VectorSliceMut<'a, ops::RangeInclusive<usize>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = SubVectorMut<'a, T>;
+
+    fn slice_mut(
+        &'a mut self,
+        range: ops::RangeInclusive<usize>,
+    ) -> SubVectorMut<'a, T> {
+        // Make sure the input is valid
+        check_range_inclusive(&range);
+
+        // Performs bound checking
+        self.check_bound(*range.end());
+
+        // Returns new mutable sub numeric vector
+        SubVectorMut {
+            offset: *range.start(),
+            size: (*range.end() + 1) - *range.start(),
+            source: self,
+        }
+    }
+}
+
+// vector.slice_mut(..=end)
+impl<'a, T: 'a> VectorSliceMut<'a, ops::RangeToInclusive<usize>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = SubVectorMut<'a, T>;
+
+    fn slice_mut(
+        &'a mut self,
+        range: ops::RangeToInclusive<usize>,
+    ) -> SubVectorMut<'a, T> {
+        // Performs bound checking
+        self.check_bound(range.end);
+
+        // Returns new mutable sub numeric vector
+        SubVectorMut {
+            offset: 0,
+            size: range.end + 1,
+            source: self,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -303,4 +472,68 @@ mod tests {
         };
         assert_eq!(submatrix, expected);
     }
+
+    // Test SliceMut(Range)
+    // vector.slice_mut(start..end)
+    #[test]
+    fn test_slice_mut_range() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        v.slice_mut(2..5).fill(0);
+        assert_eq!(v, vector![3, 1, 0, 0, 0, 9]);
+    }
+
+    // Test SliceMut(RangeFrom)
+    // vector.slice_mut(start..)
+    #[test]
+    fn test_slice_mut_rangefrom() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        v.slice_mut(2..).fill(0);
+        assert_eq!(v, vector![3, 1, 0, 0, 0, 0]);
+    }
+
+    // Test SliceMut(RangeTo)
+    // vector.slice_mut(..end)
+    #[test]
+    fn test_slice_mut_rangeto() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        v.slice_mut(..3).fill(0);
+        assert_eq!(v, vector![0, 0, 0, 1, 5, 9]);
+    }
+
+    // Test SliceMut(RangeFull)
+    // vector.slice_mut(..)
+    #[test]
+    fn test_slice_mut_rangefull() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        v.slice_mut(..).fill(0);
+        assert_eq!(v, vector![0, 0, 0, 0, 0, 0]);
+    }
+
+    // Test SliceMut(RangeInclusive)
+    // vector.slice_mut(start..=end)
+    #[test]
+    fn test_slice_mut_rangeinclusive() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        v.slice_mut(0..=2).fill(0);
+        assert_eq!(v, vector![0, 0, 0, 1, 5, 9]);
+    }
+
+    // Test SliceMut(RangeToInclusive)
+    // vector.slice_mut(..=end)
+    #[test]
+    fn test_slice_mut_rangetoinclusive() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        v.slice_mut(..=2).fill(0);
+        assert_eq!(v, vector![0, 0, 0, 1, 5, 9]);
+    }
+
+    // SliceMut writes through to the parent vector, and an out-of-place
+    // read via `get_mut` sees the same element.
+    #[test]
+    fn test_slice_mut_get_mut() {
+        let mut v = vector![3, 1, 4, 1, 5, 9];
+        let mut sub = v.slice_mut(1..4);
+        *sub.get_mut(0) = 100;
+        assert_eq!(v, vector![3, 100, 4, 1, 5, 9]);
+    }
 }
@@ -0,0 +1,299 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sparse numeric vectors.
+//!
+//! [`SparseVector`] stores only its nonzero elements, which is a lot
+//! cheaper than [`Vector`] for data that is dominated by zeros (feature
+//! vectors, graph adjacency rows, ...). Elements are kept in a compressed
+//! layout: `indices` holds the position of each nonzero value in strictly
+//! increasing order, and `data` holds the corresponding values.
+//!
+//! [`Vector`]: ../struct.Vector.html
+
+use crate::vector::errors::{VectorBuilderError, VectorBuilderErrorKind};
+use crate::vector::Vector;
+use num::Num;
+use std::ops;
+
+/// A sparse numeric vector, storing only its nonzero elements.
+///
+/// See the [module documentation] for more details.
+///
+/// [module documentation]: index.html
+pub struct SparseVector<T>
+where
+    T: Num + Copy,
+{
+    // Logical length of the vector
+    dim: usize,
+    // Positions of the nonzero elements, strictly increasing
+    indices: Vec<usize>,
+    // Values of the nonzero elements, `data[k]` sits at `indices[k]`
+    data: Vec<T>,
+    // A standing zero to hand back a reference to for absent positions
+    zero: T,
+}
+
+impl<T> SparseVector<T>
+where
+    T: Num + Copy,
+{
+    // Bound checking, mirrors `Vector::check_bound`.
+    fn check_bound(&self, i: usize) {
+        if i >= self.dim {
+            panic!(
+                "SparseVector index {} out of range for vector with length {}",
+                i, self.dim
+            )
+        }
+    }
+
+    // Checks that `indices`/`data` form a valid compressed layout: equal
+    // length, strictly increasing indices, and every index within `dim`.
+    fn validate(dim: usize, indices: &[usize], data: &[T]) -> Result<(), VectorBuilderError> {
+        if indices.len() != data.len() {
+            return Err(VectorBuilderError::new(
+                VectorBuilderErrorKind::InvalidRange,
+                format!(
+                    "indices has length {} but data has length {}",
+                    indices.len(),
+                    data.len()
+                ),
+            ));
+        }
+        if let Some(&last) = indices.last() {
+            if last >= dim {
+                return Err(VectorBuilderError::new(
+                    VectorBuilderErrorKind::InvalidRange,
+                    format!("index {} is out of range for dim {}", last, dim),
+                ));
+            }
+        }
+        for window in indices.windows(2) {
+            if window[0] >= window[1] {
+                return Err(VectorBuilderError::new(
+                    VectorBuilderErrorKind::InvalidRange,
+                    format!(
+                        "indices should be strictly increasing, found {} before {}",
+                        window[0], window[1]
+                    ),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new, empty sparse vector of the given logical length
+    /// `dim`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let v: SparseVector<f64> = SparseVector::new(5);
+    /// assert_eq!(v.dim(), 5);
+    /// assert_eq!(v.nnz(), 0);
+    /// ```
+    pub fn new(dim: usize) -> SparseVector<T> {
+        SparseVector {
+            dim,
+            indices: Vec::new(),
+            data: Vec::new(),
+            zero: T::zero(),
+        }
+    }
+
+    /// Creates a sparse vector from its raw compressed layout, validating
+    /// that `indices` is strictly increasing, `indices.len() ==
+    /// data.len()`, and every index is within `dim`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let v = SparseVector::from_parts(5, vec![1, 3], vec![10, 30]).unwrap();
+    /// assert_eq!(v.nnz(), 2);
+    /// assert_eq!(v[1], 10);
+    /// assert_eq!(v[0], 0);
+    /// ```
+    pub fn from_parts(
+        dim: usize,
+        indices: Vec<usize>,
+        data: Vec<T>,
+    ) -> Result<SparseVector<T>, VectorBuilderError> {
+        SparseVector::validate(dim, &indices, &data)?;
+        Ok(SparseVector {
+            dim,
+            indices,
+            data,
+            zero: T::zero(),
+        })
+    }
+
+    /// Creates a sparse vector holding the nonzero elements of the dense
+    /// vector `v`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let v = vector![0, 3, 0, 5];
+    /// let s = SparseVector::from_dense(&v);
+    /// assert_eq!(s.nnz(), 2);
+    /// ```
+    pub fn from_dense(v: &Vector<T>) -> SparseVector<T> {
+        let zero = T::zero();
+        let mut indices = Vec::new();
+        let mut data = Vec::new();
+        for (i, &value) in v.elements().enumerate() {
+            if value != zero {
+                indices.push(i);
+                data.push(value);
+            }
+        }
+
+        SparseVector {
+            dim: v.len(),
+            indices,
+            data,
+            zero,
+        }
+    }
+
+    /// Expands this sparse vector into a dense [`Vector`], filling absent
+    /// positions with zero.
+    ///
+    /// [`Vector`]: ../struct.Vector.html
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let s = SparseVector::from_parts(4, vec![1, 3], vec![3, 5]).unwrap();
+    /// assert_eq!(s.to_dense(), vector![0, 3, 0, 5]);
+    /// ```
+    pub fn to_dense(&self) -> Vector<T> {
+        let mut data = vec![self.zero; self.dim];
+        for (&i, &value) in self.indices.iter().zip(self.data.iter()) {
+            data[i] = value;
+        }
+        Vector::from(data)
+    }
+
+    /// The logical length of the sparse vector.
+    pub fn dim(&self) -> usize {
+        self.dim
+    }
+
+    /// The number of stored nonzero elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let s = SparseVector::from_parts(4, vec![1, 3], vec![3, 5]).unwrap();
+    /// assert_eq!(s.nnz(), 2);
+    /// ```
+    pub fn nnz(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Computes the dot product of two sparse vectors via a single merge
+    /// walk over their indices, in `O(nnz(self) + nnz(other))` time
+    /// without materializing either side.
+    ///
+    /// # Panics
+    /// Panics if `self.dim() != other.dim()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = SparseVector::from_parts(4, vec![0, 2], vec![2, 3]).unwrap();
+    /// let b = SparseVector::from_parts(4, vec![2, 3], vec![5, 7]).unwrap();
+    /// assert_eq!(a.dot(&b), 15); // only index 2 overlaps: 3 * 5
+    /// ```
+    pub fn dot(&self, other: &SparseVector<T>) -> T {
+        if self.dim != other.dim {
+            panic!(
+                "SparseVector dot: dimension mismatch, {} != {}",
+                self.dim, other.dim
+            )
+        }
+
+        let mut result = T::zero();
+        let mut i = 0;
+        let mut j = 0;
+        while i < self.indices.len() && j < other.indices.len() {
+            let a = self.indices[i];
+            let b = other.indices[j];
+            if a == b {
+                result = result + self.data[i] * other.data[j];
+                i += 1;
+                j += 1;
+            } else if a < b {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        result
+    }
+
+    /// Computes the dot product of this sparse vector with the dense
+    /// vector `other`, indexing the dense side directly at each nonzero
+    /// position instead of walking it in full.
+    ///
+    /// # Panics
+    /// Panics if `self.dim() != other.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let a = SparseVector::from_parts(4, vec![0, 2], vec![2, 3]).unwrap();
+    /// let b = vector![1, 1, 5, 1];
+    /// assert_eq!(a.dot_dense(&b), 17); // 2*1 + 3*5
+    /// ```
+    pub fn dot_dense(&self, other: &Vector<T>) -> T {
+        if self.dim != other.len() {
+            panic!(
+                "SparseVector dot_dense: dimension mismatch, {} != {}",
+                self.dim,
+                other.len()
+            )
+        }
+
+        let mut result = T::zero();
+        for (&i, &value) in self.indices.iter().zip(self.data.iter()) {
+            result = result + value * other[i];
+        }
+
+        result
+    }
+}
+
+// Implement sparse vector indexing, returning zero (by reference) for
+// positions that aren't stored.
+// sparse_vector[index]
+impl<T> ops::Index<usize> for SparseVector<T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+
+    fn index(&self, i: usize) -> &T {
+        self.check_bound(i);
+        match self.indices.binary_search(&i) {
+            Ok(pos) => &self.data[pos],
+            Err(_) => &self.zero,
+        }
+    }
+}
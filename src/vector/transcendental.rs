@@ -0,0 +1,188 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Element-wise transcendental math for numeric vectors.
+//!
+//! Every method here is bounded on [`num::Float`], so it works the same
+//! way for `Vector<f32>` and `Vector<f64>`. `num-traits` implements
+//! [`Float`] in terms of `std`'s `f32`/`f64` methods when its own `std`
+//! feature is on, and in terms of the [`libm`] crate when it isn't. This
+//! crate's `libm` Cargo feature (enabled instead of the default `std`
+//! feature, see `Cargo.toml`) simply forwards to `num`'s `libm` feature,
+//! so the functions below need no `cfg` of their own to work on `no_std`
+//! targets — they stay generic over `Float` either way.
+//!
+//! [`num::Float`]: https://docs.rs/num/0.2/num/trait.Float.html
+//! [`Float`]: https://docs.rs/num/0.2/num/trait.Float.html
+//! [`libm`]: https://docs.rs/libm
+
+use crate::vector::Vector;
+use num::Float;
+
+macro_rules! impl_unary_transcendental {
+    ($name:ident, $name_mut:ident, $doc:expr) => {
+        #[doc = $doc]
+        pub fn $name(&self) -> Vector<T> {
+            self.elements().map(|x| x.$name()).collect()
+        }
+
+        /// In-place, mutating version of its non-`_mut` counterpart above.
+        pub fn $name_mut(&mut self) {
+            self.data.iter_mut().for_each(|x| *x = x.$name());
+        }
+    };
+}
+
+impl<T> Vector<T>
+where
+    T: Float,
+{
+    impl_unary_transcendental!(
+        sin,
+        sin_mut,
+        "Returns a new numeric vector with the sine of each element.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![0.0, std::f64::consts::FRAC_PI_2];\n\
+         assert_eq!(x.sin(), vector![0.0, 1.0]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        cos,
+        cos_mut,
+        "Returns a new numeric vector with the cosine of each element.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![0.0, std::f64::consts::PI];\n\
+         assert_eq!(x.cos(), vector![1.0, -1.0]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        exp,
+        exp_mut,
+        "Returns a new numeric vector with `e` raised to the power of each element.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![0.0, 1.0];\n\
+         assert_eq!(x.exp(), vector![1.0, std::f64::consts::E]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        ln,
+        ln_mut,
+        "Returns a new numeric vector with the natural logarithm of each element.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![1.0, std::f64::consts::E];\n\
+         assert_eq!(x.ln(), vector![0.0, 1.0]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        sqrt,
+        sqrt_mut,
+        "Returns a new numeric vector with the square root of each element.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![4.0, 9.0];\n\
+         assert_eq!(x.sqrt(), vector![2.0, 3.0]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        abs,
+        abs_mut,
+        "Returns a new numeric vector with the absolute value of each element.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![-1.0, 2.0];\n\
+         assert_eq!(x.abs(), vector![1.0, 2.0]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        floor,
+        floor_mut,
+        "Returns a new numeric vector with each element rounded down to the nearest integer.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![1.7, -1.7];\n\
+         assert_eq!(x.floor(), vector![1.0, -2.0]);\n\
+         ```"
+    );
+
+    impl_unary_transcendental!(
+        ceil,
+        ceil_mut,
+        "Returns a new numeric vector with each element rounded up to the nearest integer.\n\n\
+         # Examples\n\
+         ```\n\
+         # use crabsformer::prelude::*;\n\
+         let x = vector![1.3, -1.3];\n\
+         assert_eq!(x.ceil(), vector![2.0, -1.0]);\n\
+         ```"
+    );
+
+    /// Returns a new numeric vector with the logarithm of each element
+    /// with respect to an arbitrary `base`. If you want to modify an
+    /// existing numeric vector use [`log_mut`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![8.0, 16.0];
+    /// assert_eq!(x.log(2.0), vector![3.0, 4.0]);
+    /// ```
+    ///
+    /// [`log_mut`]: #method.log_mut
+    pub fn log(&self, base: T) -> Vector<T> {
+        self.elements().map(|x| x.log(base)).collect()
+    }
+
+    /// In-place, mutating version of [`log`](#method.log).
+    pub fn log_mut(&mut self, base: T) {
+        self.data.iter_mut().for_each(|x| *x = x.log(base));
+    }
+
+    /// Returns a new numeric vector with each element raised to the
+    /// floating-point power `exp`. If you want to modify an existing
+    /// numeric vector use [`powf_mut`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use crabsformer::prelude::*;
+    /// let x = vector![4.0, 9.0];
+    /// assert_eq!(x.powf(0.5), vector![2.0, 3.0]);
+    /// ```
+    ///
+    /// [`powf_mut`]: #method.powf_mut
+    pub fn powf(&self, exp: T) -> Vector<T> {
+        self.elements().map(|x| x.powf(exp)).collect()
+    }
+
+    /// In-place, mutating version of [`powf`](#method.powf).
+    pub fn powf_mut(&mut self, exp: T) {
+        self.data.iter_mut().for_each(|x| *x = x.powf(exp));
+    }
+}
@@ -292,3 +292,127 @@ fn test_normal() {
     assert_eq!(a.shape(), b.shape());
     assert_ne!(a, b);
 }
+
+#[test]
+fn test_random_matrix_builder_standard_normal() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<f64> = rmb.standard_normal([5, 5]);
+    let b: Matrix<f64> = rmb.standard_normal([5, 5]);
+    assert_eq!(a.shape(), b.shape());
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_random_matrix_builder_lognormal() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<f64> = rmb.lognormal([5, 5], 0.0, 1.0).unwrap();
+    for rows in a.rows() {
+        for value in rows.elements() {
+            assert!(value >= 0.0);
+        }
+    }
+
+    // Negative standard deviation
+    let b: Result<Matrix<f64>, _> = rmb.lognormal([5, 5], 0.0, -1.0);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_random_matrix_builder_exponential() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<f64> = rmb.exponential([5, 5], 1.0).unwrap();
+    for rows in a.rows() {
+        for value in rows.elements() {
+            assert!(value >= 0.0);
+        }
+    }
+
+    // Invalid parameter: lambda is not positive
+    let b: Result<Matrix<f64>, _> = rmb.exponential([5, 5], 0.0);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_random_matrix_builder_poisson() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<u32> = rmb.poisson([5, 5], 4.0).unwrap();
+    assert_eq!(a.shape(), [5, 5]);
+
+    // Invalid parameter: lambda is not positive
+    let b: Result<Matrix<u32>, _> = rmb.poisson([5, 5], 0.0);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_random_matrix_builder_binomial() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<u32> = rmb.binomial([5, 5], 10, 0.5).unwrap();
+    for rows in a.rows() {
+        for value in rows.elements() {
+            assert!(value <= 10);
+        }
+    }
+
+    // Invalid parameter: p is outside [0, 1]
+    let b: Result<Matrix<u32>, _> = rmb.binomial([5, 5], 10, 1.5);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_random_matrix_builder_bernoulli() {
+    let mut rmb = RandomMatrixBuilder::new();
+
+    let a: Matrix<u32> = rmb.bernoulli([5, 5], 0.5).unwrap();
+    for rows in a.rows() {
+        for value in rows.elements() {
+            assert!(value == 0 || value == 1);
+        }
+    }
+
+    // Invalid parameter: p is outside [0, 1]
+    let b: Result<Matrix<u32>, _> = rmb.bernoulli([5, 5], 1.5);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_random_matrix_builder_uniform_with_rng() {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    let mut rng1 = SmallRng::seed_from_u64(12);
+    let a = RandomMatrixBuilder::uniform_with_rng([5, 5], -10, 10, &mut rng1).unwrap();
+
+    let mut rng2 = SmallRng::seed_from_u64(12);
+    let b = RandomMatrixBuilder::uniform_with_rng([5, 5], -10, 10, &mut rng2).unwrap();
+
+    assert_eq!(a, b);
+
+    // low >= high still returns an error
+    let mut rng3 = SmallRng::seed_from_u64(12);
+    let c = RandomMatrixBuilder::uniform_with_rng([5, 5], 10, 10, &mut rng3);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_random_matrix_builder_normal_with_rng() {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    let mut rng1 = SmallRng::seed_from_u64(12);
+    let a = RandomMatrixBuilder::normal_with_rng([5, 5], 2.0, 4.0, &mut rng1).unwrap();
+
+    let mut rng2 = SmallRng::seed_from_u64(12);
+    let b = RandomMatrixBuilder::normal_with_rng([5, 5], 2.0, 4.0, &mut rng2).unwrap();
+
+    assert_eq!(a, b);
+
+    // Negative standard deviation still returns an error
+    let mut rng3 = SmallRng::seed_from_u64(12);
+    let c = RandomMatrixBuilder::normal_with_rng([5, 5], 2.0, -4.0, &mut rng3);
+    assert_eq!(c.is_err(), true);
+}
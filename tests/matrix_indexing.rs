@@ -0,0 +1,90 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::*;
+
+#[test]
+fn test_strided_submatrix_diagonal() {
+    let w = matrix![
+        1, 2, 3, 4, 5, 6;
+        7, 8, 9, 10, 11, 12;
+        13, 14, 15, 16, 17, 18;
+        19, 20, 21, 22, 23, 24;
+        25, 26, 27, 28, 29, 30;
+        31, 32, 33, 34, 35, 36;
+    ];
+    let sub = w.slice_step(0..6, 0..6, (2, 3));
+    let d = sub.diagonal(0);
+    assert_eq!(d[0], 1);
+    assert_eq!(d[1], 16);
+}
+
+#[test]
+fn test_strided_submatrix_diagonal_mut() {
+    let mut w = matrix![
+        1, 2, 3, 4, 5, 6;
+        7, 8, 9, 10, 11, 12;
+        13, 14, 15, 16, 17, 18;
+        19, 20, 21, 22, 23, 24;
+        25, 26, 27, 28, 29, 30;
+        31, 32, 33, 34, 35, 36;
+    ];
+    w.slice_mut(0..2, 0..2).diagonal_mut(0).fill(0);
+    assert_eq!(
+        w,
+        matrix![
+            0, 2, 3, 4, 5, 6;
+            7, 0, 9, 10, 11, 12;
+            13, 14, 15, 16, 17, 18;
+            19, 20, 21, 22, 23, 24;
+            25, 26, 27, 28, 29, 30;
+            31, 32, 33, 34, 35, 36;
+        ]
+    );
+}
+
+#[test]
+fn test_strided_submatrix_diag_and_diag_offset() {
+    let w = matrix![
+        1, 2, 3, 4, 5, 6;
+        7, 8, 9, 10, 11, 12;
+        13, 14, 15, 16, 17, 18;
+        19, 20, 21, 22, 23, 24;
+        25, 26, 27, 28, 29, 30;
+        31, 32, 33, 34, 35, 36;
+    ];
+    let sub = w.slice_step(0..6, 0..6, (2, 3)); // [[1, 4], [13, 16], [25, 28]]
+
+    let diag: Vec<&i32> = sub.diag().collect();
+    assert_eq!(diag, [&1, &16]);
+
+    let super_diag: Vec<&i32> = sub.diag_offset(1).collect();
+    assert_eq!(super_diag, [&4]);
+}
+
+#[test]
+fn test_strided_submatrix_anti_diag() {
+    let w = matrix![
+        1, 2, 3, 4, 5, 6;
+        7, 8, 9, 10, 11, 12;
+        13, 14, 15, 16, 17, 18;
+        19, 20, 21, 22, 23, 24;
+        25, 26, 27, 28, 29, 30;
+        31, 32, 33, 34, 35, 36;
+    ];
+    let sub = w.slice_step(0..6, 0..6, (2, 3)); // [[1, 4], [13, 16], [25, 28]]
+
+    let anti_diag: Vec<&i32> = sub.anti_diag().collect();
+    assert_eq!(anti_diag, [&4, &13]);
+}
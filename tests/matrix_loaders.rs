@@ -88,3 +88,25 @@ fn test_load_valid_csv_with_header() {
         ]
     );
 }
+
+#[test]
+fn test_save_and_load_npy_round_trip() {
+    let w = matrix![3.0, 1.0; 4.0, 1.0];
+    let path = std::env::temp_dir().join("crabsformer_test_matrix.npy");
+    w.save_npy(&path).unwrap();
+
+    let loaded: Matrix<f64> = Matrix::load_npy(&path).unwrap();
+    assert_eq!(loaded, w);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_load_npy_dtype_mismatch() {
+    let w = matrix![3.0, 1.0; 4.0, 1.0];
+    let path = std::env::temp_dir().join("crabsformer_test_matrix_dtype.npy");
+    w.save_npy(&path).unwrap();
+
+    let _loaded: Matrix<f32> = Matrix::load_npy(&path).unwrap();
+}
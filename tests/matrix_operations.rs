@@ -29,6 +29,29 @@ fn test_power_mut() {
     assert_eq!(w1, matrix![9, 1; 16, 1]);
 }
 
+#[test]
+fn test_pow() {
+    let a = matrix![1, 1; 0, 1];
+    assert_eq!(a.pow(3), matrix![1, 3; 0, 1]);
+    assert_eq!(a.pow(0), Matrix::eye(2));
+}
+
+#[test]
+#[should_panic]
+fn test_pow_not_square() {
+    let a = matrix![1, 2, 3; 4, 5, 6];
+    let _ = a.pow(2);
+}
+
+#[test]
+fn test_try_pow() {
+    let a = matrix![1, 1; 0, 1];
+    assert_eq!(a.try_pow(3).unwrap(), matrix![1, 3; 0, 1]);
+
+    let b = matrix![1, 2, 3; 4, 5, 6];
+    assert_eq!(b.try_pow(2).is_err(), true);
+}
+
 // Binary operations
 #[test]
 fn test_add() {
@@ -68,6 +91,18 @@ fn test_add_assign() {
     let mut d = matrix![3.7, 1.7; 4.4, 1.2];
     d += 2.0;
     assert_eq!(d, matrix![5.7, 3.7; 6.4, 3.2]);
+
+    let mut e = matrix![1, 2, 3; 4, 5, 6];
+    e += matrix![10, 20, 30];
+    assert_eq!(e, matrix![11, 22, 33; 14, 25, 36]);
+}
+
+#[test]
+#[should_panic]
+fn test_add_assign_broadcast_cannot_grow() {
+    // A 1x3 matrix can't broadcast a 2x3 matrix into itself in place.
+    let mut a = matrix![1, 2, 3];
+    a += matrix![1, 2, 3; 4, 5, 6];
 }
 
 #[test]
@@ -76,6 +111,33 @@ fn test_add_invalid() {
     let _a = matrix![3, 1, 4; 1, 5, 5] + matrix![3, 1; 4, 1];
 }
 
+#[test]
+fn test_add_broadcast_row() {
+    // Broadcast a 1x3 row vector against every row of a 2x3 matrix.
+    let a = matrix![1, 2, 3; 4, 5, 6] + matrix![10, 20, 30];
+    assert_eq!(a, matrix![11, 22, 33; 14, 25, 36]);
+}
+
+#[test]
+fn test_add_broadcast_column() {
+    // Broadcast a 2x1 column vector against every column of a 2x3 matrix.
+    let a = matrix![1, 2, 3; 4, 5, 6] + matrix![10; 20];
+    assert_eq!(a, matrix![11, 12, 13; 24, 25, 26]);
+}
+
+#[test]
+fn test_add_broadcast_scalar_shaped() {
+    // Broadcast a 1x1 matrix against a full 2x2 matrix.
+    let a = matrix![1, 2; 3, 4] + matrix![10];
+    assert_eq!(a, matrix![11, 12; 13, 14]);
+}
+
+#[test]
+#[should_panic]
+fn test_add_broadcast_invalid() {
+    let _a = matrix![1, 2, 3; 4, 5, 6] + matrix![1, 2];
+}
+
 #[test]
 fn test_sub() {
     let a = matrix![3, 1; 4, 1] - matrix![3, 1; 4, 1];
@@ -197,3 +259,112 @@ fn test_mul_assign() {
 fn test_mul_invalid() {
     let _x = matrix![[4, 4] => 1] * matrix![[3, 3] => 2];
 }
+
+#[test]
+fn test_div() {
+    let a = matrix![9, 1; 16, 1] / matrix![3, 1; 4, 1];
+    assert_eq!(a, matrix![3, 1; 4, 1]);
+
+    let b = matrix![7.4, 3.4; 8.8, 2.4] / matrix![2.0, 2.0; 2.0, 2.0];
+    assert_eq!(b, matrix![3.7, 1.7; 4.4, 1.2]);
+
+    let c = matrix![6, 2; 8, 2] / 2;
+    assert_eq!(c, matrix![3, 1; 4, 1]);
+
+    let d = matrix![7.4, 3.4; 8.8, 2.4] / 2.0;
+    assert_eq!(d, matrix![3.7, 1.7; 4.4, 1.2]);
+
+    let e = 12 / matrix![3, 1; 4, 6];
+    assert_eq!(e, matrix![4, 12; 3, 2]);
+}
+
+#[test]
+fn test_div_assign() {
+    let mut a = matrix![9, 1; 16, 1];
+    a /= matrix![3, 1; 4, 1];
+    assert_eq!(a, matrix![3, 1; 4, 1]);
+
+    let mut c = matrix![6, 2; 8, 2];
+    c /= 2;
+    assert_eq!(c, matrix![3, 1; 4, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_div_invalid() {
+    let _x = matrix![[4, 4] => 1] / matrix![[3, 3] => 2];
+}
+
+#[test]
+fn test_cholesky() {
+    let a = matrix![4.0, 2.0; 2.0, 5.0];
+    let l = a.cholesky().unwrap();
+    assert_eq!(l, matrix![2.0, 0.0; 1.0, 2.0]);
+    assert_eq!(l.dot(&l.transpose()), a);
+}
+
+#[test]
+fn test_cholesky_rejects_non_positive_definite() {
+    let a = matrix![1.0, 2.0; 2.0, 1.0];
+    assert!(a.cholesky().is_none());
+}
+
+#[test]
+fn test_cholesky_rejects_non_symmetric() {
+    // Not symmetric, but its lower triangle alone still describes a
+    // valid Cholesky factor; cholesky() must reject it rather than
+    // silently ignoring the upper triangle.
+    let a = matrix![4.0, 1.0; 2.0, 9.0];
+    assert!(a.cholesky().is_none());
+}
+
+#[test]
+fn test_determinant_rejects_non_symmetric_shortcut() {
+    let a = matrix![4.0, 1.0; 2.0, 9.0];
+    assert_eq!(a.determinant(), 34.0);
+}
+
+#[test]
+fn test_determinant_via_cholesky() {
+    let a = matrix![4.0, 2.0; 2.0, 5.0];
+    assert_eq!(a.determinant(), 16.0);
+}
+
+#[test]
+fn test_determinant_via_lu_fallback() {
+    let a = matrix![1.0, 2.0; 3.0, 4.0];
+    assert_eq!(a.determinant(), -2.0);
+
+    let b = matrix![2.0, 0.0, 0.0; 0.0, -3.0, 0.0; 0.0, 0.0, 4.0];
+    assert_eq!(b.determinant(), -24.0);
+}
+
+#[test]
+fn test_solve() {
+    let a = matrix![2.0, 1.0; 1.0, 3.0];
+    let b = vector![5.0, 10.0];
+    assert_eq!(a.solve(&b), vector![1.0, 3.0]);
+
+    let c = matrix![1.0, 2.0, 3.0; 0.0, 1.0, 4.0; 5.0, 6.0, 0.0];
+    let d = vector![6.0, -1.0, 13.0];
+    let x = c.solve(&d);
+    // The exact solution is [-97, 83, -21]; elimination round-off shows
+    // up in the last digits.
+    assert_eq!(x, vector![-97.00000000000034, 83.00000000000028, -21.00000000000007]);
+}
+
+#[test]
+#[should_panic]
+fn test_solve_singular() {
+    let a = matrix![1.0, 2.0; 2.0, 4.0];
+    let b = vector![1.0, 2.0];
+    let _x = a.solve(&b);
+}
+
+#[test]
+#[should_panic]
+fn test_solve_requires_square() {
+    let a = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+    let b = vector![1.0, 2.0];
+    let _x = a.solve(&b);
+}
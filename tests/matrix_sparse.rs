@@ -0,0 +1,87 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+
+#[test]
+fn test_from_parts() {
+    // [[0, 3], [0, 0], [5, 0]]
+    let m = CsMatrix::from_parts(3, 2, vec![0, 1, 1, 2], vec![1, 0], vec![3, 5]).unwrap();
+    assert_eq!(m.shape(), [3, 2]);
+    assert_eq!(m.nnz(), 2);
+
+    // Mismatched indices/data lengths
+    let bad_lengths = CsMatrix::from_parts(3, 2, vec![0, 1, 1, 2], vec![1], vec![3, 5]);
+    assert_eq!(bad_lengths.is_err(), true);
+
+    // Wrong indptr length
+    let bad_indptr_len = CsMatrix::from_parts(3, 2, vec![0, 1, 2], vec![1, 0], vec![3, 5]);
+    assert_eq!(bad_indptr_len.is_err(), true);
+
+    // indptr doesn't start at 0
+    let bad_start = CsMatrix::from_parts(3, 2, vec![1, 1, 1, 2], vec![1, 0], vec![3, 5]);
+    assert_eq!(bad_start.is_err(), true);
+
+    // indptr not monotonically nondecreasing
+    let bad_order = CsMatrix::from_parts(3, 2, vec![0, 2, 1, 2], vec![1, 0], vec![3, 5]);
+    assert_eq!(bad_order.is_err(), true);
+
+    // Column index out of range
+    let bad_col = CsMatrix::from_parts(3, 2, vec![0, 1, 1, 2], vec![1, 2], vec![3, 5]);
+    assert_eq!(bad_col.is_err(), true);
+}
+
+#[test]
+fn test_from_dense_and_to_dense() {
+    let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    let m = CsMatrix::from_dense(&source);
+    assert_eq!(m.shape(), [3, 2]);
+    assert_eq!(m.nnz(), 2);
+    assert_eq!(m.to_dense(), source);
+}
+
+#[test]
+fn test_row() {
+    let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    let m = CsMatrix::from_dense(&source);
+    let row0: Vec<(usize, i32)> = m.row(0).map(|(c, &v)| (c, v)).collect();
+    assert_eq!(row0, vec![(1, 3)]);
+    let row1: Vec<(usize, i32)> = m.row(1).map(|(c, &v)| (c, v)).collect();
+    assert_eq!(row1, vec![]);
+}
+
+#[test]
+#[should_panic]
+fn test_row_out_of_bound() {
+    let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    let m = CsMatrix::from_dense(&source);
+    let _ = m.row(3).count();
+}
+
+#[test]
+fn test_dot() {
+    let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    let m = CsMatrix::from_dense(&source);
+    let x = vector![1, 2];
+    assert_eq!(m.dot(&x), vector![6, 0, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_dot_dimension_mismatch() {
+    let source = vec![vec![0, 3], vec![0, 0], vec![5, 0]];
+    let m = CsMatrix::from_dense(&source);
+    let x = vector![1, 2, 3];
+    let _ = m.dot(&x);
+}
@@ -0,0 +1,74 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::*;
+
+#[test]
+fn test_vstack() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6];
+    let w = Matrix::vstack(&[a, b]).unwrap();
+    assert_eq!(w, matrix![1, 2; 3, 4; 5, 6]);
+}
+
+#[test]
+fn test_vstack_shape_mismatch() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6, 7];
+    assert_eq!(Matrix::vstack(&[a, b]).is_err(), true);
+}
+
+#[test]
+fn test_hstack() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5; 6];
+    let w = Matrix::hstack(&[a, b]).unwrap();
+    assert_eq!(w, matrix![1, 2, 5; 3, 4, 6]);
+}
+
+#[test]
+fn test_hstack_shape_mismatch() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6, 7];
+    assert_eq!(Matrix::hstack(&[a, b]).is_err(), true);
+}
+
+#[test]
+fn test_matrix_block() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5; 6];
+    let c = matrix![7, 8];
+    let d = matrix![9];
+    let w = matrix_block![a, b; c, d].unwrap();
+    assert_eq!(w, matrix![1, 2, 5; 3, 4, 6; 7, 8, 9]);
+}
+
+#[test]
+fn test_matrix_block_rejects_row_total_width_mismatch() {
+    let a = matrix![1, 2; 3, 4];
+    let b = matrix![5, 6, 7];
+    assert_eq!(matrix_block![a; b].is_err(), true);
+}
+
+#[test]
+fn test_matrix_block_rejects_misaligned_column_blocks() {
+    // Row totals both equal 5, so checking only the aggregate width per
+    // row would miss that column blocks don't actually line up: the first
+    // column block is 2 wide in row 0 but 4 wide in row 1.
+    let a = matrix![0, 0; 0, 0]; // 2x2
+    let b = matrix![0, 0, 0; 0, 0, 0]; // 2x3
+    let c = matrix![0, 0, 0, 0; 0, 0, 0, 0]; // 2x4
+    let d = matrix![0; 0]; // 2x1
+    assert_eq!(matrix_block![a, b; c, d].is_err(), true);
+}
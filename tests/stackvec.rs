@@ -0,0 +1,79 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::stackvec::StackVec;
+
+#[test]
+fn test_push() {
+    let mut v: StackVec<i32, 3> = StackVec::new();
+    assert_eq!(v.size(), 0);
+    v.push(1).unwrap();
+    v.push(2).unwrap();
+    assert_eq!(&v[..], &[1, 2]);
+    assert_eq!(v.size(), 2);
+    assert_eq!(v.capacity(), 3);
+}
+
+#[test]
+fn test_push_capacity_error() {
+    let mut v: StackVec<i32, 2> = StackVec::new();
+    v.push(1).unwrap();
+    v.push(2).unwrap();
+    assert!(v.push(3).is_err());
+}
+
+#[test]
+fn test_full_of() {
+    let v: StackVec<i32, 4> = StackVec::full_of(3, 7).unwrap();
+    assert_eq!(&v[..], &[7, 7, 7]);
+}
+
+#[test]
+fn test_full_of_capacity_error() {
+    let v: Result<StackVec<i32, 2>, _> = StackVec::full_of(3, 7);
+    assert!(v.is_err());
+}
+
+#[test]
+fn test_range() {
+    let v: StackVec<i32, 4> = StackVec::range(0, 4, 1).unwrap();
+    assert_eq!(&v[..], &[0, 1, 2, 3]);
+}
+
+#[test]
+fn test_range_capacity_error() {
+    let v: Result<StackVec<i32, 2>, _> = StackVec::range(0, 4, 1);
+    assert!(v.is_err());
+}
+
+#[test]
+fn test_drop_runs_only_for_initialized_elements() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    struct DropCounter(Rc<RefCell<usize>>);
+    impl Drop for DropCounter {
+        fn drop(&mut self) {
+            *self.0.borrow_mut() += 1;
+        }
+    }
+
+    let count = Rc::new(RefCell::new(0));
+    {
+        let mut v: StackVec<DropCounter, 4> = StackVec::new();
+        v.push(DropCounter(count.clone())).unwrap();
+        v.push(DropCounter(count.clone())).unwrap();
+    }
+    assert_eq!(*count.borrow(), 2);
+}
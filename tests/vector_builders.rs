@@ -173,6 +173,42 @@ fn test_linspace() {
     assert_eq!(a, vector![1.0, 3.25, 5.5, 7.75, 10.0]);
 }
 
+#[test]
+fn test_linspace_single_point() {
+    let a = Vector::linspace(1, 1.0, 10.0);
+    assert_eq!(a, vector![1.0]);
+}
+
+#[test]
+fn test_logspace() {
+    let a = Vector::logspace(3, 2.0, 4.0);
+    assert_eq!(a, vector![100.0, 1_000.0, 10_000.0]);
+}
+
+#[test]
+fn test_geomspace() {
+    let a = Vector::geomspace(3, 1.0, 100.0).unwrap();
+    assert_eq!(a, vector![1.0, 10.0, 100.0]);
+
+    // Invalid range: start is zero
+    let b = Vector::geomspace(3, 0.0, 100.0);
+    assert_eq!(b.is_err(), true);
+
+    // Invalid range: end is zero
+    let c = Vector::geomspace(3, 1.0, 0.0);
+    assert_eq!(c.is_err(), true);
+
+    // Invalid range: start and end differ in sign
+    let d = Vector::geomspace(3, -1.0, 100.0);
+    assert_eq!(d.is_err(), true);
+}
+
+#[test]
+fn test_geomspace_single_point() {
+    let a = Vector::geomspace(1, 1.0, 100.0).unwrap();
+    assert_eq!(a, vector![1.0]);
+}
+
 #[test]
 fn test_uniform_data_types() {
     let mut rvb = RandomVectorBuilder::new();
@@ -262,6 +298,25 @@ fn test_uniform_interval() {
     assert_eq!(x3.is_err(), true);
 }
 
+#[test]
+fn test_uniform_with_rng() {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    let mut rng1 = SmallRng::seed_from_u64(12);
+    let a = RandomVectorBuilder::uniform_with_rng(5, -10, 10, &mut rng1).unwrap();
+
+    let mut rng2 = SmallRng::seed_from_u64(12);
+    let b = RandomVectorBuilder::uniform_with_rng(5, -10, 10, &mut rng2).unwrap();
+
+    assert_eq!(a, b);
+
+    // low >= high still returns an error
+    let mut rng3 = SmallRng::seed_from_u64(12);
+    let c = RandomVectorBuilder::uniform_with_rng(5, 10, 10, &mut rng3);
+    assert_eq!(c.is_err(), true);
+}
+
 #[test]
 fn test_normal() {
     let mut rvb = RandomVectorBuilder::new();
@@ -270,4 +325,273 @@ fn test_normal() {
     let b = rvb.normal(5, 2.0, 4.0).unwrap();
     assert_eq!(a.len(), b.len());
     assert_ne!(a, b);
+
+    // Negative standard deviation
+    let c = rvb.normal(5, 2.0, -4.0);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_normal_with_rng() {
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+
+    let mut rng1 = SmallRng::seed_from_u64(12);
+    let a = RandomVectorBuilder::normal_with_rng(5, 2.0, 4.0, &mut rng1).unwrap();
+
+    let mut rng2 = SmallRng::seed_from_u64(12);
+    let b = RandomVectorBuilder::normal_with_rng(5, 2.0, 4.0, &mut rng2).unwrap();
+
+    assert_eq!(a, b);
+
+    // Negative standard deviation still returns an error
+    let mut rng3 = SmallRng::seed_from_u64(12);
+    let c = RandomVectorBuilder::normal_with_rng(5, 2.0, -4.0, &mut rng3);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_standard_normal() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.standard_normal(5);
+    let b: Vector<f64> = rvb.standard_normal(5);
+    assert_eq!(a.len(), b.len());
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_exponential() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.exponential(5, 1.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    // Invalid parameter: lambda is not positive
+    let b: Result<Vector<f64>, _> = rvb.exponential(5, 0.0);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_gamma() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.gamma(5, 2.0, 1.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    // Invalid parameter: shape is not positive
+    let b: Result<Vector<f64>, _> = rvb.gamma(5, 0.0, 1.0);
+    assert_eq!(b.is_err(), true);
+
+    // Invalid parameter: scale is not positive
+    let c: Result<Vector<f64>, _> = rvb.gamma(5, 2.0, 0.0);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_lognormal() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<f64> = rvb.lognormal(5, 0.0, 1.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    // Negative standard deviation
+    let b: Result<Vector<f64>, _> = rvb.lognormal(5, 0.0, -1.0);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_poisson() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<u32> = rvb.poisson(5, 4.0).unwrap();
+    assert_eq!(a.len(), 5);
+
+    // Invalid parameter: lambda is not positive
+    let b: Result<Vector<u32>, _> = rvb.poisson(5, 0.0);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_binomial() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<u32> = rvb.binomial(5, 10, 0.5).unwrap();
+    for value in a.elements() {
+        assert!(*value <= 10);
+    }
+
+    // Invalid parameter: p is outside [0, 1]
+    let b: Result<Vector<u32>, _> = rvb.binomial(5, 10, 1.5);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_bernoulli() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a: Vector<u32> = rvb.bernoulli(5, 0.5).unwrap();
+    for value in a.elements() {
+        assert!(*value == 0 || *value == 1);
+    }
+
+    // Invalid parameter: p is outside [0, 1]
+    let b: Result<Vector<u32>, _> = rvb.bernoulli(5, 1.5);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_cauchy() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.cauchy(5, 0.0, 1.0).unwrap();
+    let b = rvb.cauchy(5, 0.0, 1.0).unwrap();
+    assert_eq!(a.len(), b.len());
+    assert_ne!(a, b);
+
+    // Invalid parameter: scale is not positive
+    let c = rvb.cauchy(5, 0.0, 0.0);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_pareto() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.pareto(5, 1.0, 3.0).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 1.0);
+    }
+
+    // Invalid parameter: scale is not positive
+    let b = rvb.pareto(5, 0.0, 3.0);
+    assert_eq!(b.is_err(), true);
+
+    // Invalid parameter: shape is not positive
+    let c = rvb.pareto(5, 1.0, 0.0);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_weibull() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.weibull(5, 1.0, 1.5).unwrap();
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    // Invalid parameter: scale is not positive
+    let b = rvb.weibull(5, 0.0, 1.5);
+    assert_eq!(b.is_err(), true);
+
+    // Invalid parameter: shape is not positive
+    let c = rvb.weibull(5, 1.0, 0.0);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_choice_weighted() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let source = vector![10, 20, 30];
+    let weights = vector![1.0, 0.0, 0.0];
+    let a = rvb.choice_weighted(&source, &weights, 20).unwrap();
+    assert_eq!(a.len(), 20);
+    for value in a.elements() {
+        assert_eq!(*value, 10);
+    }
+
+    // Mismatched lengths
+    let b = rvb.choice_weighted(&source, &vector![1.0, 1.0], 5);
+    assert_eq!(b.is_err(), true);
+
+    // Negative weight
+    let c = rvb.choice_weighted(&source, &vector![1.0, -1.0, 1.0], 5);
+    assert_eq!(c.is_err(), true);
+}
+
+#[test]
+fn test_dirichlet() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let alpha = vector![1.0, 1.0, 1.0];
+    let a = rvb.dirichlet(&alpha).unwrap();
+    assert_eq!(a.len(), alpha.len());
+    let total: f64 = a.elements().sum();
+    assert!((total - 1.0).abs() < 1e-9);
+    for value in a.elements() {
+        assert!(*value >= 0.0);
+    }
+
+    // Invalid parameter: alpha[i] is not positive
+    let b = rvb.dirichlet(&vector![1.0, 0.0, 1.0]);
+    assert_eq!(b.is_err(), true);
+}
+
+#[test]
+fn test_bytes() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.bytes(1_000);
+    let b = rvb.bytes(1_000);
+    assert_eq!(a.len(), 1_000);
+    assert_eq!(b.len(), 1_000);
+    assert_ne!(a, b);
+}
+
+#[test]
+fn test_permutation() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let p = rvb.permutation(5);
+    assert_eq!(p.len(), 5);
+    let mut sorted: Vec<usize> = p.elements().cloned().collect();
+    sorted.sort();
+    assert_eq!(sorted, vec![0, 1, 2, 3, 4]);
+}
+
+#[test]
+fn test_shuffle() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let mut v = vector![1, 2, 3, 4, 5];
+    rvb.shuffle(&mut v);
+    let mut sorted: Vec<i32> = v.elements().cloned().collect();
+    sorted.sort();
+    assert_eq!(sorted, vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_permuted() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let v = vector![1, 2, 3, 4, 5];
+    let shuffled = rvb.permuted(&v);
+    assert_eq!(shuffled.len(), v.len());
+    let mut sorted: Vec<i32> = shuffled.elements().cloned().collect();
+    sorted.sort();
+    assert_eq!(sorted, vec![1, 2, 3, 4, 5]);
+    // `v` itself must be untouched
+    assert_eq!(v, vector![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn test_triangular() {
+    let mut rvb = RandomVectorBuilder::new();
+
+    let a = rvb.triangular(5, 0.0, 10.0, 3.0).unwrap();
+    for value in a.elements() {
+        assert!((0.0 <= *value) && (*value <= 10.0));
+    }
+
+    // Invalid parameter: mode is outside [min, max]
+    let b = rvb.triangular(5, 0.0, 10.0, 20.0);
+    assert_eq!(b.is_err(), true);
 }
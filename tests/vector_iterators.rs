@@ -24,3 +24,107 @@ fn test_elements() {
     assert_eq!(elements.next(), Some(&3));
     assert_eq!(elements.next(), None);
 }
+
+#[test]
+fn test_elements_mut() {
+    let mut x = vector![1, 2, 3];
+    for value in x.elements_mut() {
+        *value *= 10;
+    }
+    assert_eq!(x, vector![10, 20, 30]);
+}
+
+#[test]
+fn test_apply() {
+    let mut x = vector![1, 2, 3];
+    x.apply(|value| *value *= 10);
+    assert_eq!(x, vector![10, 20, 30]);
+}
+
+#[test]
+fn test_zip_apply() {
+    let mut x = vector![1, 2, 3];
+    let y = vector![10, 20, 30];
+    x.zip_apply(&y, |a, b| *a += b);
+    assert_eq!(x, vector![11, 22, 33]);
+}
+
+#[test]
+#[should_panic]
+fn test_zip_apply_invalid() {
+    let mut x = vector![1, 2, 3];
+    let y = vector![10, 20];
+    x.zip_apply(&y, |a, b| *a += b);
+}
+
+#[test]
+fn test_zip_with() {
+    let a = vector![1, 2, 3];
+    let b = vector![10, 20, 30];
+    let c = a.zip_with(&b, |x, y| x + y);
+    assert_eq!(c, vector![11, 22, 33]);
+}
+
+#[test]
+#[should_panic]
+fn test_zip_with_invalid() {
+    let a = vector![1, 2, 3];
+    let b = vector![10, 20];
+    let _c = a.zip_with(&b, |x, y| x + y);
+}
+
+#[test]
+fn test_map() {
+    let x = vector![1, 2, 3];
+    let y = x.map(|value| value as f64 * 0.5);
+    assert_eq!(y, vector![0.5, 1.0, 1.5]);
+}
+
+#[test]
+fn test_enumerate() {
+    let x = vector![3, 1, 4];
+    let pairs: Vec<(usize, &i32)> = x.enumerate().collect();
+    assert_eq!(pairs, [(0, &3), (1, &1), (2, &4)]);
+}
+
+#[test]
+fn test_fold() {
+    let x = vector![1, 2, 3, 4];
+    let sum = x.fold(0, |acc, value| acc + value);
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn test_scan() {
+    let x = vector![1, 2, 3, 4];
+    let running_sum: Vec<i32> = x
+        .scan(0, |state, value| {
+            *state += value;
+            Some(*state)
+        })
+        .collect();
+    assert_eq!(running_sum, [1, 3, 6, 10]);
+}
+
+#[test]
+fn test_into_iter_by_value() {
+    let x = vector![1, 2, 3];
+    let collected: Vec<i32> = x.into_iter().collect();
+    assert_eq!(collected, [1, 2, 3]);
+}
+
+#[test]
+fn test_into_iter_by_ref() {
+    let x = vector![1, 2, 3];
+    let collected: Vec<&i32> = (&x).into_iter().collect();
+    assert_eq!(collected, [&1, &2, &3]);
+}
+
+#[test]
+fn test_into_iter_by_mut_ref() {
+    let mut x = vector![1, 2, 3];
+    for value in &mut x {
+        *value *= 10;
+    }
+    assert_eq!(x, vector![10, 20, 30]);
+}
@@ -0,0 +1,46 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crabsformer::prelude::*;
+
+#[test]
+fn test_save_and_load_npy_round_trip() {
+    let w = vector![3.0, 1.0, 4.0, 1.0];
+    let path = std::env::temp_dir().join("crabsformer_test_vector.npy");
+    w.save_npy(&path).unwrap();
+
+    let loaded: Vector<f64> = Vector::load_npy(&path).unwrap();
+    assert_eq!(loaded, w);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_load_npy_dtype_mismatch() {
+    let w = vector![3.0, 1.0, 4.0, 1.0];
+    let path = std::env::temp_dir().join("crabsformer_test_vector_dtype.npy");
+    w.save_npy(&path).unwrap();
+
+    let _loaded: Vector<f32> = Vector::load_npy(&path).unwrap();
+}
+
+#[test]
+#[should_panic]
+fn test_load_npy_shape_mismatch() {
+    let m = matrix![3.0, 1.0; 4.0, 1.0];
+    let path = std::env::temp_dir().join("crabsformer_test_vector_shape.npy");
+    m.save_npy(&path).unwrap();
+
+    let _loaded: Vector<f64> = Vector::load_npy(&path).unwrap();
+}
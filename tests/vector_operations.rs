@@ -36,6 +36,19 @@ fn test_filter() {
     assert_eq!(y, vector![3, 4]);
 }
 
+#[test]
+fn test_clip() {
+    let x = vector![-1, 5, 3, 10];
+    assert_eq!(x.clip(0, 4), vector![0, 4, 3, 4]);
+}
+
+#[test]
+fn test_clip_mut() {
+    let mut x = vector![-1, 5, 3, 10];
+    x.clip_mut(0, 4);
+    assert_eq!(x, vector![0, 4, 3, 4]);
+}
+
 #[test]
 fn test_sum() {
     let x = vector![3, 1, 4, 1];
@@ -45,22 +58,106 @@ fn test_sum() {
     assert_eq!(y.sum(), 9.0);
 }
 
+#[test]
+fn test_product() {
+    let x = vector![1, 2, 3, 4];
+    assert_eq!(x.product(), 24);
+
+    let y = vector![1.0, 2.0, 3.0, 4.0];
+    assert_eq!(y.product(), 24.0);
+}
+
+#[test]
+fn test_cumsum() {
+    let x = vector![1, 2, 3, 4];
+    assert_eq!(x.cumsum(), vector![1, 3, 6, 10]);
+}
+
+#[test]
+fn test_cumprod() {
+    let x = vector![1, 2, 3, 4];
+    assert_eq!(x.cumprod(), vector![1, 2, 6, 24]);
+}
+
 #[test]
 fn test_max() {
     let x = vector![3, 1, 4, 1];
-    assert_eq!(x.max(), &4);
+    assert_eq!(x.max(), 4);
+
+    let y = vector![3.0, 1.0, 4.0, 1.0];
+    assert_eq!(y.max(), 4.0);
 
-    // let y = vector![3.0, 1.0, 4.0, 1.0];
-    // assert_eq!(y.max(), 4.0);
+    let z = vector![3.0, std::f64::NAN, 4.0];
+    assert!(z.max().is_nan());
 }
 
 #[test]
 fn test_min() {
     let x = vector![3, 1, 4, 1];
-    assert_eq!(x.min(), &1);
+    assert_eq!(x.min(), 1);
+
+    let y = vector![3.0, 1.0, 4.0, 1.0];
+    assert_eq!(y.min(), 1.0);
+
+    let z = vector![3.0, std::f64::NAN, 1.0];
+    assert!(z.min().is_nan());
+}
 
-    // let y = vector![3.0, 1.0, 4.0, 1.0];
-    // assert_eq!(y.min(), 1.0);
+#[test]
+fn test_nanmax() {
+    let x = vector![3.0, std::f64::NAN, 4.0];
+    assert_eq!(x.nanmax(), 4.0);
+}
+
+#[test]
+fn test_nanmin() {
+    let x = vector![3.0, std::f64::NAN, 1.0];
+    assert_eq!(x.nanmin(), 1.0);
+}
+
+#[test]
+#[should_panic]
+fn test_nanmax_all_nan() {
+    let x = vector![std::f64::NAN, std::f64::NAN];
+    let _m = x.nanmax();
+}
+
+#[test]
+fn test_argmax() {
+    let x = vector![3, 1, 4, 1];
+    assert_eq!(x.argmax(), 2);
+}
+
+#[test]
+fn test_argmin() {
+    let x = vector![3, 1, 4, 1];
+    assert_eq!(x.argmin(), 1);
+}
+
+#[test]
+fn test_mean() {
+    let x = vector![1.0, 2.0, 3.0];
+    assert_eq!(x.mean(), 2.0);
+}
+
+#[test]
+fn test_var() {
+    let x = vector![1.0, 2.0, 3.0, 4.0];
+    assert_eq!(x.var(0), 1.25);
+    assert_eq!(x.var(1), 1.6666666666666667);
+}
+
+#[test]
+fn test_std() {
+    let x = vector![1.0, 2.0, 3.0, 4.0];
+    assert_eq!(x.std(0), 1.118033988749895);
+}
+
+#[test]
+#[should_panic]
+fn test_var_invalid_ddof() {
+    let x = vector![1.0];
+    x.var(1);
 }
 
 // Binary operations
@@ -250,3 +347,157 @@ fn test_mul_assign() {
 fn test_mul_invalid() {
     let _x = vector![1, 2] * vector![2];
 }
+
+#[test]
+fn test_div() {
+    let a = vector![9, 1, 16, 1, 25] / vector![3, 1, 4, 1, 5];
+    assert_eq!(a, vector![3, 1, 4, 1, 5]);
+
+    let b = vector![7.4, 3.4, 8.8, 2.4, 11.0] / vector![2.0, 2.0, 2.0, 2.0, 2.0];
+    assert_eq!(b, vector![3.7, 1.7, 4.4, 1.2, 5.5]);
+
+    let c = vector![6, 2, 8, 2, 10] / 2;
+    assert_eq!(c, vector![3, 1, 4, 1, 5]);
+
+    let d = vector![7.4, 3.4, 8.8, 2.4, 11.0] / 2.0;
+    assert_eq!(d, vector![3.7, 1.7, 4.4, 1.2, 5.5]);
+
+    let e = 12 / vector![3, 1, 4, 1, 6];
+    assert_eq!(e, vector![4, 12, 3, 12, 2]);
+
+    let f = 12.0 / vector![3.0, 1.0, 4.0, 1.0, 6.0];
+    assert_eq!(f, vector![4.0, 12.0, 3.0, 12.0, 2.0]);
+}
+
+#[test]
+fn test_div_assign() {
+    let mut a = vector![9, 1, 16, 1, 25];
+    a /= vector![3, 1, 4, 1, 5];
+    assert_eq!(a, vector![3, 1, 4, 1, 5]);
+
+    let mut c = vector![6, 2, 8, 2, 10];
+    c /= 2;
+    assert_eq!(c, vector![3, 1, 4, 1, 5]);
+}
+
+#[test]
+#[should_panic]
+fn test_div_invalid() {
+    let _x = vector![1, 2] / vector![2];
+}
+
+#[test]
+#[should_panic]
+fn test_div_by_zero() {
+    let _x = vector![1, 2, 3] / vector![1, 0, 3];
+}
+
+#[test]
+fn test_div_float_by_zero_yields_inf() {
+    let x = vector![1.0, 2.0, 3.0] / vector![1.0, 0.0, 3.0];
+    assert!(x[1].is_infinite());
+}
+
+#[test]
+fn test_rem() {
+    let a = vector![9, 10, 16, 1, 25] % vector![4, 3, 5, 1, 7];
+    assert_eq!(a, vector![1, 1, 1, 0, 4]);
+
+    let c = vector![9, 10, 16, 1, 25] % 4;
+    assert_eq!(c, vector![1, 2, 0, 1, 1]);
+
+    let e = 12 % vector![5, 7, 4];
+    assert_eq!(e, vector![2, 5, 0]);
+}
+
+#[test]
+fn test_rem_assign() {
+    let mut a = vector![9, 10, 16, 1, 25];
+    a %= vector![4, 3, 5, 1, 7];
+    assert_eq!(a, vector![1, 1, 1, 0, 4]);
+
+    let mut c = vector![9, 10, 16, 1, 25];
+    c %= 4;
+    assert_eq!(c, vector![1, 2, 0, 1, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_rem_invalid() {
+    let _x = vector![1, 2] % vector![2];
+}
+
+#[test]
+fn test_arithmetic_on_borrowed_vectors() {
+    let a = vector![3, 1, 4, 1, 5];
+    let b = vector![3, 1, 4, 1, 5];
+
+    let c = &a + &b;
+    assert_eq!(c, vector![6, 2, 8, 2, 10]);
+
+    let d = &a - &b;
+    assert_eq!(d, vector![0, 0, 0, 0, 0]);
+
+    let e = &a * &b;
+    assert_eq!(e, vector![9, 1, 16, 1, 25]);
+
+    let f = &e / &a;
+    assert_eq!(f, b);
+
+    let g = &a + 2;
+    assert_eq!(g, vector![5, 3, 6, 3, 7]);
+
+    // `a` and `b` are still usable since the operators above only
+    // borrowed them.
+    assert_eq!(a, vector![3, 1, 4, 1, 5]);
+    assert_eq!(b, vector![3, 1, 4, 1, 5]);
+}
+
+#[test]
+fn test_dot() {
+    let a = vector![1, 3, -5];
+    let b = vector![4, -2, -1];
+    assert_eq!(a.dot(&b), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_dot_invalid() {
+    let a = vector![1, 3, -5];
+    let b = vector![4, -2];
+    let _d = a.dot(&b);
+}
+
+#[test]
+fn test_cross() {
+    let a = vector![1, 0, 0];
+    let b = vector![0, 1, 0];
+    assert_eq!(a.cross(&b), vector![0, 0, 1]);
+}
+
+#[test]
+#[should_panic]
+fn test_cross_invalid() {
+    let a = vector![1, 0, 0];
+    let b = vector![0, 1];
+    let _c = a.cross(&b);
+}
+
+#[test]
+fn test_norm() {
+    let x = vector![3.0, 4.0];
+    assert_eq!(x.norm(), 5.0);
+}
+
+#[test]
+fn test_normalize() {
+    let x = vector![3.0, 4.0];
+    assert_eq!(x.normalize(), vector![0.6, 0.8]);
+}
+
+#[test]
+fn test_normalize_mut() {
+    let mut x = vector![3.0, 4.0];
+    x.normalize_mut();
+    assert_eq!(x, vector![0.6, 0.8]);
+}
@@ -0,0 +1,78 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::prelude::*;
+
+#[test]
+fn test_new() {
+    let v: SparseVector<f64> = SparseVector::new(5);
+    assert_eq!(v.dim(), 5);
+    assert_eq!(v.nnz(), 0);
+    assert_eq!(v[0], 0.0);
+}
+
+#[test]
+fn test_from_parts() {
+    let v = SparseVector::from_parts(5, vec![1, 3], vec![10, 30]).unwrap();
+    assert_eq!(v.nnz(), 2);
+    assert_eq!(v[0], 0);
+    assert_eq!(v[1], 10);
+    assert_eq!(v[3], 30);
+    assert_eq!(v[4], 0);
+
+    // Mismatched indices/data lengths
+    let bad_lengths = SparseVector::from_parts(5, vec![1, 3], vec![10]);
+    assert_eq!(bad_lengths.is_err(), true);
+
+    // Non-increasing indices
+    let bad_order = SparseVector::from_parts(5, vec![3, 1], vec![30, 10]);
+    assert_eq!(bad_order.is_err(), true);
+
+    // Duplicate indices
+    let bad_dup = SparseVector::from_parts(5, vec![1, 1], vec![10, 20]);
+    assert_eq!(bad_dup.is_err(), true);
+
+    // Index out of range for dim
+    let bad_range = SparseVector::from_parts(5, vec![1, 5], vec![10, 20]);
+    assert_eq!(bad_range.is_err(), true);
+}
+
+#[test]
+fn test_from_dense_and_to_dense() {
+    let v = vector![0, 3, 0, 5];
+    let s = SparseVector::from_dense(&v);
+    assert_eq!(s.nnz(), 2);
+    assert_eq!(s.to_dense(), v);
+}
+
+#[test]
+#[should_panic]
+fn test_index_out_of_bound() {
+    let v: SparseVector<i32> = SparseVector::new(3);
+    let _ = v[3];
+}
+
+#[test]
+fn test_dot() {
+    let a = SparseVector::from_parts(4, vec![0, 2], vec![2, 3]).unwrap();
+    let b = SparseVector::from_parts(4, vec![2, 3], vec![5, 7]).unwrap();
+    assert_eq!(a.dot(&b), 15);
+}
+
+#[test]
+fn test_dot_dense() {
+    let a = SparseVector::from_parts(4, vec![0, 2], vec![2, 3]).unwrap();
+    let b = vector![1, 1, 5, 1];
+    assert_eq!(a.dot_dense(&b), 17);
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2019, Bayu Aldi Yansyah <bayualdiyansyah@gmail.com>
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crabsformer::*;
+
+#[test]
+fn test_sin() {
+    let x = vector![0.0, std::f64::consts::FRAC_PI_2];
+    assert_eq!(x.sin(), vector![0.0, 1.0]);
+}
+
+#[test]
+fn test_sin_mut() {
+    let mut x = vector![0.0, std::f64::consts::FRAC_PI_2];
+    x.sin_mut();
+    assert_eq!(x, vector![0.0, 1.0]);
+}
+
+#[test]
+fn test_cos() {
+    let x = vector![0.0, std::f64::consts::PI];
+    assert_eq!(x.cos(), vector![1.0, -1.0]);
+}
+
+#[test]
+fn test_exp() {
+    let x = vector![0.0, 1.0];
+    assert_eq!(x.exp(), vector![1.0, std::f64::consts::E]);
+}
+
+#[test]
+fn test_ln() {
+    let x = vector![1.0, std::f64::consts::E];
+    assert_eq!(x.ln(), vector![0.0, 1.0]);
+}
+
+#[test]
+fn test_log() {
+    let x = vector![8.0, 16.0];
+    assert_eq!(x.log(2.0), vector![3.0, 4.0]);
+}
+
+#[test]
+fn test_sqrt() {
+    let x = vector![4.0, 9.0];
+    assert_eq!(x.sqrt(), vector![2.0, 3.0]);
+}
+
+#[test]
+fn test_abs() {
+    let x = vector![-1.0, 2.0];
+    assert_eq!(x.abs(), vector![1.0, 2.0]);
+}
+
+#[test]
+fn test_floor() {
+    let x = vector![1.7, -1.7];
+    assert_eq!(x.floor(), vector![1.0, -2.0]);
+}
+
+#[test]
+fn test_ceil() {
+    let x = vector![1.3, -1.3];
+    assert_eq!(x.ceil(), vector![2.0, -1.0]);
+}
+
+#[test]
+fn test_powf() {
+    let x = vector![4.0, 9.0];
+    assert_eq!(x.powf(0.5), vector![2.0, 3.0]);
+}
+
+#[test]
+fn test_powf_mut() {
+    let mut x = vector![4.0, 9.0];
+    x.powf_mut(0.5);
+    assert_eq!(x, vector![2.0, 3.0]);
+}